@@ -0,0 +1,90 @@
+use std::{collections::HashSet, env, sync::Arc};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use solo_machine_core::{
+    ibc::apps::transfer::amount::Amount,
+    policy::{AcceptancePolicy, IncomingPacket, PacketDecision, PolicyRegistrar},
+};
+
+/// Acceptance policy which rejects packets from blocked senders, packets with unknown denoms and packets with amounts
+/// above a limit
+struct LimitsPolicy {
+    /// Addresses of senders (on IBC enabled chain) whose packets are rejected
+    blocked_senders: HashSet<String>,
+    /// Denoms which are accepted (all the denoms are accepted if this is `None`)
+    allowed_denoms: Option<HashSet<String>>,
+    /// Maximum amount of tokens accepted in a packet
+    max_amount: Option<Amount>,
+}
+
+impl LimitsPolicy {
+    pub fn from_env() -> Result<Self> {
+        let blocked_senders = get_list_env("SOLO_BLOCKED_SENDERS")
+            .map(|senders| senders.into_iter().collect())
+            .unwrap_or_default();
+        let allowed_denoms =
+            get_list_env("SOLO_ALLOWED_DENOMS").map(|denoms| denoms.into_iter().collect());
+        let max_amount = env::var("SOLO_MAX_AMOUNT")
+            .ok()
+            .map(|max_amount| max_amount.parse())
+            .transpose()
+            .context("invalid `SOLO_MAX_AMOUNT`")?;
+
+        Ok(Self {
+            blocked_senders,
+            allowed_denoms,
+            max_amount,
+        })
+    }
+}
+
+#[async_trait]
+impl AcceptancePolicy for LimitsPolicy {
+    async fn check(&self, packet: &IncomingPacket) -> Result<PacketDecision> {
+        if self.blocked_senders.contains(&packet.sender) {
+            return Ok(PacketDecision::Reject {
+                reason: format!("sender {} is blocked", packet.sender),
+            });
+        }
+
+        if let Some(ref allowed_denoms) = self.allowed_denoms {
+            if !allowed_denoms.contains(&packet.denom.to_string()) {
+                return Ok(PacketDecision::Reject {
+                    reason: format!("unknown denom {}", packet.denom),
+                });
+            }
+        }
+
+        if let Some(ref max_amount) = self.max_amount {
+            if &packet.amount > max_amount {
+                return Ok(PacketDecision::Reject {
+                    reason: format!(
+                        "amount {} is above the limit of {}",
+                        packet.amount, max_amount
+                    ),
+                });
+            }
+        }
+
+        Ok(PacketDecision::Accept)
+    }
+}
+
+/// Returns comma separated values of given environment variable (if it is set)
+fn get_list_env(key: &str) -> Option<Vec<String>> {
+    env::var(key).ok().map(|value| {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(ToOwned::to_owned)
+            .collect()
+    })
+}
+
+#[no_mangle]
+pub fn register_policy(registrar: &mut dyn PolicyRegistrar) -> Result<()> {
+    registrar.register(Arc::new(LimitsPolicy::from_env()?));
+    Ok(())
+}
@@ -10,6 +10,9 @@
 //! - `SOLO_HD_PATH`: HD wallet path (default: "m/44'/118'/0'/0/0")
 //! - `SOLO_ACCOUNT_PREFIX`: Account prefix for generating addresses (default: "cosmos")
 //! - `SOLO_ADDRESS_ALGO`: Algorithm of the key pair (default: "secp256k1") (possible values: ["secp256k1", "eth-secp256k1"])
+//!
+//! When loaded as a fee payer signer, the same arguments are read with `SOLO_FEE_PAYER_` prefix instead (e.g.,
+//! `SOLO_FEE_PAYER_MNEMONIC`).
 use std::{env, str::FromStr, sync::Arc};
 
 use anyhow::{anyhow, Context, Result};
@@ -41,16 +44,17 @@ pub struct MnemonicSigner {
 }
 
 impl MnemonicSigner {
-    pub fn from_env() -> Result<Self> {
-        let mnemonic_str = get_env("SOLO_MNEMONIC")?;
+    pub fn from_env(env_prefix: &str) -> Result<Self> {
+        let mnemonic_str = get_env(env_prefix, "MNEMONIC")?;
         let mnemonic = Mnemonic::new(mnemonic_str, Language::English)
             .map_err(|_| anyhow!("invalid mnemonic"))?;
 
-        let hd_path = get_env("SOLO_HD_PATH").unwrap_or_else(|_| DEFAULT_HD_PATH.to_string());
-        let account_prefix =
-            get_env("SOLO_ACCOUNT_PREFIX").unwrap_or_else(|_| DEFAULT_ACCOUNT_PREFIX.to_string());
+        let hd_path =
+            get_env(env_prefix, "HD_PATH").unwrap_or_else(|_| DEFAULT_HD_PATH.to_string());
+        let account_prefix = get_env(env_prefix, "ACCOUNT_PREFIX")
+            .unwrap_or_else(|_| DEFAULT_ACCOUNT_PREFIX.to_string());
 
-        let algo = get_env("SOLO_ADDRESS_ALGO")
+        let algo = get_env(env_prefix, "ADDRESS_ALGO")
             .unwrap_or_else(|_| DEFAULT_ADDRESS_ALGO.to_string())
             .parse()?;
 
@@ -72,8 +76,10 @@ impl MnemonicSigner {
     }
 }
 
-fn get_env(key: &str) -> Result<String> {
-    env::var(key).context(format!(
+fn get_env(env_prefix: &str, name: &str) -> Result<String> {
+    let key = format!("{}{}", env_prefix, name);
+
+    env::var(&key).context(format!(
         "`{}` environment variable is required for mnemonic signer",
         key
     ))
@@ -120,6 +126,6 @@ impl Signer for MnemonicSigner {
 
 #[no_mangle]
 pub fn register_signer(registrar: &mut dyn SignerRegistrar) -> Result<()> {
-    registrar.register(Arc::new(MnemonicSigner::from_env()?));
+    registrar.register(Arc::new(MnemonicSigner::from_env(registrar.env_prefix())?));
     Ok(())
 }
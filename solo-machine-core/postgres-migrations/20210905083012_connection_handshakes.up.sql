@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS connection_handshakes (
+    chain_id TEXT PRIMARY KEY NOT NULL,
+    step TEXT NOT NULL,
+    solo_machine_client_id TEXT,
+    tendermint_client_id TEXT,
+    solo_machine_connection_id TEXT,
+    tendermint_connection_id TEXT,
+    solo_machine_channel_id TEXT,
+    tendermint_channel_id TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,22 @@
+CREATE TABLE IF NOT EXISTS connections (
+    chain_id TEXT NOT NULL,
+    solo_machine_client_id TEXT NOT NULL,
+    tendermint_client_id TEXT NOT NULL,
+    solo_machine_connection_id TEXT NOT NULL,
+    tendermint_connection_id TEXT NOT NULL,
+    solo_machine_channel_id TEXT NOT NULL,
+    tendermint_channel_id TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (chain_id, solo_machine_channel_id)
+);
+
+INSERT INTO connections (chain_id, solo_machine_client_id, tendermint_client_id, solo_machine_connection_id, tendermint_connection_id, solo_machine_channel_id, tendermint_channel_id)
+SELECT
+    id,
+    connection_details->>'solo_machine_client_id',
+    connection_details->>'tendermint_client_id',
+    connection_details->>'solo_machine_connection_id',
+    connection_details->>'tendermint_connection_id',
+    connection_details->>'solo_machine_channel_id',
+    connection_details->>'tendermint_channel_id'
+FROM chains WHERE connection_details IS NOT NULL;
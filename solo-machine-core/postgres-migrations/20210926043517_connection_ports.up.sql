@@ -0,0 +1,5 @@
+ALTER TABLE connections ADD COLUMN port_id TEXT NOT NULL DEFAULT 'transfer';
+
+UPDATE connections SET port_id = chains.config->>'port_id' FROM chains WHERE chains.id = connections.chain_id;
+
+UPDATE chains SET connection_details = jsonb_set(connection_details, '{port_id}', config->'port_id') WHERE connection_details IS NOT NULL;
@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS pending_acks (
+    chain_id TEXT NOT NULL,
+    port_id TEXT NOT NULL,
+    channel_id TEXT NOT NULL,
+    sequence BIGINT NOT NULL,
+    packet BYTEA NOT NULL,
+    packet_height BIGINT NOT NULL,
+    request_id TEXT,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    failed BOOLEAN NOT NULL DEFAULT FALSE,
+    last_error TEXT,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (chain_id, port_id, channel_id, sequence)
+);
@@ -0,0 +1,11 @@
+ALTER TABLE operations ALTER COLUMN amount TYPE BYTEA USING decode(
+    lpad(to_hex((div(amount::NUMERIC, 1) % 256)::INTEGER), 2, '0')
+    || lpad(to_hex((div(amount::NUMERIC, 256) % 256)::INTEGER), 2, '0')
+    || lpad(to_hex((div(amount::NUMERIC, 65536) % 256)::INTEGER), 2, '0')
+    || lpad(to_hex((div(amount::NUMERIC, 16777216) % 256)::INTEGER), 2, '0')
+    || lpad(to_hex((div(amount::NUMERIC, 4294967296) % 256)::INTEGER), 2, '0')
+    || lpad(to_hex((div(amount::NUMERIC, 1099511627776) % 256)::INTEGER), 2, '0')
+    || lpad(to_hex((div(amount::NUMERIC, 281474976710656) % 256)::INTEGER), 2, '0')
+    || lpad(to_hex((div(amount::NUMERIC, 72057594037927936) % 256)::INTEGER), 2, '0'),
+    'hex'
+);
@@ -0,0 +1,10 @@
+ALTER TABLE operations ALTER COLUMN amount TYPE TEXT USING (
+    get_byte(amount, 0)::NUMERIC
+    + get_byte(amount, 1)::NUMERIC * 256
+    + get_byte(amount, 2)::NUMERIC * 65536
+    + get_byte(amount, 3)::NUMERIC * 16777216
+    + get_byte(amount, 4)::NUMERIC * 4294967296
+    + get_byte(amount, 5)::NUMERIC * 1099511627776
+    + get_byte(amount, 6)::NUMERIC * 281474976710656
+    + get_byte(amount, 7)::NUMERIC * 72057594037927936
+)::TEXT;
@@ -0,0 +1 @@
+ALTER TABLE connections ADD COLUMN packet_search_height BIGINT NOT NULL DEFAULT 1;
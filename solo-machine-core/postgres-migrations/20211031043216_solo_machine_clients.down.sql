@@ -0,0 +1,33 @@
+-- Chains take over the sequence of their default solo machine client and the highest packet sequence of their channels
+ALTER TABLE chains ADD COLUMN sequence BIGINT NOT NULL DEFAULT 1;
+ALTER TABLE chains ADD COLUMN packet_sequence BIGINT NOT NULL DEFAULT 1;
+
+UPDATE chains SET
+    sequence = COALESCE((
+        SELECT sequence FROM solo_machine_clients
+        WHERE chain_id = chains.id AND client_id = chains.connection_details->>'solo_machine_client_id'
+    ), 1),
+    packet_sequence = COALESCE((SELECT MAX(packet_sequence) FROM connections WHERE chain_id = chains.id), 1);
+
+CREATE TABLE IF NOT EXISTS sign_ledger_old (
+    chain_id TEXT NOT NULL,
+    sequence BIGINT NOT NULL,
+    data_type INTEGER NOT NULL,
+    data_hash TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (chain_id, sequence)
+);
+
+INSERT INTO sign_ledger_old (chain_id, sequence, data_type, data_hash, created_at)
+SELECT sign_ledger.chain_id, sign_ledger.sequence, sign_ledger.data_type, sign_ledger.data_hash, sign_ledger.created_at
+FROM sign_ledger
+INNER JOIN chains ON chains.id = sign_ledger.chain_id
+WHERE sign_ledger.client_id = chains.connection_details->>'solo_machine_client_id';
+
+DROP TABLE sign_ledger;
+
+ALTER TABLE sign_ledger_old RENAME TO sign_ledger;
+
+ALTER TABLE connections DROP COLUMN packet_sequence;
+
+DROP TABLE solo_machine_clients;
@@ -0,0 +1,48 @@
+-- Sequences are tracked per solo machine client (a chain can have multiple clients created by `connect --force`). All
+-- the existing clients of a chain start from the sequence which was shared by them.
+CREATE TABLE IF NOT EXISTS solo_machine_clients (
+    chain_id TEXT NOT NULL,
+    client_id TEXT NOT NULL,
+    sequence BIGINT NOT NULL DEFAULT 1,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (chain_id, client_id)
+);
+
+INSERT INTO solo_machine_clients (chain_id, client_id, sequence)
+SELECT clients.chain_id, clients.client_id, chains.sequence
+FROM (
+    SELECT chain_id, solo_machine_client_id AS client_id FROM connections
+    UNION
+    SELECT chain_id, solo_machine_client_id AS client_id FROM connection_handshakes WHERE solo_machine_client_id IS NOT NULL
+) AS clients
+INNER JOIN chains ON chains.id = clients.chain_id;
+
+-- Packet sequences are tracked per channel
+ALTER TABLE connections ADD COLUMN packet_sequence BIGINT NOT NULL DEFAULT 1;
+
+UPDATE connections SET packet_sequence = (SELECT packet_sequence FROM chains WHERE chains.id = connections.chain_id);
+
+-- Sign-ledger entries are recorded per solo machine client. Existing entries were signed for the shared sequence, so,
+-- they're kept for every client of the chain.
+CREATE TABLE IF NOT EXISTS sign_ledger_new (
+    chain_id TEXT NOT NULL,
+    client_id TEXT NOT NULL,
+    sequence BIGINT NOT NULL,
+    data_type INTEGER NOT NULL,
+    data_hash TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (chain_id, client_id, sequence)
+);
+
+INSERT INTO sign_ledger_new (chain_id, client_id, sequence, data_type, data_hash, created_at)
+SELECT sign_ledger.chain_id, solo_machine_clients.client_id, sign_ledger.sequence, sign_ledger.data_type, sign_ledger.data_hash, sign_ledger.created_at
+FROM sign_ledger
+INNER JOIN solo_machine_clients ON solo_machine_clients.chain_id = sign_ledger.chain_id;
+
+DROP TABLE sign_ledger;
+
+ALTER TABLE sign_ledger_new RENAME TO sign_ledger;
+
+ALTER TABLE chains DROP COLUMN sequence;
+ALTER TABLE chains DROP COLUMN packet_sequence;
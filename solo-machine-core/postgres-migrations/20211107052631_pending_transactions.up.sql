@@ -0,0 +1,11 @@
+-- Transactions whose outcome was unknown when they were broadcasted (i.e., they were not included in a block before
+-- timeout) along with the operations to be finalized once they're included
+CREATE TABLE IF NOT EXISTS pending_transactions (
+    transaction_hash TEXT PRIMARY KEY,
+    chain_id TEXT NOT NULL,
+    request_id TEXT,
+    operation JSONB NOT NULL,
+    status TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
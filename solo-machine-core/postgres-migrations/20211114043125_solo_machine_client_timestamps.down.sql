@@ -0,0 +1,5 @@
+-- Chains take over the latest consensus timestamp of their solo machine clients (signatures with an older timestamp are
+-- rejected by solo machine clients on IBC enabled chain)
+UPDATE chains SET consensus_timestamp = COALESCE((SELECT MAX(consensus_timestamp) FROM solo_machine_clients WHERE chain_id = chains.id), consensus_timestamp);
+
+ALTER TABLE solo_machine_clients DROP COLUMN consensus_timestamp;
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS light_blocks (
+    chain_id TEXT NOT NULL,
+    height BIGINT NOT NULL,
+    status TEXT NOT NULL,
+    light_block TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (chain_id, height, status)
+);
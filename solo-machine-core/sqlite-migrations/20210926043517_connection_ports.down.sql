@@ -0,0 +1,3 @@
+UPDATE chains SET connection_details = json_remove(connection_details, '$.port_id') WHERE connection_details IS NOT NULL;
+
+ALTER TABLE connections DROP COLUMN port_id;
@@ -0,0 +1,69 @@
+-- Amounts are converted back to u64 little endian bytes (only amounts which fit in 63 bits can be converted)
+CREATE TABLE IF NOT EXISTS operations_old (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    request_id TEXT,
+    address TEXT NOT NULL,
+    denom TEXT NOT NULL,
+    amount BLOB NOT NULL,
+    operation_type TEXT NOT NULL,
+    transaction_hash TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+WITH bytes (value, byte) AS (
+    VALUES
+        (0, X'00'), (1, X'01'), (2, X'02'), (3, X'03'), (4, X'04'), (5, X'05'), (6, X'06'), (7, X'07'),
+        (8, X'08'), (9, X'09'), (10, X'0A'), (11, X'0B'), (12, X'0C'), (13, X'0D'), (14, X'0E'), (15, X'0F'),
+        (16, X'10'), (17, X'11'), (18, X'12'), (19, X'13'), (20, X'14'), (21, X'15'), (22, X'16'), (23, X'17'),
+        (24, X'18'), (25, X'19'), (26, X'1A'), (27, X'1B'), (28, X'1C'), (29, X'1D'), (30, X'1E'), (31, X'1F'),
+        (32, X'20'), (33, X'21'), (34, X'22'), (35, X'23'), (36, X'24'), (37, X'25'), (38, X'26'), (39, X'27'),
+        (40, X'28'), (41, X'29'), (42, X'2A'), (43, X'2B'), (44, X'2C'), (45, X'2D'), (46, X'2E'), (47, X'2F'),
+        (48, X'30'), (49, X'31'), (50, X'32'), (51, X'33'), (52, X'34'), (53, X'35'), (54, X'36'), (55, X'37'),
+        (56, X'38'), (57, X'39'), (58, X'3A'), (59, X'3B'), (60, X'3C'), (61, X'3D'), (62, X'3E'), (63, X'3F'),
+        (64, X'40'), (65, X'41'), (66, X'42'), (67, X'43'), (68, X'44'), (69, X'45'), (70, X'46'), (71, X'47'),
+        (72, X'48'), (73, X'49'), (74, X'4A'), (75, X'4B'), (76, X'4C'), (77, X'4D'), (78, X'4E'), (79, X'4F'),
+        (80, X'50'), (81, X'51'), (82, X'52'), (83, X'53'), (84, X'54'), (85, X'55'), (86, X'56'), (87, X'57'),
+        (88, X'58'), (89, X'59'), (90, X'5A'), (91, X'5B'), (92, X'5C'), (93, X'5D'), (94, X'5E'), (95, X'5F'),
+        (96, X'60'), (97, X'61'), (98, X'62'), (99, X'63'), (100, X'64'), (101, X'65'), (102, X'66'), (103, X'67'),
+        (104, X'68'), (105, X'69'), (106, X'6A'), (107, X'6B'), (108, X'6C'), (109, X'6D'), (110, X'6E'), (111, X'6F'),
+        (112, X'70'), (113, X'71'), (114, X'72'), (115, X'73'), (116, X'74'), (117, X'75'), (118, X'76'), (119, X'77'),
+        (120, X'78'), (121, X'79'), (122, X'7A'), (123, X'7B'), (124, X'7C'), (125, X'7D'), (126, X'7E'), (127, X'7F'),
+        (128, X'80'), (129, X'81'), (130, X'82'), (131, X'83'), (132, X'84'), (133, X'85'), (134, X'86'), (135, X'87'),
+        (136, X'88'), (137, X'89'), (138, X'8A'), (139, X'8B'), (140, X'8C'), (141, X'8D'), (142, X'8E'), (143, X'8F'),
+        (144, X'90'), (145, X'91'), (146, X'92'), (147, X'93'), (148, X'94'), (149, X'95'), (150, X'96'), (151, X'97'),
+        (152, X'98'), (153, X'99'), (154, X'9A'), (155, X'9B'), (156, X'9C'), (157, X'9D'), (158, X'9E'), (159, X'9F'),
+        (160, X'A0'), (161, X'A1'), (162, X'A2'), (163, X'A3'), (164, X'A4'), (165, X'A5'), (166, X'A6'), (167, X'A7'),
+        (168, X'A8'), (169, X'A9'), (170, X'AA'), (171, X'AB'), (172, X'AC'), (173, X'AD'), (174, X'AE'), (175, X'AF'),
+        (176, X'B0'), (177, X'B1'), (178, X'B2'), (179, X'B3'), (180, X'B4'), (181, X'B5'), (182, X'B6'), (183, X'B7'),
+        (184, X'B8'), (185, X'B9'), (186, X'BA'), (187, X'BB'), (188, X'BC'), (189, X'BD'), (190, X'BE'), (191, X'BF'),
+        (192, X'C0'), (193, X'C1'), (194, X'C2'), (195, X'C3'), (196, X'C4'), (197, X'C5'), (198, X'C6'), (199, X'C7'),
+        (200, X'C8'), (201, X'C9'), (202, X'CA'), (203, X'CB'), (204, X'CC'), (205, X'CD'), (206, X'CE'), (207, X'CF'),
+        (208, X'D0'), (209, X'D1'), (210, X'D2'), (211, X'D3'), (212, X'D4'), (213, X'D5'), (214, X'D6'), (215, X'D7'),
+        (216, X'D8'), (217, X'D9'), (218, X'DA'), (219, X'DB'), (220, X'DC'), (221, X'DD'), (222, X'DE'), (223, X'DF'),
+        (224, X'E0'), (225, X'E1'), (226, X'E2'), (227, X'E3'), (228, X'E4'), (229, X'E5'), (230, X'E6'), (231, X'E7'),
+        (232, X'E8'), (233, X'E9'), (234, X'EA'), (235, X'EB'), (236, X'EC'), (237, X'ED'), (238, X'EE'), (239, X'EF'),
+        (240, X'F0'), (241, X'F1'), (242, X'F2'), (243, X'F3'), (244, X'F4'), (245, X'F5'), (246, X'F6'), (247, X'F7'),
+        (248, X'F8'), (249, X'F9'), (250, X'FA'), (251, X'FB'), (252, X'FC'), (253, X'FD'), (254, X'FE'), (255, X'FF')
+)
+INSERT INTO operations_old (id, request_id, address, denom, amount, operation_type, transaction_hash, created_at)
+SELECT
+    id,
+    request_id,
+    address,
+    denom,
+    CAST((SELECT byte FROM bytes WHERE value = (amount >> 0) & 255)
+        || (SELECT byte FROM bytes WHERE value = (amount >> 8) & 255)
+        || (SELECT byte FROM bytes WHERE value = (amount >> 16) & 255)
+        || (SELECT byte FROM bytes WHERE value = (amount >> 24) & 255)
+        || (SELECT byte FROM bytes WHERE value = (amount >> 32) & 255)
+        || (SELECT byte FROM bytes WHERE value = (amount >> 40) & 255)
+        || (SELECT byte FROM bytes WHERE value = (amount >> 48) & 255)
+        || (SELECT byte FROM bytes WHERE value = (amount >> 56) & 255) AS BLOB),
+    operation_type,
+    transaction_hash,
+    created_at
+FROM (SELECT id, request_id, address, denom, CAST(amount AS INTEGER) AS amount, operation_type, transaction_hash, created_at FROM operations);
+
+DROP TABLE operations;
+
+ALTER TABLE operations_old RENAME TO operations;
@@ -0,0 +1,51 @@
+-- Amounts were stored as u64 little endian bytes, they're now stored as decimal strings (to support amounts up to 256
+-- bits). The high byte is handled separately (in base 10^9) so that the conversion does not overflow 64-bit integers.
+CREATE TABLE IF NOT EXISTS operations_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    request_id TEXT,
+    address TEXT NOT NULL,
+    denom TEXT NOT NULL,
+    amount TEXT NOT NULL,
+    operation_type TEXT NOT NULL,
+    transaction_hash TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO operations_new (id, request_id, address, denom, amount, operation_type, transaction_hash, created_at)
+SELECT
+    id,
+    request_id,
+    address,
+    denom,
+    CASE WHEN high = 0 THEN CAST(low AS TEXT) ELSE CAST(high AS TEXT) || printf('%09d', low) END,
+    operation_type,
+    transaction_hash,
+    created_at
+FROM (
+    SELECT
+        *,
+        b7 * 72057594 + low_bytes / 1000000000 + (b7 * 37927936 + low_bytes % 1000000000) / 1000000000 AS high,
+        (b7 * 37927936 + low_bytes % 1000000000) % 1000000000 AS low
+    FROM (
+        SELECT
+            *,
+            b0 + b1 * 256 + b2 * 65536 + b3 * 16777216 + b4 * 4294967296 + b5 * 1099511627776 + b6 * 281474976710656 AS low_bytes
+        FROM (
+            SELECT
+                *,
+                instr('0123456789ABCDEF', substr(hex(amount), 1, 1)) * 16 + instr('0123456789ABCDEF', substr(hex(amount), 2, 1)) - 17 AS b0,
+                instr('0123456789ABCDEF', substr(hex(amount), 3, 1)) * 16 + instr('0123456789ABCDEF', substr(hex(amount), 4, 1)) - 17 AS b1,
+                instr('0123456789ABCDEF', substr(hex(amount), 5, 1)) * 16 + instr('0123456789ABCDEF', substr(hex(amount), 6, 1)) - 17 AS b2,
+                instr('0123456789ABCDEF', substr(hex(amount), 7, 1)) * 16 + instr('0123456789ABCDEF', substr(hex(amount), 8, 1)) - 17 AS b3,
+                instr('0123456789ABCDEF', substr(hex(amount), 9, 1)) * 16 + instr('0123456789ABCDEF', substr(hex(amount), 10, 1)) - 17 AS b4,
+                instr('0123456789ABCDEF', substr(hex(amount), 11, 1)) * 16 + instr('0123456789ABCDEF', substr(hex(amount), 12, 1)) - 17 AS b5,
+                instr('0123456789ABCDEF', substr(hex(amount), 13, 1)) * 16 + instr('0123456789ABCDEF', substr(hex(amount), 14, 1)) - 17 AS b6,
+                instr('0123456789ABCDEF', substr(hex(amount), 15, 1)) * 16 + instr('0123456789ABCDEF', substr(hex(amount), 16, 1)) - 17 AS b7
+            FROM operations
+        )
+    )
+);
+
+DROP TABLE operations;
+
+ALTER TABLE operations_new RENAME TO operations;
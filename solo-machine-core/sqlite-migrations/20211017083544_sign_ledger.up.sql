@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS sign_ledger (
+    chain_id TEXT NOT NULL,
+    sequence BIGINT NOT NULL,
+    data_type INTEGER NOT NULL,
+    data_hash TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (chain_id, sequence)
+);
@@ -0,0 +1 @@
+ALTER TABLE connections DROP COLUMN packet_search_height;
@@ -0,0 +1,6 @@
+-- Consensus timestamp is tracked per solo machine client and advanced whenever solo machine signs for the client (the
+-- timestamp of last signature becomes the consensus timestamp of client on IBC enabled chain). Existing clients start
+-- from the consensus timestamp of their chain.
+ALTER TABLE solo_machine_clients ADD COLUMN consensus_timestamp DATETIME NOT NULL DEFAULT '1970-01-01 00:00:00+00:00';
+
+UPDATE solo_machine_clients SET consensus_timestamp = (SELECT consensus_timestamp FROM chains WHERE chains.id = solo_machine_clients.chain_id);
@@ -7,16 +7,29 @@ use cosmos_sdk_proto::cosmos::crypto::multisig::v1beta1::CompactBitArray;
 const MASK: u8 = 0b1000_0000;
 
 pub trait BitArray {
+    fn new(len: usize) -> Self
+    where
+        Self: Sized;
+
     fn is_empty(&self) -> bool;
 
     fn len(&self) -> usize;
 
     fn get(&self, index: usize) -> bool;
 
+    fn set(&mut self, index: usize, value: bool) -> bool;
+
     fn num_true_bits_before(&self, index: usize) -> usize;
 }
 
 impl BitArray for CompactBitArray {
+    fn new(len: usize) -> Self {
+        Self {
+            extra_bits_stored: u32::try_from(len % 8).unwrap(),
+            elems: vec![0; len.div_ceil(8)],
+        }
+    }
+
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
@@ -37,6 +50,20 @@ impl BitArray for CompactBitArray {
         (self.elems[index >> 3] & (MASK >> (index & 7))) > 0 // equivalent to `(self.elems[index / 8] & (MASK >> (index % 8)))`
     }
 
+    fn set(&mut self, index: usize, value: bool) -> bool {
+        if index >= self.len() {
+            return false;
+        }
+
+        if value {
+            self.elems[index >> 3] |= MASK >> (index & 7);
+        } else {
+            self.elems[index >> 3] &= !(MASK >> (index & 7));
+        }
+
+        true
+    }
+
     fn num_true_bits_before(&self, index: usize) -> usize {
         let mut num_true_values = 0;
 
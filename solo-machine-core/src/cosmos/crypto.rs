@@ -94,7 +94,10 @@ impl PublicKey {
             Self::EthSecp256k1(key) => hex::encode_upper(key.to_bytes()),
             Self::Secp256k1(key) => hex::encode_upper(key.to_bytes()),
             Self::Ed25519(key) => hex::encode_upper(key.as_bytes()),
-            Self::Multisig(_) => "unsupported key type".to_string(),
+            Self::Multisig(key) => LegacyAminoPubKey::try_from(key)
+                .and_then(|key| proto_encode(&key))
+                .map(hex::encode_upper)
+                .unwrap_or_else(|_| "unsupported key type".to_string()),
         }
     }
 
@@ -7,7 +7,10 @@ use tokio::sync::mpsc::UnboundedSender;
 
 use crate::{
     cosmos::crypto::PublicKey,
-    ibc::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, Identifier},
+    ibc::{
+        apps::transfer::{amount::Amount, denom::Denom},
+        core::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId},
+    },
     model::ConnectionDetails,
 };
 
@@ -28,9 +31,26 @@ pub enum Event {
         /// Address of account on IBC enabled chain
         to_address: String,
         /// Amount of tokens minted
-        amount: u64,
+        amount: Amount,
         /// Denom of tokens minted
-        denom: Identifier,
+        denom: Denom,
+        /// Hash of transaction on IBC enabled chain (in hex)
+        transaction_hash: String,
+    },
+    /// Failed to mint tokens on IBC enabled chain (i.e., IBC enabled chain sent an error acknowledgement)
+    MintFailed {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Optional request ID (for tracking purposes)
+        request_id: Option<String>,
+        /// Address of account on IBC enabled chain
+        to_address: String,
+        /// Amount of tokens
+        amount: Amount,
+        /// Denom of tokens
+        denom: Denom,
+        /// Error in acknowledgement sent by IBC enabled chain
+        error: String,
         /// Hash of transaction on IBC enabled chain (in hex)
         transaction_hash: String,
     },
@@ -43,9 +63,59 @@ pub enum Event {
         /// Address of account on IBC enabled chain
         from_address: String,
         /// Amount of tokens minted
-        amount: u64,
+        amount: Amount,
         /// Denom of tokens minted
-        denom: Identifier,
+        denom: Denom,
+        /// Hash of transaction on IBC enabled chain (in hex)
+        transaction_hash: String,
+    },
+    /// Refunded burnt tokens on IBC enabled chain (after IBC packet timed out)
+    TokensRefunded {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Optional request ID (for tracking purposes)
+        request_id: Option<String>,
+        /// Address of account on IBC enabled chain
+        to_address: String,
+        /// Amount of tokens refunded
+        amount: Amount,
+        /// Denom of tokens refunded
+        denom: Denom,
+        /// Hash of transaction on IBC enabled chain (in hex)
+        transaction_hash: String,
+    },
+    /// Redeemed tokens sent from IBC enabled chain to solo machine (by any account on IBC enabled chain)
+    TokensRedeemed {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Address of account on IBC enabled chain
+        from_address: String,
+        /// Address of receiver on solo machine
+        to_address: String,
+        /// Amount of tokens redeemed
+        amount: Amount,
+        /// Denom of tokens redeemed
+        denom: Denom,
+        /// Hash of transaction on IBC enabled chain (in hex)
+        transaction_hash: String,
+    },
+    /// Rejected tokens sent from IBC enabled chain to solo machine (as per acceptance policy) by sending an error
+    /// acknowledgement (so that IBC enabled chain refunds the sender)
+    PacketRejected {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Channel ID of solo machine client on IBC enabled chain
+        channel_id: ChannelId,
+        /// Sequence of packet
+        sequence: u64,
+        /// Address of account on IBC enabled chain
+        from_address: String,
+        /// Amount of tokens rejected
+        amount: Amount,
+        /// Denom of tokens rejected
+        denom: Denom,
+        /// Reason for rejecting the packet
+        reason: String,
         /// Hash of transaction on IBC enabled chain (in hex)
         transaction_hash: String,
     },
@@ -58,6 +128,79 @@ pub enum Event {
         /// New signer's public key
         new_public_key: PublicKey,
     },
+    /// Updated diversifier of solo machine on IBC enabled chain for future messages from solo machine
+    DiversifierUpdated {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Old diversifier
+        old_diversifier: String,
+        /// New diversifier
+        new_diversifier: String,
+    },
+    /// Updated tendermint client on solo machine to a newer verified header of IBC enabled chain
+    TendermintClientUpdated {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Client ID of IBC enabled chain on solo machine
+        client_id: ClientId,
+        /// New latest height of tendermint client (in `{revision_number}-{revision_height}` format)
+        latest_height: String,
+    },
+    /// Acknowledged a packet sent from IBC enabled chain to solo machine
+    PacketAcknowledged {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Channel ID of solo machine client on IBC enabled chain
+        channel_id: ChannelId,
+        /// Sequence of packet
+        sequence: u64,
+        /// Hash of transaction on IBC enabled chain (in hex)
+        transaction_hash: String,
+    },
+    /// Failed to acknowledge a packet sent from IBC enabled chain to solo machine after all the retry attempts (the
+    /// packet can still be retried using `force`)
+    PacketAckFailed {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Channel ID of solo machine client on IBC enabled chain
+        channel_id: ChannelId,
+        /// Sequence of packet
+        sequence: u64,
+        /// Number of failed attempts
+        attempts: u32,
+        /// Error in the last attempt
+        error: String,
+    },
+    /// Solo machine client on IBC enabled chain is frozen (all the IBC operations fail until it is recovered using a
+    /// client update proposal)
+    ClientFrozen {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Client ID of solo machine client on IBC enabled chain
+        client_id: ClientId,
+        /// Sequence of frozen solo machine client
+        sequence: u64,
+    },
+    /// Created a substitute solo machine client on IBC enabled chain for recovering a frozen client
+    SubstituteClientCreated {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Client ID of frozen solo machine client on IBC enabled chain
+        subject_client_id: ClientId,
+        /// Client ID of substitute solo machine client on IBC enabled chain
+        substitute_client_id: ClientId,
+    },
+    /// Recovered frozen solo machine client on IBC enabled chain (after client update proposal passed)
+    ClientRecovered {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Client ID of recovered solo machine client on IBC enabled chain
+        client_id: ClientId,
+        /// Sequence of recovered solo machine client
+        sequence: u64,
+        /// Signer's public key of recovered solo machine client
+        public_key: PublicKey,
+    },
 
     // ----- IBC connection handshake events ----- //
     /// Created solo machine client on IBC enabled chain
@@ -117,6 +260,31 @@ pub enum Event {
         /// Connection details
         connection_details: ConnectionDetails,
     },
+    /// Accepted an IBC connection initialized by counterparty on IBC enabled chain
+    ConnectionAccepted {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Connection ID of solo machine client on IBC enabled chain
+        solo_machine_connection_id: ConnectionId,
+        /// Connection ID of IBC enabled chain on solo machine
+        tendermint_connection_id: ConnectionId,
+    },
+    /// Opened a new IBC channel on an existing connection with IBC enabled chain
+    ChannelOpened {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Connection details of new channel
+        connection_details: ConnectionDetails,
+    },
+    /// Closed IBC channel with IBC enabled chain
+    ChannelClosed {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Channel ID of solo machine client on IBC enabled chain
+        solo_machine_channel_id: ChannelId,
+        /// Channel ID of IBC enabled chain on solo machine
+        tendermint_channel_id: ChannelId,
+    },
 
     // ----- Chain events ----- //
     /// Added new chain metadata to solo machine
@@ -1 +1,4 @@
+pub mod amount;
+pub mod denom;
+pub mod forward;
 pub mod msg_transfer;
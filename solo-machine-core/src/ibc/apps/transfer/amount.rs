@@ -0,0 +1,127 @@
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+use anyhow::{ensure, Error};
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+
+const MAX_AMOUNT_BITS: u64 = 256;
+
+/// Amount of fungible tokens (an unsigned integer of at most 256 bits encoded as a decimal string)
+///
+/// # Specs
+///
+/// <https://github.com/cosmos/ibc/tree/master/spec/app/ics-020-fungible-token-transfer#data-structures>
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Amount(BigUint);
+
+impl Amount {
+    /// Returns `true` if the amount is zero
+    pub fn is_zero(&self) -> bool {
+        self.0 == BigUint::default()
+    }
+}
+
+impl FromStr for Amount {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ensure!(!s.is_empty(), "amount cannot be blank");
+        ensure!(
+            s.bytes().all(|byte| byte.is_ascii_digit()),
+            "amount {} must contain only decimal digits",
+            s
+        );
+
+        let amount: BigUint = s.parse()?;
+
+        ensure!(
+            amount.bits() <= MAX_AMOUNT_BITS,
+            "amount {} cannot be larger than {} bits",
+            s,
+            MAX_AMOUNT_BITS
+        );
+
+        Ok(Self(amount))
+    }
+}
+
+impl TryFrom<String> for Amount {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<u64> for Amount {
+    fn from(value: u64) -> Self {
+        Self(value.into())
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Amount> for String {
+    fn from(amount: Amount) -> Self {
+        amount.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX_AMOUNT: &str =
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+    const OVERFLOW_AMOUNT: &str =
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+
+    #[test]
+    fn test_zero_amount() {
+        let amount: Amount = "0".parse().unwrap();
+
+        assert!(amount.is_zero());
+        assert_eq!(amount.to_string(), "0");
+        assert_eq!(amount, Amount::from(0));
+    }
+
+    #[test]
+    fn test_max_amount() {
+        let amount: Amount = MAX_AMOUNT.parse().unwrap();
+
+        assert!(!amount.is_zero());
+        assert_eq!(amount.to_string(), MAX_AMOUNT);
+    }
+
+    #[test]
+    fn test_overflow_amount() {
+        assert!(OVERFLOW_AMOUNT.parse::<Amount>().is_err());
+    }
+
+    #[test]
+    fn test_invalid_amount() {
+        for amount in ["", "+1", "-1", " 1", "1.0", "1e3", "0x10", "one"].iter() {
+            assert!(amount.parse::<Amount>().is_err(), "{}", amount);
+        }
+    }
+
+    #[test]
+    fn test_amount_serde() {
+        let amount: Amount = serde_json::from_str(&format!("\"{}\"", MAX_AMOUNT)).unwrap();
+
+        assert_eq!(amount.to_string(), MAX_AMOUNT);
+        assert_eq!(
+            serde_json::to_string(&amount).unwrap(),
+            format!("\"{}\"", MAX_AMOUNT)
+        );
+
+        assert!(serde_json::from_str::<Amount>(&format!("\"{}\"", OVERFLOW_AMOUNT)).is_err());
+        assert!(serde_json::from_str::<Amount>("\"-1\"").is_err());
+        assert!(serde_json::from_str::<Amount>("\"\"").is_err());
+    }
+}
@@ -0,0 +1,247 @@
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+use anyhow::{ensure, Error};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::ibc::core::ics24_host::identifier::{ChannelId, PortId};
+
+const MAX_BASE_DENOM_LEN: usize = 128;
+const VALID_BASE_DENOM_PATTERN: &str = r"^[a-zA-Z0-9/:\._\+\-\#\[\]<>]+$";
+
+static VALID_BASE_DENOM_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(VALID_BASE_DENOM_PATTERN).unwrap());
+
+/// Denomination of fungible tokens along with its trace, i.e., list of port and channel pairs through which the tokens
+/// were transferred (e.g., `transfer/channel-5/uatom`)
+///
+/// # Specs
+///
+/// <https://github.com/cosmos/ibc/tree/master/spec/app/ics-020-fungible-token-transfer#data-structures>
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Denom {
+    trace_path: Vec<(PortId, ChannelId)>,
+    base_denom: String,
+}
+
+impl Denom {
+    /// Returns base denomination (i.e., denomination on the chain where tokens were issued)
+    pub fn base_denom(&self) -> &str {
+        &self.base_denom
+    }
+
+    /// Returns `true` if the tokens were issued on current chain (i.e., trace path is empty)
+    pub fn is_native(&self) -> bool {
+        self.trace_path.is_empty()
+    }
+
+    /// Returns `true` if the last hop of tokens was over given port and channel
+    pub fn has_prefix(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        matches!(self.trace_path.first(), Some((port, channel)) if port == port_id && channel == channel_id)
+    }
+
+    /// Returns denomination after adding given port and channel to the start of trace path
+    pub fn add_prefix(&self, port_id: &PortId, channel_id: &ChannelId) -> Self {
+        let mut trace_path = Vec::with_capacity(self.trace_path.len() + 1);
+        trace_path.push((port_id.clone(), channel_id.clone()));
+        trace_path.extend(self.trace_path.iter().cloned());
+
+        Self {
+            trace_path,
+            base_denom: self.base_denom.clone(),
+        }
+    }
+
+    /// Returns denomination after removing the first port and channel from trace path (returns `None` if the tokens
+    /// are native)
+    pub fn remove_prefix(&self) -> Option<Self> {
+        if self.is_native() {
+            return None;
+        }
+
+        Some(Self {
+            trace_path: self.trace_path[1..].to_vec(),
+            base_denom: self.base_denom.clone(),
+        })
+    }
+
+    /// Returns the denomination of tokens in bank module (i.e., base denomination for native tokens and `ibc/{hash}`
+    /// for vouchers)
+    pub fn ibc_denom(&self) -> String {
+        if self.is_native() {
+            return self.base_denom.clone();
+        }
+
+        let hash = Sha256::digest(self.to_string().as_bytes());
+        format!("ibc/{}", hex::encode_upper(hash))
+    }
+}
+
+impl FromStr for Denom {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ensure!(!s.trim().is_empty(), "denom cannot be blank");
+
+        let segments = s.split('/').collect::<Vec<_>>();
+        let mut trace_path = Vec::new();
+        let mut index = 0;
+
+        // A pair of segments is a part of trace path only if both of them are valid identifiers and the second one is
+        // a channel identifier, remaining segments form the base denomination (which may also contain '/')
+        while index + 2 < segments.len() {
+            match (
+                segments[index].parse::<PortId>(),
+                segments[index + 1].parse::<ChannelId>(),
+            ) {
+                (Ok(port_id), Ok(channel_id)) if channel_id.starts_with("channel-") => {
+                    trace_path.push((port_id, channel_id));
+                    index += 2;
+                }
+                _ => break,
+            }
+        }
+
+        let base_denom = segments[index..].join("/");
+
+        ensure!(
+            !base_denom.is_empty() && base_denom.len() <= MAX_BASE_DENOM_LEN,
+            "base denom {} has invalid length: {}, must be between 1-{} characters",
+            base_denom,
+            base_denom.len(),
+            MAX_BASE_DENOM_LEN
+        );
+
+        ensure!(
+            segments[index..].iter().all(|segment| !segment.is_empty()),
+            "base denom {} cannot contain empty segments",
+            base_denom
+        );
+
+        ensure!(VALID_BASE_DENOM_REGEX.is_match(&base_denom), "base denom {} must contain only alphanumeric or the following characters: '/', ':', '.', '_', '+', '-', '#', '[', ']', '<', '>'", base_denom);
+
+        Ok(Self {
+            trace_path,
+            base_denom,
+        })
+    }
+}
+
+impl TryFrom<String> for Denom {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for Denom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (port_id, channel_id) in self.trace_path.iter() {
+            write!(f, "{}/{}/", port_id, channel_id)?;
+        }
+
+        write!(f, "{}", self.base_denom)
+    }
+}
+
+impl From<Denom> for String {
+    fn from(denom: Denom) -> Self {
+        denom.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_native_denom() {
+        let denom: Denom = "uatom".parse().unwrap();
+
+        assert!(denom.is_native());
+        assert_eq!(denom.base_denom(), "uatom");
+        assert_eq!(denom.ibc_denom(), "uatom");
+        assert_eq!(denom.to_string(), "uatom");
+        assert!(denom.remove_prefix().is_none());
+    }
+
+    #[test]
+    fn test_single_hop_denom() {
+        let denom: Denom = "transfer/channel-0/uatom".parse().unwrap();
+
+        assert!(!denom.is_native());
+        assert_eq!(denom.base_denom(), "uatom");
+        assert!(denom.has_prefix(&"transfer".parse().unwrap(), &"channel-0".parse().unwrap()));
+        assert!(!denom.has_prefix(&"transfer".parse().unwrap(), &"channel-1".parse().unwrap()));
+        assert_eq!(
+            denom.ibc_denom(),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+        assert_eq!(denom.remove_prefix().unwrap().to_string(), "uatom");
+    }
+
+    #[test]
+    fn test_multi_hop_denom() {
+        let denom: Denom = "transfer/channel-1/transfer/channel-2/gamm/pool/1"
+            .parse()
+            .unwrap();
+
+        assert_eq!(denom.base_denom(), "gamm/pool/1");
+        assert!(denom.has_prefix(&"transfer".parse().unwrap(), &"channel-1".parse().unwrap()));
+        assert_eq!(
+            denom.to_string(),
+            "transfer/channel-1/transfer/channel-2/gamm/pool/1"
+        );
+
+        let unprefixed = denom.remove_prefix().unwrap();
+
+        assert_eq!(unprefixed.to_string(), "transfer/channel-2/gamm/pool/1");
+        assert!(unprefixed.has_prefix(&"transfer".parse().unwrap(), &"channel-2".parse().unwrap()));
+        assert_eq!(
+            unprefixed.remove_prefix().unwrap().to_string(),
+            "gamm/pool/1"
+        );
+
+        let prefixed =
+            unprefixed.add_prefix(&"transfer".parse().unwrap(), &"channel-1".parse().unwrap());
+
+        assert_eq!(prefixed, denom);
+    }
+
+    #[test]
+    fn test_denom_without_channel_segment() {
+        let denom: Denom = "transfer/channel-0".parse().unwrap();
+
+        assert!(denom.is_native());
+        assert_eq!(denom.base_denom(), "transfer/channel-0");
+
+        let denom: Denom = "transfer/connection-0/uatom".parse().unwrap();
+
+        assert!(denom.is_native());
+        assert_eq!(denom.base_denom(), "transfer/connection-0/uatom");
+    }
+
+    #[test]
+    fn test_invalid_denom() {
+        for denom in [
+            "",
+            " ",
+            "transfer/channel-0/",
+            "uatom/",
+            "u atom",
+            "transfer//uatom",
+        ]
+        .iter()
+        {
+            assert!(denom.parse::<Denom>().is_err(), "{}", denom);
+        }
+
+        assert!(format!("u{}", "a".repeat(MAX_BASE_DENOM_LEN))
+            .parse::<Denom>()
+            .is_err());
+    }
+}
@@ -0,0 +1,36 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::ibc::core::ics24_host::identifier::{ChannelId, PortId};
+
+/// Metadata for forwarding tokens received on IBC enabled chain to another chain (using packet-forward middleware)
+///
+/// # Specs
+///
+/// <https://github.com/strangelove-ventures/packet-forward-middleware>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForwardMetadata {
+    /// Address of receiver on the next chain
+    pub receiver: String,
+    /// Port ID of channel (on IBC enabled chain) over which tokens are forwarded
+    pub port: PortId,
+    /// Channel ID of channel (on IBC enabled chain) over which tokens are forwarded
+    pub channel: ChannelId,
+}
+
+impl ForwardMetadata {
+    /// Creates new forward metadata
+    pub fn new(receiver: String, port: PortId, channel: ChannelId) -> Self {
+        Self {
+            receiver,
+            port,
+            channel,
+        }
+    }
+
+    /// Returns packet memo (i.e., `{"forward": {"receiver": ..., "port": ..., "channel": ...}}`) for forwarding tokens
+    pub fn to_memo(&self) -> Result<String> {
+        serde_json::to_string(&json!({ "forward": self })).context("unable to encode forward memo")
+    }
+}
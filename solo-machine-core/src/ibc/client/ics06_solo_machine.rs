@@ -1,3 +1,253 @@
 pub mod v1;
-#[cfg(feature = "solomachine-v2")]
 pub mod v2;
+pub mod v3;
+
+use std::{fmt, str::FromStr};
+
+use anyhow::{anyhow, Error, Result};
+use cosmos_sdk_proto::ibc::core::{channel::v1::Channel, connection::v1::ConnectionEnd};
+use prost_types::Any;
+use serde::{Deserialize, Serialize};
+
+/// Version of solo machine client protocol spoken with an IBC enabled chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SoloMachineVersion {
+    /// `ibc.lightclients.solomachine.v1` (IBC module of cosmos-sdk v0.40 to v0.42)
+    V1,
+    /// `ibc.lightclients.solomachine.v2` (ibc-go v1 to v6)
+    V2,
+    /// `ibc.lightclients.solomachine.v3` (ibc-go v7 onwards)
+    V3,
+}
+
+// Deriving `Default` for enums (using `#[default]`) requires Rust 1.62
+#[allow(clippy::derivable_impls)]
+impl Default for SoloMachineVersion {
+    fn default() -> Self {
+        Self::V2
+    }
+}
+
+impl SoloMachineVersion {
+    /// Returns name of version (i.e., `v1`, `v2` or `v3`)
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::V1 => "v1",
+            Self::V2 => "v2",
+            Self::V3 => "v3",
+        }
+    }
+
+    /// Encodes given client state as `Any` (with `allow_update_after_proposal` set for versions which have it, so
+    /// that a frozen client can be recovered using a client update proposal)
+    pub fn encode_client_state(&self, client_state: &ClientState) -> Result<Any> {
+        match self {
+            Self::V1 => v1::encode_client_state(client_state),
+            Self::V2 => v2::encode_client_state(client_state),
+            Self::V3 => v3::encode_client_state(client_state),
+        }
+    }
+
+    /// Decodes client state from given `Any`
+    pub fn decode_client_state(&self, value: &Any) -> Result<ClientState> {
+        match self {
+            Self::V1 => v1::decode_client_state(value),
+            Self::V2 => v2::decode_client_state(value),
+            Self::V3 => v3::decode_client_state(value),
+        }
+    }
+
+    /// Encodes given consensus state as `Any`
+    pub fn encode_consensus_state(&self, consensus_state: &ConsensusState) -> Result<Any> {
+        match self {
+            Self::V1 => v1::encode_consensus_state(consensus_state),
+            Self::V2 => v2::encode_consensus_state(consensus_state),
+            Self::V3 => v3::encode_consensus_state(consensus_state),
+        }
+    }
+
+    /// Encodes given header as `Any`
+    pub fn encode_header(&self, header: &Header) -> Result<Any> {
+        match self {
+            Self::V1 => v1::encode_header(header),
+            Self::V2 => v2::encode_header(header),
+            Self::V3 => v3::encode_header(header),
+        }
+    }
+
+    /// Returns protobuf encoded `SignBytes` for given data
+    pub fn encode_sign_bytes(
+        &self,
+        sequence: u64,
+        timestamp: u64,
+        diversifier: String,
+        data: SignData,
+    ) -> Result<Vec<u8>> {
+        match self {
+            Self::V1 => v1::encode_sign_bytes(sequence, timestamp, diversifier, data),
+            Self::V2 => v2::encode_sign_bytes(sequence, timestamp, diversifier, data),
+            Self::V3 => v3::encode_sign_bytes(sequence, timestamp, diversifier, data),
+        }
+    }
+
+    /// Decodes protobuf encoded `SignBytes`
+    pub fn decode_sign_bytes(&self, sign_bytes: &[u8]) -> Result<DecodedSignBytes> {
+        match self {
+            Self::V1 => v1::decode_sign_bytes(sign_bytes),
+            Self::V2 => v2::decode_sign_bytes(sign_bytes),
+            Self::V3 => v3::decode_sign_bytes(sign_bytes),
+        }
+    }
+
+    /// Returns protobuf encoded `TimestampedSignatureData` (i.e., proof) for given signature data
+    pub fn encode_timestamped_signature_data(
+        &self,
+        signature_data: Vec<u8>,
+        timestamp: u64,
+    ) -> Result<Vec<u8>> {
+        match self {
+            Self::V1 => v1::encode_timestamped_signature_data(signature_data, timestamp),
+            Self::V2 => v2::encode_timestamped_signature_data(signature_data, timestamp),
+            Self::V3 => v3::encode_timestamped_signature_data(signature_data, timestamp),
+        }
+    }
+}
+
+impl FromStr for SoloMachineVersion {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "v1" => Ok(Self::V1),
+            "v2" => Ok(Self::V2),
+            "v3" => Ok(Self::V3),
+            _ => Err(anyhow!(
+                "invalid solo machine version: {}, must be one of: v1, v2, v3",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for SoloMachineVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Client state of solo machine client (common to all the versions)
+#[derive(Debug, Clone)]
+pub struct ClientState {
+    /// Latest sequence of solo machine client
+    pub sequence: u64,
+    /// Whether solo machine client is frozen
+    pub is_frozen: bool,
+    /// Consensus state of solo machine client
+    pub consensus_state: Option<ConsensusState>,
+}
+
+/// Consensus state of solo machine client (common to all the versions)
+#[derive(Debug, Clone)]
+pub struct ConsensusState {
+    /// Public key of solo machine
+    pub public_key: Option<Any>,
+    /// Diversifier of solo machine
+    pub diversifier: String,
+    /// Consensus timestamp of solo machine
+    pub timestamp: u64,
+}
+
+/// Header of solo machine client (common to all the versions, `sequence` is not included in v3 headers)
+#[derive(Debug, Clone)]
+pub struct Header {
+    /// Sequence of solo machine
+    pub sequence: u64,
+    /// Consensus timestamp of solo machine
+    pub timestamp: u64,
+    /// Signature over `SignBytes` of header data
+    pub signature: Vec<u8>,
+    /// New public key of solo machine
+    pub new_public_key: Option<Any>,
+    /// New diversifier of solo machine
+    pub new_diversifier: String,
+}
+
+/// Data signed by solo machine. Paths are prefixed with commitment prefix of IBC enabled chain.
+///
+/// In v1 and v2, path and value are wrapped in a version specific message (e.g., `ConnectionStateData`) along with
+/// the type of data. In v3, path and protobuf encoded value are signed directly (without any type of data).
+#[derive(Debug, Clone)]
+pub enum SignData {
+    /// Client state of tendermint client on solo machine
+    ClientState {
+        /// Path of client state
+        path: Vec<u8>,
+        /// Client state
+        client_state: Any,
+    },
+    /// Consensus state of tendermint client on solo machine
+    ConsensusState {
+        /// Path of consensus state
+        path: Vec<u8>,
+        /// Consensus state
+        consensus_state: Any,
+    },
+    /// Connection on solo machine
+    ConnectionState {
+        /// Path of connection
+        path: Vec<u8>,
+        /// Connection
+        connection: ConnectionEnd,
+    },
+    /// Channel on solo machine
+    ChannelState {
+        /// Path of channel
+        path: Vec<u8>,
+        /// Channel
+        channel: Channel,
+    },
+    /// Commitment of a packet sent from solo machine
+    PacketCommitment {
+        /// Path of packet commitment
+        path: Vec<u8>,
+        /// Packet commitment
+        commitment: Vec<u8>,
+    },
+    /// Acknowledgement of a packet received on solo machine
+    PacketAcknowledgement {
+        /// Path of packet acknowledgement
+        path: Vec<u8>,
+        /// Acknowledgement (v3 signs its commitment, i.e., sha256 hash, instead)
+        acknowledgement: Vec<u8>,
+    },
+    /// Absence of receipt of a packet on solo machine
+    PacketReceiptAbsence {
+        /// Path of packet receipt
+        path: Vec<u8>,
+    },
+    /// New public key and diversifier of solo machine
+    Header {
+        /// New public key
+        new_public_key: Option<Any>,
+        /// New diversifier
+        new_diversifier: String,
+    },
+}
+
+/// Decoded `SignBytes` (common to all the versions)
+#[derive(Debug, Clone)]
+pub struct DecodedSignBytes {
+    /// Sequence of solo machine
+    pub sequence: u64,
+    /// Consensus timestamp of solo machine
+    pub timestamp: u64,
+    /// Diversifier of solo machine
+    pub diversifier: String,
+    /// Type of data (always `0`, i.e., unspecified, in v3)
+    pub data_type: i32,
+    /// Path of data (always empty in v1 and v2, where path is a part of data)
+    pub path: Vec<u8>,
+    /// Signed data
+    pub data: Vec<u8>,
+}
@@ -1,3 +1,171 @@
 pub mod client_state;
 pub mod consensus_state;
 pub mod header;
+
+use anyhow::{anyhow, Result};
+use prost::Message;
+use prost_types::Any;
+
+use crate::proto::{
+    ibc::lightclients::solomachine::v2::{
+        ChannelStateData, ClientState, ClientStateData, ConnectionStateData, ConsensusState,
+        ConsensusStateData, DataType, Header, HeaderData, PacketAcknowledgementData,
+        PacketCommitmentData, PacketReceiptAbsenceData, SignBytes, TimestampedSignatureData,
+    },
+    proto_encode, AnyConvert,
+};
+
+use super::{
+    ClientState as SoloMachineClientState, ConsensusState as SoloMachineConsensusState,
+    DecodedSignBytes, Header as SoloMachineHeader, SignData,
+};
+
+pub(super) fn encode_client_state(client_state: &SoloMachineClientState) -> Result<Any> {
+    ClientState {
+        sequence: client_state.sequence,
+        is_frozen: client_state.is_frozen,
+        consensus_state: client_state.consensus_state.as_ref().map(consensus_state),
+        allow_update_after_proposal: true,
+    }
+    .to_any()
+}
+
+pub(super) fn decode_client_state(value: &Any) -> Result<SoloMachineClientState> {
+    let client_state = ClientState::from_any(value)?;
+
+    Ok(SoloMachineClientState {
+        sequence: client_state.sequence,
+        is_frozen: client_state.is_frozen,
+        consensus_state: client_state.consensus_state.map(|consensus_state| {
+            SoloMachineConsensusState {
+                public_key: consensus_state.public_key,
+                diversifier: consensus_state.diversifier,
+                timestamp: consensus_state.timestamp,
+            }
+        }),
+    })
+}
+
+pub(super) fn encode_consensus_state(consensus_state: &SoloMachineConsensusState) -> Result<Any> {
+    self::consensus_state(consensus_state).to_any()
+}
+
+pub(super) fn encode_header(header: &SoloMachineHeader) -> Result<Any> {
+    Header {
+        sequence: header.sequence,
+        timestamp: header.timestamp,
+        signature: header.signature.clone(),
+        new_public_key: header.new_public_key.clone(),
+        new_diversifier: header.new_diversifier.clone(),
+    }
+    .to_any()
+}
+
+pub(super) fn encode_sign_bytes(
+    sequence: u64,
+    timestamp: u64,
+    diversifier: String,
+    data: SignData,
+) -> Result<Vec<u8>> {
+    let (data_type, data) = match data {
+        SignData::ClientState { path, client_state } => (
+            DataType::ClientState,
+            proto_encode(&ClientStateData {
+                path,
+                client_state: Some(client_state),
+            })?,
+        ),
+        SignData::ConsensusState {
+            path,
+            consensus_state,
+        } => (
+            DataType::ConsensusState,
+            proto_encode(&ConsensusStateData {
+                path,
+                consensus_state: Some(consensus_state),
+            })?,
+        ),
+        SignData::ConnectionState { path, connection } => (
+            DataType::ConnectionState,
+            proto_encode(&ConnectionStateData {
+                path,
+                connection: Some(connection),
+            })?,
+        ),
+        SignData::ChannelState { path, channel } => (
+            DataType::ChannelState,
+            proto_encode(&ChannelStateData {
+                path,
+                channel: Some(channel),
+            })?,
+        ),
+        SignData::PacketCommitment { path, commitment } => (
+            DataType::PacketCommitment,
+            proto_encode(&PacketCommitmentData { path, commitment })?,
+        ),
+        SignData::PacketAcknowledgement {
+            path,
+            acknowledgement,
+        } => (
+            DataType::PacketAcknowledgement,
+            proto_encode(&PacketAcknowledgementData {
+                path,
+                acknowledgement,
+            })?,
+        ),
+        SignData::PacketReceiptAbsence { path } => (
+            DataType::PacketReceiptAbsence,
+            proto_encode(&PacketReceiptAbsenceData { path })?,
+        ),
+        SignData::Header {
+            new_public_key,
+            new_diversifier,
+        } => (
+            DataType::Header,
+            proto_encode(&HeaderData {
+                new_pub_key: new_public_key,
+                new_diversifier,
+            })?,
+        ),
+    };
+
+    proto_encode(&SignBytes {
+        sequence,
+        timestamp,
+        diversifier,
+        data_type: data_type.into(),
+        data,
+    })
+}
+
+pub(super) fn decode_sign_bytes(sign_bytes: &[u8]) -> Result<DecodedSignBytes> {
+    let sign_bytes = SignBytes::decode(sign_bytes)
+        .map_err(|err| anyhow!("unable to decode sign bytes: {}", err))?;
+
+    Ok(DecodedSignBytes {
+        sequence: sign_bytes.sequence,
+        timestamp: sign_bytes.timestamp,
+        diversifier: sign_bytes.diversifier,
+        data_type: sign_bytes.data_type,
+        path: Vec::new(),
+        data: sign_bytes.data,
+    })
+}
+
+pub(super) fn encode_timestamped_signature_data(
+    signature_data: Vec<u8>,
+    timestamp: u64,
+) -> Result<Vec<u8>> {
+    proto_encode(&TimestampedSignatureData {
+        signature_data,
+        timestamp,
+    })
+}
+
+fn consensus_state(consensus_state: &SoloMachineConsensusState) -> ConsensusState {
+    ConsensusState {
+        public_key: consensus_state.public_key.clone(),
+        diversifier: consensus_state.diversifier.clone(),
+        timestamp: consensus_state.timestamp,
+    }
+}
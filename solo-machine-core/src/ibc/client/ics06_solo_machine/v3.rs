@@ -0,0 +1,138 @@
+pub mod client_state;
+pub mod consensus_state;
+pub mod header;
+
+use anyhow::{anyhow, Result};
+use prost::Message;
+use prost_types::Any;
+use sha2::{Digest, Sha256};
+
+use crate::proto::{
+    ibc::lightclients::solomachine::v3::{
+        ClientState, ConsensusState, Header, HeaderData, SignBytes, TimestampedSignatureData,
+    },
+    proto_encode, AnyConvert,
+};
+
+use super::{
+    ClientState as SoloMachineClientState, ConsensusState as SoloMachineConsensusState,
+    DecodedSignBytes, Header as SoloMachineHeader, SignData,
+};
+
+/// Path used in `SignBytes` of header (i.e., update of public key and diversifier of solo machine)
+const SENTINEL_HEADER_PATH: &[u8] = b"solomachine:header";
+
+pub(super) fn encode_client_state(client_state: &SoloMachineClientState) -> Result<Any> {
+    ClientState {
+        sequence: client_state.sequence,
+        is_frozen: client_state.is_frozen,
+        consensus_state: client_state.consensus_state.as_ref().map(consensus_state),
+    }
+    .to_any()
+}
+
+pub(super) fn decode_client_state(value: &Any) -> Result<SoloMachineClientState> {
+    let client_state = ClientState::from_any(value)?;
+
+    Ok(SoloMachineClientState {
+        sequence: client_state.sequence,
+        is_frozen: client_state.is_frozen,
+        consensus_state: client_state.consensus_state.map(|consensus_state| {
+            SoloMachineConsensusState {
+                public_key: consensus_state.public_key,
+                diversifier: consensus_state.diversifier,
+                timestamp: consensus_state.timestamp,
+            }
+        }),
+    })
+}
+
+pub(super) fn encode_consensus_state(consensus_state: &SoloMachineConsensusState) -> Result<Any> {
+    self::consensus_state(consensus_state).to_any()
+}
+
+pub(super) fn encode_header(header: &SoloMachineHeader) -> Result<Any> {
+    Header {
+        timestamp: header.timestamp,
+        signature: header.signature.clone(),
+        new_public_key: header.new_public_key.clone(),
+        new_diversifier: header.new_diversifier.clone(),
+    }
+    .to_any()
+}
+
+/// In v3, IBC enabled chain verifies membership (or non-membership) of protobuf encoded values (and hashes in case
+/// of packet commitments and acknowledgements) at given paths directly, i.e., without wrapping them in any other
+/// message.
+pub(super) fn encode_sign_bytes(
+    sequence: u64,
+    timestamp: u64,
+    diversifier: String,
+    data: SignData,
+) -> Result<Vec<u8>> {
+    let (path, data) = match data {
+        SignData::ClientState { path, client_state } => (path, proto_encode(&client_state)?),
+        SignData::ConsensusState {
+            path,
+            consensus_state,
+        } => (path, proto_encode(&consensus_state)?),
+        SignData::ConnectionState { path, connection } => (path, proto_encode(&connection)?),
+        SignData::ChannelState { path, channel } => (path, proto_encode(&channel)?),
+        SignData::PacketCommitment { path, commitment } => (path, commitment),
+        SignData::PacketAcknowledgement {
+            path,
+            acknowledgement,
+        } => (path, Sha256::digest(&acknowledgement).to_vec()),
+        SignData::PacketReceiptAbsence { path } => (path, Vec::new()),
+        SignData::Header {
+            new_public_key,
+            new_diversifier,
+        } => (
+            SENTINEL_HEADER_PATH.to_vec(),
+            proto_encode(&HeaderData {
+                new_pub_key: new_public_key,
+                new_diversifier,
+            })?,
+        ),
+    };
+
+    proto_encode(&SignBytes {
+        sequence,
+        timestamp,
+        diversifier,
+        path,
+        data,
+    })
+}
+
+pub(super) fn decode_sign_bytes(sign_bytes: &[u8]) -> Result<DecodedSignBytes> {
+    let sign_bytes = SignBytes::decode(sign_bytes)
+        .map_err(|err| anyhow!("unable to decode sign bytes: {}", err))?;
+
+    Ok(DecodedSignBytes {
+        sequence: sign_bytes.sequence,
+        timestamp: sign_bytes.timestamp,
+        diversifier: sign_bytes.diversifier,
+        data_type: 0,
+        path: sign_bytes.path,
+        data: sign_bytes.data,
+    })
+}
+
+pub(super) fn encode_timestamped_signature_data(
+    signature_data: Vec<u8>,
+    timestamp: u64,
+) -> Result<Vec<u8>> {
+    proto_encode(&TimestampedSignatureData {
+        signature_data,
+        timestamp,
+    })
+}
+
+fn consensus_state(consensus_state: &SoloMachineConsensusState) -> ConsensusState {
+    ConsensusState {
+        public_key: consensus_state.public_key.clone(),
+        diversifier: consensus_state.diversifier.clone(),
+        timestamp: consensus_state.timestamp,
+    }
+}
@@ -0,0 +1,5 @@
+use crate::proto::ibc::core::client::v1::ClientUpdateProposal;
+
+const TYPE_URL: &str = "/ibc.core.client.v1.ClientUpdateProposal";
+
+impl_any_conversion!(ClientUpdateProposal, TYPE_URL);
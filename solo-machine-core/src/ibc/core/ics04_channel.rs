@@ -1,5 +1,8 @@
+pub mod channel;
 pub mod msg_acknowledgement;
+pub mod msg_channel_close_confirm;
 pub mod msg_channel_open_ack;
 pub mod msg_channel_open_init;
 pub mod msg_recv_packet;
+pub mod msg_timeout;
 pub mod packet;
@@ -0,0 +1,55 @@
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+use anyhow::{anyhow, Error, Result};
+use cosmos_sdk_proto::ibc::core::channel::v1::Order;
+
+/// Ordering of packets in an IBC channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelOrdering {
+    /// Packets can be delivered in any order
+    Unordered,
+    /// Packets are delivered exactly in the order which they were sent
+    Ordered,
+}
+
+impl From<ChannelOrdering> for Order {
+    fn from(ordering: ChannelOrdering) -> Self {
+        match ordering {
+            ChannelOrdering::Unordered => Order::Unordered,
+            ChannelOrdering::Ordered => Order::Ordered,
+        }
+    }
+}
+
+impl TryFrom<Order> for ChannelOrdering {
+    type Error = Error;
+
+    fn try_from(order: Order) -> Result<Self, Self::Error> {
+        match order {
+            Order::Unordered => Ok(Self::Unordered),
+            Order::Ordered => Ok(Self::Ordered),
+            Order::NoneUnspecified => Err(anyhow!("channel ordering is not specified")),
+        }
+    }
+}
+
+impl fmt::Display for ChannelOrdering {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unordered => write!(f, "unordered"),
+            Self::Ordered => write!(f, "ordered"),
+        }
+    }
+}
+
+impl FromStr for ChannelOrdering {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "unordered" => Ok(Self::Unordered),
+            "ordered" => Ok(Self::Ordered),
+            _ => Err(anyhow!("invalid channel ordering: {}", s)),
+        }
+    }
+}
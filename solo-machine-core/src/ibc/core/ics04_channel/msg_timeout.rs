@@ -0,0 +1,5 @@
+use cosmos_sdk_proto::ibc::core::channel::v1::MsgTimeout;
+
+const TYPE_URL: &str = "/ibc.core.channel.v1.MsgTimeout";
+
+impl_any_conversion!(MsgTimeout, TYPE_URL);
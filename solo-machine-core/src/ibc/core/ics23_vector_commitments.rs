@@ -1,4 +1,10 @@
-use cosmos_sdk_proto::ics23::{HashOp, InnerSpec, LeafOp, LengthOp, ProofSpec};
+use anyhow::{anyhow, bail, ensure, Result};
+use cosmos_sdk_proto::ics23::{
+    commitment_proof::Proof, CommitmentProof, ExistenceProof, HashOp, InnerOp, InnerSpec, LeafOp,
+    LengthOp, ProofSpec,
+};
+use ripemd160::Ripemd160;
+use sha2::{Digest, Sha256, Sha512};
 
 fn tendermint_spec() -> ProofSpec {
     ProofSpec {
@@ -47,3 +53,211 @@ fn iavl_spec() -> ProofSpec {
 pub fn proof_specs() -> Vec<ProofSpec> {
     vec![iavl_spec(), tendermint_spec()]
 }
+
+/// Verifies that `value` is stored at given key path (ordered from root store to leaf store, e.g., `["ibc",
+/// "commitments/..."]`) in merkle tree with given `root` using chained existence proofs (ordered from leaf store to
+/// root store)
+pub fn verify_membership(
+    specs: &[ProofSpec],
+    root: &[u8],
+    proofs: &[CommitmentProof],
+    keys: &[&[u8]],
+    value: &[u8],
+) -> Result<()> {
+    ensure!(
+        specs.len() == proofs.len() && keys.len() == proofs.len(),
+        "number of proofs ({}), proof specs ({}) and keys ({}) should be equal",
+        proofs.len(),
+        specs.len(),
+        keys.len()
+    );
+    ensure!(!proofs.is_empty(), "proofs cannot be empty");
+
+    let mut value = value.to_vec();
+
+    for (i, (proof, spec)) in proofs.iter().zip(specs.iter()).enumerate() {
+        let proof = match proof.proof {
+            Some(Proof::Exist(ref existence_proof)) => existence_proof,
+            _ => bail!("expected existence proof at index {}", i),
+        };
+
+        let key = keys[keys.len() - 1 - i];
+
+        ensure!(
+            proof.key == key,
+            "key in existence proof does not match provided key"
+        );
+        ensure!(
+            proof.value == value,
+            "value in existence proof does not match provided value"
+        );
+
+        check_existence_spec(proof, spec)?;
+
+        value = calculate_existence_root(proof)?;
+    }
+
+    ensure!(
+        value == root,
+        "calculated root does not match provided merkle root"
+    );
+
+    Ok(())
+}
+
+fn check_existence_spec(proof: &ExistenceProof, spec: &ProofSpec) -> Result<()> {
+    let leaf = proof
+        .leaf
+        .as_ref()
+        .ok_or_else(|| anyhow!("leaf op is missing in existence proof"))?;
+    let leaf_spec = spec
+        .leaf_spec
+        .as_ref()
+        .ok_or_else(|| anyhow!("leaf spec is missing in proof spec"))?;
+    let inner_spec = spec
+        .inner_spec
+        .as_ref()
+        .ok_or_else(|| anyhow!("inner spec is missing in proof spec"))?;
+
+    ensure!(
+        leaf.hash == leaf_spec.hash
+            && leaf.prehash_key == leaf_spec.prehash_key
+            && leaf.prehash_value == leaf_spec.prehash_value
+            && leaf.length == leaf_spec.length,
+        "leaf op in existence proof does not match proof spec"
+    );
+    ensure!(
+        leaf.prefix.starts_with(&leaf_spec.prefix),
+        "invalid leaf prefix in existence proof"
+    );
+
+    if spec.min_depth > 0 {
+        ensure!(
+            proof.path.len() >= spec.min_depth as usize,
+            "existence proof is too short"
+        );
+    }
+
+    if spec.max_depth > 0 {
+        ensure!(
+            proof.path.len() <= spec.max_depth as usize,
+            "existence proof is too long"
+        );
+    }
+
+    for inner in proof.path.iter() {
+        check_inner_op(inner, inner_spec, &leaf_spec.prefix)?;
+    }
+
+    Ok(())
+}
+
+fn check_inner_op(inner: &InnerOp, inner_spec: &InnerSpec, leaf_prefix: &[u8]) -> Result<()> {
+    ensure!(
+        inner.hash == inner_spec.hash,
+        "inner op in existence proof does not match proof spec"
+    );
+    ensure!(
+        leaf_prefix.is_empty() || !inner.prefix.starts_with(leaf_prefix),
+        "inner prefix in existence proof starts with leaf prefix"
+    );
+    ensure!(
+        inner.prefix.len() >= inner_spec.min_prefix_length as usize,
+        "inner prefix in existence proof is too short"
+    );
+
+    let max_left_child_bytes =
+        inner_spec.child_order.len().saturating_sub(1) * inner_spec.child_size as usize;
+
+    ensure!(
+        inner.prefix.len() <= inner_spec.max_prefix_length as usize + max_left_child_bytes,
+        "inner prefix in existence proof is too long"
+    );
+
+    Ok(())
+}
+
+fn calculate_existence_root(proof: &ExistenceProof) -> Result<Vec<u8>> {
+    let leaf = proof
+        .leaf
+        .as_ref()
+        .ok_or_else(|| anyhow!("leaf op is missing in existence proof"))?;
+
+    let mut hash = apply_leaf(leaf, &proof.key, &proof.value)?;
+
+    for inner in proof.path.iter() {
+        hash = apply_inner(inner, &hash)?;
+    }
+
+    Ok(hash)
+}
+
+fn apply_leaf(leaf: &LeafOp, key: &[u8], value: &[u8]) -> Result<Vec<u8>> {
+    ensure!(!key.is_empty(), "leaf op needs a key");
+    ensure!(!value.is_empty(), "leaf op needs a value");
+
+    let mut data = leaf.prefix.clone();
+    data.extend(prepare_leaf_data(leaf.prehash_key, leaf.length, key)?);
+    data.extend(prepare_leaf_data(leaf.prehash_value, leaf.length, value)?);
+
+    do_hash(leaf.hash, &data)
+}
+
+fn apply_inner(inner: &InnerOp, child: &[u8]) -> Result<Vec<u8>> {
+    ensure!(!child.is_empty(), "inner op needs a child value");
+
+    let mut data = inner.prefix.clone();
+    data.extend(child);
+    data.extend(&inner.suffix);
+
+    do_hash(inner.hash, &data)
+}
+
+fn prepare_leaf_data(prehash: i32, length: i32, data: &[u8]) -> Result<Vec<u8>> {
+    let hashed = do_hash(prehash, data)?;
+    do_length(length, &hashed)
+}
+
+fn do_hash(hash_op: i32, data: &[u8]) -> Result<Vec<u8>> {
+    match HashOp::from_i32(hash_op) {
+        Some(HashOp::NoHash) => Ok(data.to_vec()),
+        Some(HashOp::Sha256) => Ok(Sha256::digest(data).to_vec()),
+        Some(HashOp::Sha512) => Ok(Sha512::digest(data).to_vec()),
+        Some(HashOp::Ripemd160) => Ok(Ripemd160::digest(data).to_vec()),
+        Some(HashOp::Bitcoin) => Ok(Ripemd160::digest(&Sha256::digest(data)).to_vec()),
+        Some(hash_op) => bail!("unsupported hash op: {:?}", hash_op),
+        None => bail!("invalid hash op: {}", hash_op),
+    }
+}
+
+fn do_length(length_op: i32, data: &[u8]) -> Result<Vec<u8>> {
+    match LengthOp::from_i32(length_op) {
+        Some(LengthOp::NoPrefix) => Ok(data.to_vec()),
+        Some(LengthOp::VarProto) => {
+            let mut result = Vec::with_capacity(data.len() + 10);
+            prost::encoding::encode_varint(data.len() as u64, &mut result);
+            result.extend(data);
+            Ok(result)
+        }
+        Some(LengthOp::Fixed32Big) => {
+            let mut result = (data.len() as u32).to_be_bytes().to_vec();
+            result.extend(data);
+            Ok(result)
+        }
+        Some(LengthOp::Fixed32Little) => {
+            let mut result = (data.len() as u32).to_le_bytes().to_vec();
+            result.extend(data);
+            Ok(result)
+        }
+        Some(LengthOp::Require32Bytes) => {
+            ensure!(data.len() == 32, "data should be exactly 32 bytes");
+            Ok(data.to_vec())
+        }
+        Some(LengthOp::Require64Bytes) => {
+            ensure!(data.len() == 64, "data should be exactly 64 bytes");
+            Ok(data.to_vec())
+        }
+        Some(length_op) => bail!("unsupported length op: {:?}", length_op),
+        None => bail!("invalid length op: {}", length_op),
+    }
+}
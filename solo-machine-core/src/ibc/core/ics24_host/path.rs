@@ -242,18 +242,6 @@ impl PacketCommitmentPath {
     }
 }
 
-impl_path!("Denom trace of tokens transferred to IBC chain", DenomTrace);
-
-impl DenomTrace {
-    pub fn new(port_id: &PortId, channel_id: &ChannelId, denom: &Identifier) -> Self {
-        Self(
-            format!("{}/{}/{}", port_id, channel_id, denom)
-                .parse()
-                .unwrap(),
-        )
-    }
-}
-
 impl_path!(
     "Path for storing packet acknowledgements",
     PacketAcknowledgementPath
@@ -271,3 +259,18 @@ impl PacketAcknowledgementPath {
         )
     }
 }
+
+impl_path!("Path for storing packet receipts", PacketReceiptPath);
+
+impl PacketReceiptPath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId, packet_sequence: u64) -> Self {
+        Self(
+            format!(
+                "receipts/ports/{}/channels/{}/sequences/{}",
+                port_id, channel_id, packet_sequence
+            )
+            .parse()
+            .unwrap(),
+        )
+    }
+}
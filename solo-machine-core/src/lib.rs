@@ -7,6 +7,7 @@ pub mod cosmos;
 pub mod event;
 pub mod ibc;
 pub mod model;
+pub mod policy;
 pub mod service;
 pub mod signer;
 pub(crate) mod transaction_builder;
@@ -1,12 +1,22 @@
 //! Data types used by solo machine
 pub(crate) mod chain;
 pub(crate) mod ibc;
+pub(crate) mod light_block;
 pub(crate) mod operation;
+pub(crate) mod pending_ack;
+pub(crate) mod pending_transaction;
+pub(crate) mod sign_ledger;
 
 pub use self::{
     chain::{
         chain_keys::ChainKey,
-        {Chain, ChainConfig, ConnectionDetails, Fee},
+        handshake::{Handshake, HandshakeStep},
+        solo_machine_client::SoloMachineClient,
+        {BroadcastMode, Chain, ChainConfig, ConnectionDetails, Fee, GasMode},
     },
+    light_block::DbLightStore,
     operation::{Operation, OperationType},
+    pending_ack::PendingAck,
+    pending_transaction::{PendingOperation, PendingTransaction, PendingTransactionStatus},
+    sign_ledger::SignLedgerEntry,
 };
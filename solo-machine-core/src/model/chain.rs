@@ -1,5 +1,8 @@
 #[allow(clippy::module_inception)]
 mod chain;
 pub mod chain_keys;
+pub mod connection;
+pub mod handshake;
+pub mod solo_machine_client;
 
 pub use self::chain::*;
@@ -1,5 +1,6 @@
 use std::{
     convert::{TryFrom, TryInto},
+    fmt,
     time::Duration,
 };
 
@@ -11,14 +12,16 @@ use cosmos_sdk_proto::cosmos::bank::v1beta1::{
 use num_rational::Ratio;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
 use sqlx::{types::Json, Executor, FromRow};
 use tendermint::{block::Height as BlockHeight, node::Id as NodeId};
 
 use crate::{
-    ibc::core::ics24_host::{
-        identifier::{ChainId, ChannelId, ClientId, ConnectionId, Identifier, PortId},
-        path::DenomTrace,
+    ibc::{
+        apps::transfer::denom::Denom,
+        client::ics06_solo_machine::SoloMachineVersion,
+        core::ics24_host::identifier::{
+            ChainId, ChannelId, ClientId, ConnectionId, Identifier, PortId,
+        },
     },
     Db, ToPublicKey,
 };
@@ -32,12 +35,9 @@ pub struct Chain {
     pub node_id: NodeId,
     /// Configuration for chain
     pub config: ChainConfig,
-    /// Consensus timestamp of solo machine (used when creating transactions on chain)
+    /// Consensus timestamp of solo machine (used when creating solo machine clients on chain, each client then tracks
+    /// its own consensus timestamp)
     pub consensus_timestamp: DateTime<Utc>,
-    /// Sequence of solo machine (used when creating transactions on chain)
-    pub sequence: u32,
-    /// Packet sequence of solo machine (used when creating transactions on chain)
-    pub packet_sequence: u32,
     /// IBC connection details
     pub connection_details: Option<ConnectionDetails>,
     /// Creation time of chain
@@ -47,27 +47,18 @@ pub struct Chain {
 }
 
 impl Chain {
-    /// Returns the IBC denom of given denomination based on connection details. Returns `None` if connection details
-    /// are not present.
-    pub fn get_ibc_denom(&self, denom: &Identifier) -> Option<String> {
-        let connection_details = self.connection_details.as_ref()?;
-
-        let denom_trace = DenomTrace::new(
-            &self.config.port_id,
-            &connection_details.solo_machine_channel_id,
-            denom,
-        );
-
-        let hash = Sha256::digest(denom_trace.to_string().as_bytes());
-
-        Some(format!("ibc/{}", hex::encode_upper(hash)))
+    /// Returns the IBC denom of given denomination based on default connection details. Returns `None` if connection
+    /// details are not present.
+    pub fn get_ibc_denom(&self, denom: &Denom) -> Option<String> {
+        Some(self.connection_details.as_ref()?.get_ibc_denom(denom))
     }
 
-    /// Fetches on-chain balance of given denom
+    /// Fetches on-chain balance of given denom sent over the channel in given connection details
     pub async fn get_balance(
         &self,
         signer: impl ToPublicKey,
-        denom: &Identifier,
+        connection_details: &ConnectionDetails,
+        denom: &Denom,
     ) -> Result<Decimal> {
         let mut query_client = BankQueryClient::connect(self.config.grpc_addr.clone())
             .await
@@ -76,13 +67,9 @@ impl Chain {
                 self.config.grpc_addr
             ))?;
 
-        let denom = self
-            .get_ibc_denom(denom)
-            .ok_or_else(|| anyhow!("connection details not found when fetching balance"))?;
-
         let request = QueryBalanceRequest {
             address: signer.to_account_address()?,
-            denom,
+            denom: connection_details.get_ibc_denom(denom),
         };
 
         Ok(query_client
@@ -105,12 +92,9 @@ struct RawChain {
     pub node_id: String,
     /// Configuration for chain
     pub config: Json<ChainConfig>,
-    /// Consensus timestamp of solo machine (used when creating transactions on chain)
+    /// Consensus timestamp of solo machine (used when creating solo machine clients on chain, each client then tracks
+    /// its own consensus timestamp)
     pub consensus_timestamp: DateTime<Utc>,
-    /// Sequence of solo machine (used when creating transactions on chain)
-    pub sequence: i64,
-    /// Packet sequence of solo machine (used when creating transactions on chain)
-    pub packet_sequence: i64,
     /// IBC connection details
     pub connection_details: Option<Json<ConnectionDetails>>,
     /// Creation time of chain
@@ -145,6 +129,29 @@ pub struct ChainConfig {
     /// Block hash at trusted height of the chain
     #[serde(with = "hex::serde")]
     pub trusted_hash: [u8; 32],
+    /// Number of blocks (of IBC enabled chain or solo machine, depending on the destination of packet) after which an
+    /// IBC packet times out
+    #[serde(default = "default_packet_timeout_height_offset")]
+    pub packet_timeout_height_offset: u64,
+    /// Duration after which an IBC packet times out (zero disables timestamp based timeouts). For packets sent to solo
+    /// machine, this is relative to consensus timestamp of solo machine client (which is advanced to current time
+    /// whenever solo machine signs for the client).
+    #[serde(default)]
+    pub packet_timeout_timestamp_offset: Duration,
+    /// Mode of computing gas limit and fee of transactions on chain
+    #[serde(default)]
+    pub gas_mode: GasMode,
+    /// Strategy for broadcasting transactions to chain
+    #[serde(default)]
+    pub broadcast_mode: BroadcastMode,
+    /// Version of solo machine client protocol spoken with chain (chains added before it was configurable use v2)
+    #[serde(default)]
+    pub solo_machine_version: SoloMachineVersion,
+}
+
+/// Default packet timeout height offset for chains added before it was configurable
+fn default_packet_timeout_height_offset() -> u64 {
+    10
 }
 
 /// Fee and gas configuration
@@ -156,11 +163,125 @@ pub struct Fee {
     pub denom: Identifier,
     /// Gas limit
     pub gas_limit: u64,
+    /// Address of fee granter (fees are deducted from granter's allowance using feegrant module)
+    #[serde(default)]
+    pub granter: Option<String>,
+}
+
+/// Mode of computing gas limit and fee of transactions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GasMode {
+    /// Use static fee and gas limit (from fee configuration) for all the transactions
+    Static,
+    /// Simulate each transaction on chain to estimate its gas limit and fee (falls back to static fee and gas limit
+    /// if simulation fails)
+    Simulate {
+        /// Multiplier applied to simulated gas to compute gas limit
+        gas_adjustment: Decimal,
+        /// Price of gas (in fee denom) used to compute fee
+        gas_price: Decimal,
+    },
+}
+
+// Deriving `Default` for enums (using `#[default]`) requires Rust 1.62
+#[allow(clippy::derivable_impls)]
+impl Default for GasMode {
+    fn default() -> Self {
+        Self::Static
+    }
+}
+
+impl fmt::Display for GasMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Static => write!(f, "static"),
+            Self::Simulate {
+                gas_adjustment,
+                gas_price,
+            } => write!(
+                f,
+                "simulate (gas adjustment: {}, gas price: {})",
+                gas_adjustment, gas_price
+            ),
+        }
+    }
+}
+
+/// Strategy for broadcasting transactions to an IBC enabled chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BroadcastMode {
+    /// Broadcast transactions using `broadcast_tx_commit` (i.e., wait for inclusion of transaction in a block in the
+    /// same RPC call)
+    Commit,
+    /// Broadcast transactions using `broadcast_tx_sync` (i.e., wait for `CheckTx`) and then poll the transaction by
+    /// its hash until it is included in a block
+    Sync {
+        /// Maximum duration to wait for inclusion of transaction in a block
+        timeout: Duration,
+    },
+    /// Broadcast transactions using `broadcast_tx_async` (i.e., do not wait for `CheckTx`) and then poll the
+    /// transaction by its hash until it is included in a block
+    Async {
+        /// Maximum duration to wait for inclusion of transaction in a block
+        timeout: Duration,
+    },
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for BroadcastMode {
+    fn default() -> Self {
+        Self::Commit
+    }
+}
+
+impl BroadcastMode {
+    /// Creates broadcast mode from its name (i.e., `commit`, `sync` or `async`) and timeout (ignored for `commit`)
+    pub fn new(mode: &str, timeout: Duration) -> Result<Self> {
+        match mode {
+            "commit" => Ok(Self::Commit),
+            "sync" => Ok(Self::Sync { timeout }),
+            "async" => Ok(Self::Async { timeout }),
+            _ => Err(anyhow!(
+                "invalid broadcast mode: {}, must be one of: commit, sync, async",
+                mode
+            )),
+        }
+    }
+
+    /// Returns name of broadcast mode (i.e., `commit`, `sync` or `async`)
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Commit => "commit",
+            Self::Sync { .. } => "sync",
+            Self::Async { .. } => "async",
+        }
+    }
+
+    /// Returns maximum duration to wait for inclusion of transaction in a block (`None` for `commit`)
+    pub fn timeout(&self) -> Option<Duration> {
+        match self {
+            Self::Commit => None,
+            Self::Sync { timeout } | Self::Async { timeout } => Some(*timeout),
+        }
+    }
+}
+
+impl fmt::Display for BroadcastMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.timeout() {
+            None => write!(f, "{}", self.name()),
+            Some(timeout) => write!(f, "{} (timeout: {}s)", self.name(), timeout.as_secs()),
+        }
+    }
 }
 
 /// IBC connection details
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConnectionDetails {
+    /// Port ID of channel (on both solo machine and IBC enabled chain)
+    pub port_id: PortId,
     /// Client ID of solo machine client on IBC enabled chain
     pub solo_machine_client_id: ClientId,
     /// Client ID of IBC enabled chain on solo machine
@@ -175,6 +296,23 @@ pub struct ConnectionDetails {
     pub tendermint_channel_id: ChannelId,
 }
 
+impl ConnectionDetails {
+    /// Returns the IBC denom (on IBC enabled chain) of given denomination (on solo machine) sent over the channel of
+    /// this connection. Vouchers which were received over this channel are un-prefixed (i.e., they're sent back to
+    /// their source) and all the other tokens are prefixed with the port and channel of IBC enabled chain.
+    pub fn get_ibc_denom(&self, denom: &Denom) -> String {
+        if denom.has_prefix(&self.port_id, &self.tendermint_channel_id) {
+            if let Some(denom) = denom.remove_prefix() {
+                return denom.ibc_denom();
+            }
+        }
+
+        denom
+            .add_prefix(&self.port_id, &self.solo_machine_channel_id)
+            .ibc_denom()
+    }
+}
+
 impl From<Chain> for RawChain {
     fn from(chain: Chain) -> Self {
         Self {
@@ -182,8 +320,6 @@ impl From<Chain> for RawChain {
             node_id: chain.node_id.to_string(),
             config: Json(chain.config),
             consensus_timestamp: chain.consensus_timestamp,
-            sequence: chain.sequence.into(),
-            packet_sequence: chain.packet_sequence.into(),
             connection_details: chain.connection_details.map(Json),
             created_at: chain.created_at,
             updated_at: chain.updated_at,
@@ -203,8 +339,6 @@ impl TryFrom<RawChain> for Chain {
                 .map_err(|err| anyhow!("unable to parse node id: {}", err))?,
             config: raw.config.0,
             consensus_timestamp: raw.consensus_timestamp,
-            sequence: raw.sequence.try_into()?,
-            packet_sequence: raw.packet_sequence.try_into()?,
             connection_details: raw.connection_details.map(|json| json.0),
             created_at: raw.created_at,
             updated_at: raw.updated_at,
@@ -254,6 +388,17 @@ pub async fn get_chain<'e>(
         .transpose()
 }
 
+/// Fetches all the chains from database
+pub async fn get_chains<'e>(executor: impl Executor<'e, Database = Db>) -> Result<Vec<Chain>> {
+    sqlx::query_as("SELECT * FROM chains ORDER BY created_at, id")
+        .fetch_all(executor)
+        .await
+        .context("unable to query chains from database")?
+        .into_iter()
+        .map(|raw: RawChain| raw.try_into())
+        .collect()
+}
+
 /// Adds connection details for given chain id
 pub async fn add_connection_details<'e>(
     executor: impl Executor<'e, Database = Db>,
@@ -280,34 +425,45 @@ pub async fn add_connection_details<'e>(
     Ok(())
 }
 
-pub async fn increment_sequence<'e>(
+/// Removes connection details of given chain id (used after the last connection with chain is closed)
+pub async fn remove_connection_details<'e>(
     executor: impl Executor<'e, Database = Db>,
     chain_id: &ChainId,
-) -> Result<Chain> {
-    let raw: RawChain = sqlx::query_as(
-        "UPDATE chains SET sequence = sequence + 1, updated_at = $1 WHERE id = $2 RETURNING *",
-    )
-    .bind(Utc::now())
-    .bind(chain_id.to_string())
-    .fetch_one(executor)
-    .await
-    .context("unable to increment sequence of a chain")?;
+) -> Result<()> {
+    let rows_affected =
+        sqlx::query("UPDATE chains SET connection_details = NULL, updated_at = $1 WHERE id = $2")
+            .bind(Utc::now())
+            .bind(chain_id.to_string())
+            .execute(executor)
+            .await
+            .context("unable to remove connection details from chain")?
+            .rows_affected();
 
-    raw.try_into()
+    ensure!(
+        rows_affected == 1,
+        "rows_affected should be equal to 1 when removing connection details from chain"
+    );
+
+    Ok(())
 }
 
-pub async fn increment_packet_sequence<'e>(
+/// Sets diversifier of solo machine for given chain (used after diversifier of solo machine client on IBC enabled
+/// chain is updated)
+pub async fn set_diversifier<'e>(
     executor: impl Executor<'e, Database = Db>,
-    chain_id: &ChainId,
+    mut chain: Chain,
+    diversifier: String,
 ) -> Result<Chain> {
-    let raw: RawChain = sqlx::query_as(
-        "UPDATE chains SET packet_sequence = packet_sequence + 1, updated_at = $1 WHERE id = $2 RETURNING *",
-    )
-    .bind(Utc::now())
-    .bind(chain_id.to_string())
-    .fetch_one(executor)
-    .await
-    .context("unable to increment packet sequence of a chain")?;
+    chain.config.diversifier = diversifier;
+
+    let raw: RawChain =
+        sqlx::query_as("UPDATE chains SET config = $1, updated_at = $2 WHERE id = $3 RETURNING *")
+            .bind(Json(&chain.config))
+            .bind(Utc::now())
+            .bind(chain.id.to_string())
+            .fetch_one(executor)
+            .await
+            .context("unable to set diversifier of a chain")?;
 
     raw.try_into()
 }
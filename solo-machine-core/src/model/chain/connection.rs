@@ -0,0 +1,295 @@
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::{anyhow, ensure, Context, Error, Result};
+use sqlx::{Executor, FromRow};
+
+use crate::{
+    ibc::core::ics24_host::identifier::{ChainId, ChannelId, ClientId},
+    Db,
+};
+
+use super::{Chain, ConnectionDetails};
+
+#[derive(Debug, FromRow)]
+/// Raw IBC connection (and channel) with an IBC enabled chain
+struct RawConnection {
+    /// Port ID of channel
+    pub port_id: String,
+    /// Client ID of solo machine client on IBC enabled chain
+    pub solo_machine_client_id: String,
+    /// Client ID of IBC enabled chain on solo machine
+    pub tendermint_client_id: String,
+    /// Connection ID of solo machine client on IBC enabled chain
+    pub solo_machine_connection_id: String,
+    /// Connection ID of IBC enabled chain on solo machine
+    pub tendermint_connection_id: String,
+    /// Channel ID of solo machine client on IBC enabled chain
+    pub solo_machine_channel_id: String,
+    /// Channel ID of IBC enabled chain on solo machine
+    pub tendermint_channel_id: String,
+}
+
+impl TryFrom<RawConnection> for ConnectionDetails {
+    type Error = Error;
+
+    fn try_from(raw: RawConnection) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: raw.port_id.parse()?,
+            solo_machine_client_id: raw.solo_machine_client_id.parse()?,
+            tendermint_client_id: raw.tendermint_client_id.parse()?,
+            solo_machine_connection_id: raw.solo_machine_connection_id.parse()?,
+            tendermint_connection_id: raw.tendermint_connection_id.parse()?,
+            solo_machine_channel_id: raw.solo_machine_channel_id.parse()?,
+            tendermint_channel_id: raw.tendermint_channel_id.parse()?,
+        })
+    }
+}
+
+/// Adds an established connection (and channel) with given chain to database
+pub async fn add_connection<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    connection_details: &ConnectionDetails,
+) -> Result<()> {
+    let rows_affected = sqlx::query(
+        "INSERT INTO connections (chain_id, port_id, solo_machine_client_id, tendermint_client_id, solo_machine_connection_id, tendermint_connection_id, solo_machine_channel_id, tendermint_channel_id) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+    )
+    .bind(chain_id.to_string())
+    .bind(connection_details.port_id.to_string())
+    .bind(connection_details.solo_machine_client_id.to_string())
+    .bind(connection_details.tendermint_client_id.to_string())
+    .bind(connection_details.solo_machine_connection_id.to_string())
+    .bind(connection_details.tendermint_connection_id.to_string())
+    .bind(connection_details.solo_machine_channel_id.to_string())
+    .bind(connection_details.tendermint_channel_id.to_string())
+    .execute(executor)
+    .await
+    .context("unable to add connection to database")?
+    .rows_affected();
+
+    ensure!(
+        rows_affected == 1,
+        "rows_affected should be equal to 1 when adding new connection"
+    );
+
+    Ok(())
+}
+
+/// Fetches connection with given chain which uses given channel (channel ID of solo machine client on IBC enabled
+/// chain) from database
+pub async fn get_connection<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    solo_machine_channel_id: &ChannelId,
+) -> Result<Option<ConnectionDetails>> {
+    sqlx::query_as(
+        "SELECT port_id, solo_machine_client_id, tendermint_client_id, solo_machine_connection_id, tendermint_connection_id, solo_machine_channel_id, tendermint_channel_id FROM connections WHERE chain_id = $1 AND solo_machine_channel_id = $2",
+    )
+    .bind(chain_id.to_string())
+    .bind(solo_machine_channel_id.to_string())
+    .fetch_optional(executor)
+    .await
+    .context("unable to query connection from database")?
+    .map(|raw: RawConnection| raw.try_into())
+    .transpose()
+}
+
+/// Fetches all the connections with given chain from database (ordered by creation time)
+pub async fn get_connections<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+) -> Result<Vec<ConnectionDetails>> {
+    sqlx::query_as(
+        "SELECT port_id, solo_machine_client_id, tendermint_client_id, solo_machine_connection_id, tendermint_connection_id, solo_machine_channel_id, tendermint_channel_id FROM connections WHERE chain_id = $1 ORDER BY created_at, solo_machine_channel_id",
+    )
+    .bind(chain_id.to_string())
+    .fetch_all(executor)
+    .await
+    .context("unable to query connections from database")?
+    .into_iter()
+    .map(|raw: RawConnection| raw.try_into())
+    .collect()
+}
+
+/// Fetches IDs of all the solo machine clients (on IBC enabled chain) used by established connections with given chain
+/// from database (ordered by creation time of their first connection)
+pub async fn get_solo_machine_client_ids<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+) -> Result<Vec<ClientId>> {
+    sqlx::query_as(
+        "SELECT solo_machine_client_id FROM connections WHERE chain_id = $1 GROUP BY solo_machine_client_id ORDER BY MIN(created_at), solo_machine_client_id",
+    )
+    .bind(chain_id.to_string())
+    .fetch_all(executor)
+    .await
+    .context("unable to query solo machine client ids from database")?
+    .into_iter()
+    .map(|(client_id,): (String,)| client_id.parse())
+    .collect()
+}
+
+/// Removes connection with given chain which uses given channel (channel ID of solo machine client on IBC enabled
+/// chain) from database
+pub async fn remove_connection<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    solo_machine_channel_id: &ChannelId,
+) -> Result<()> {
+    let rows_affected =
+        sqlx::query("DELETE FROM connections WHERE chain_id = $1 AND solo_machine_channel_id = $2")
+            .bind(chain_id.to_string())
+            .bind(solo_machine_channel_id.to_string())
+            .execute(executor)
+            .await
+            .context("unable to remove connection from database")?
+            .rows_affected();
+
+    ensure!(
+        rows_affected == 1,
+        "rows_affected should be equal to 1 when removing connection"
+    );
+
+    Ok(())
+}
+
+/// Returns connection details of given chain for given channel (channel ID of solo machine client on IBC enabled
+/// chain). Returns default connection details of chain (i.e., the latest established connection) if channel is not
+/// provided.
+pub async fn select_connection<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain: &Chain,
+    solo_machine_channel_id: Option<&ChannelId>,
+) -> Result<ConnectionDetails> {
+    match solo_machine_channel_id {
+        None => chain.connection_details.clone().ok_or_else(|| {
+            anyhow!(
+                "connection details for chain with id {} are missing",
+                chain.id
+            )
+        }),
+        Some(solo_machine_channel_id) => {
+            get_connection(executor, &chain.id, solo_machine_channel_id)
+                .await?
+                .ok_or_else(|| {
+                    anyhow!(
+                        "connection with channel id {} not found for chain with id {}",
+                        solo_machine_channel_id,
+                        chain.id
+                    )
+                })
+        }
+    }
+}
+
+/// Fetches height from which IBC enabled chain is to be searched for packets sent over given channel (channel ID of
+/// solo machine client on IBC enabled chain)
+pub async fn get_packet_search_height<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    solo_machine_channel_id: &ChannelId,
+) -> Result<u64> {
+    let (packet_search_height,): (i64,) = sqlx::query_as(
+        "SELECT packet_search_height FROM connections WHERE chain_id = $1 AND solo_machine_channel_id = $2",
+    )
+    .bind(chain_id.to_string())
+    .bind(solo_machine_channel_id.to_string())
+    .fetch_optional(executor)
+    .await
+    .context("unable to query packet search height from database")?
+    .ok_or_else(|| {
+        anyhow!(
+            "connection with channel id {} not found for chain with id {}",
+            solo_machine_channel_id,
+            chain_id
+        )
+    })?;
+
+    packet_search_height
+        .try_into()
+        .context("invalid packet search height in database")
+}
+
+/// Updates height from which IBC enabled chain is to be searched for packets sent over given channel (channel ID of
+/// solo machine client on IBC enabled chain)
+pub async fn set_packet_search_height<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    solo_machine_channel_id: &ChannelId,
+    packet_search_height: u64,
+) -> Result<()> {
+    let packet_search_height: i64 = packet_search_height
+        .try_into()
+        .context("packet search height does not fit in i64")?;
+
+    let rows_affected = sqlx::query(
+        "UPDATE connections SET packet_search_height = $1 WHERE chain_id = $2 AND solo_machine_channel_id = $3",
+    )
+    .bind(packet_search_height)
+    .bind(chain_id.to_string())
+    .bind(solo_machine_channel_id.to_string())
+    .execute(executor)
+    .await
+    .context("unable to update packet search height in database")?
+    .rows_affected();
+
+    ensure!(
+        rows_affected == 1,
+        "rows_affected should be equal to 1 when updating packet search height"
+    );
+
+    Ok(())
+}
+
+/// Fetches packet sequence of solo machine for given channel (channel ID of solo machine client on IBC enabled chain),
+/// i.e., the sequence of next packet sent over the channel
+pub async fn get_packet_sequence<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    solo_machine_channel_id: &ChannelId,
+) -> Result<u64> {
+    let (packet_sequence,): (i64,) = sqlx::query_as(
+        "SELECT packet_sequence FROM connections WHERE chain_id = $1 AND solo_machine_channel_id = $2",
+    )
+    .bind(chain_id.to_string())
+    .bind(solo_machine_channel_id.to_string())
+    .fetch_optional(executor)
+    .await
+    .context("unable to query packet sequence from database")?
+    .ok_or_else(|| {
+        anyhow!(
+            "connection with channel id {} not found for chain with id {}",
+            solo_machine_channel_id,
+            chain_id
+        )
+    })?;
+
+    packet_sequence
+        .try_into()
+        .context("invalid packet sequence in database")
+}
+
+/// Increments packet sequence of solo machine for given channel (channel ID of solo machine client on IBC enabled
+/// chain)
+pub async fn increment_packet_sequence<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    solo_machine_channel_id: &ChannelId,
+) -> Result<()> {
+    let rows_affected = sqlx::query(
+        "UPDATE connections SET packet_sequence = packet_sequence + 1 WHERE chain_id = $1 AND solo_machine_channel_id = $2",
+    )
+    .bind(chain_id.to_string())
+    .bind(solo_machine_channel_id.to_string())
+    .execute(executor)
+    .await
+    .context("unable to increment packet sequence in database")?
+    .rows_affected();
+
+    ensure!(
+        rows_affected == 1,
+        "rows_affected should be equal to 1 when incrementing packet sequence"
+    );
+
+    Ok(())
+}
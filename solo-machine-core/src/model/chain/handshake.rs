@@ -0,0 +1,276 @@
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt,
+    str::FromStr,
+};
+
+use anyhow::{anyhow, ensure, Context, Error, Result};
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, FromRow};
+
+use crate::{
+    ibc::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId},
+    Db,
+};
+
+/// Steps of IBC connection handshake (in the order in which they're executed)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandshakeStep {
+    /// Created solo machine client on IBC enabled chain
+    CreatedSoloMachineClient,
+    /// Created tendermint client on solo machine
+    CreatedTendermintClient,
+    /// Initialized connection on IBC enabled chain
+    InitializedConnectionOnTendermint,
+    /// Initialized connection on solo machine
+    InitializedConnectionOnSoloMachine,
+    /// Confirmed connection on IBC enabled chain
+    ConfirmedConnectionOnTendermint,
+    /// Confirmed connection on solo machine
+    ConfirmedConnectionOnSoloMachine,
+    /// Initialized channel on IBC enabled chain
+    InitializedChannelOnTendermint,
+    /// Initialized channel on solo machine
+    InitializedChannelOnSoloMachine,
+    /// Confirmed channel on IBC enabled chain
+    ConfirmedChannelOnTendermint,
+    /// Confirmed channel on solo machine
+    ConfirmedChannelOnSoloMachine,
+}
+
+impl HandshakeStep {
+    /// Returns `true` if this is the last step of connection handshake
+    pub fn is_final(&self) -> bool {
+        *self == Self::ConfirmedChannelOnSoloMachine
+    }
+}
+
+impl fmt::Display for HandshakeStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CreatedSoloMachineClient => write!(f, "created-solo-machine-client"),
+            Self::CreatedTendermintClient => write!(f, "created-tendermint-client"),
+            Self::InitializedConnectionOnTendermint => {
+                write!(f, "initialized-connection-on-tendermint")
+            }
+            Self::InitializedConnectionOnSoloMachine => {
+                write!(f, "initialized-connection-on-solo-machine")
+            }
+            Self::ConfirmedConnectionOnTendermint => {
+                write!(f, "confirmed-connection-on-tendermint")
+            }
+            Self::ConfirmedConnectionOnSoloMachine => {
+                write!(f, "confirmed-connection-on-solo-machine")
+            }
+            Self::InitializedChannelOnTendermint => write!(f, "initialized-channel-on-tendermint"),
+            Self::InitializedChannelOnSoloMachine => {
+                write!(f, "initialized-channel-on-solo-machine")
+            }
+            Self::ConfirmedChannelOnTendermint => write!(f, "confirmed-channel-on-tendermint"),
+            Self::ConfirmedChannelOnSoloMachine => write!(f, "confirmed-channel-on-solo-machine"),
+        }
+    }
+}
+
+impl FromStr for HandshakeStep {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "created-solo-machine-client" => Ok(Self::CreatedSoloMachineClient),
+            "created-tendermint-client" => Ok(Self::CreatedTendermintClient),
+            "initialized-connection-on-tendermint" => Ok(Self::InitializedConnectionOnTendermint),
+            "initialized-connection-on-solo-machine" => {
+                Ok(Self::InitializedConnectionOnSoloMachine)
+            }
+            "confirmed-connection-on-tendermint" => Ok(Self::ConfirmedConnectionOnTendermint),
+            "confirmed-connection-on-solo-machine" => Ok(Self::ConfirmedConnectionOnSoloMachine),
+            "initialized-channel-on-tendermint" => Ok(Self::InitializedChannelOnTendermint),
+            "initialized-channel-on-solo-machine" => Ok(Self::InitializedChannelOnSoloMachine),
+            "confirmed-channel-on-tendermint" => Ok(Self::ConfirmedChannelOnTendermint),
+            "confirmed-channel-on-solo-machine" => Ok(Self::ConfirmedChannelOnSoloMachine),
+            _ => Err(anyhow!("invalid connection handshake step: {}", s)),
+        }
+    }
+}
+
+/// Progress of IBC connection handshake with an IBC enabled chain
+#[derive(Debug, Clone)]
+pub struct Handshake {
+    /// Chain ID of IBC enabled chain
+    pub chain_id: ChainId,
+    /// Last confirmed step of connection handshake
+    pub step: HandshakeStep,
+    /// Client ID of solo machine client on IBC enabled chain
+    pub solo_machine_client_id: Option<ClientId>,
+    /// Client ID of IBC enabled chain on solo machine
+    pub tendermint_client_id: Option<ClientId>,
+    /// Connection ID of solo machine client on IBC enabled chain
+    pub solo_machine_connection_id: Option<ConnectionId>,
+    /// Connection ID of IBC enabled chain on solo machine
+    pub tendermint_connection_id: Option<ConnectionId>,
+    /// Channel ID of solo machine client on IBC enabled chain
+    pub solo_machine_channel_id: Option<ChannelId>,
+    /// Channel ID of IBC enabled chain on solo machine
+    pub tendermint_channel_id: Option<ChannelId>,
+    /// Creation time of connection handshake
+    pub created_at: DateTime<Utc>,
+    /// Last updation time of connection handshake
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Handshake {
+    /// Returns `true` if given step of connection handshake is already confirmed
+    pub fn has_completed(&self, step: HandshakeStep) -> bool {
+        self.step >= step
+    }
+}
+
+#[derive(Debug, FromRow)]
+/// Raw progress of IBC connection handshake with an IBC enabled chain
+struct RawHandshake {
+    /// Chain ID of IBC enabled chain
+    pub chain_id: String,
+    /// Last confirmed step of connection handshake
+    pub step: String,
+    /// Client ID of solo machine client on IBC enabled chain
+    pub solo_machine_client_id: Option<String>,
+    /// Client ID of IBC enabled chain on solo machine
+    pub tendermint_client_id: Option<String>,
+    /// Connection ID of solo machine client on IBC enabled chain
+    pub solo_machine_connection_id: Option<String>,
+    /// Connection ID of IBC enabled chain on solo machine
+    pub tendermint_connection_id: Option<String>,
+    /// Channel ID of solo machine client on IBC enabled chain
+    pub solo_machine_channel_id: Option<String>,
+    /// Channel ID of IBC enabled chain on solo machine
+    pub tendermint_channel_id: Option<String>,
+    /// Creation time of connection handshake
+    pub created_at: DateTime<Utc>,
+    /// Last updation time of connection handshake
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Handshake> for RawHandshake {
+    fn from(handshake: Handshake) -> Self {
+        Self {
+            chain_id: handshake.chain_id.to_string(),
+            step: handshake.step.to_string(),
+            solo_machine_client_id: handshake.solo_machine_client_id.map(Into::into),
+            tendermint_client_id: handshake.tendermint_client_id.map(Into::into),
+            solo_machine_connection_id: handshake.solo_machine_connection_id.map(Into::into),
+            tendermint_connection_id: handshake.tendermint_connection_id.map(Into::into),
+            solo_machine_channel_id: handshake.solo_machine_channel_id.map(Into::into),
+            tendermint_channel_id: handshake.tendermint_channel_id.map(Into::into),
+            created_at: handshake.created_at,
+            updated_at: handshake.updated_at,
+        }
+    }
+}
+
+impl TryFrom<RawHandshake> for Handshake {
+    type Error = Error;
+
+    fn try_from(raw: RawHandshake) -> Result<Self, Self::Error> {
+        Ok(Self {
+            chain_id: raw.chain_id.parse()?,
+            step: raw.step.parse()?,
+            solo_machine_client_id: raw
+                .solo_machine_client_id
+                .as_deref()
+                .map(FromStr::from_str)
+                .transpose()?,
+            tendermint_client_id: raw
+                .tendermint_client_id
+                .as_deref()
+                .map(FromStr::from_str)
+                .transpose()?,
+            solo_machine_connection_id: raw
+                .solo_machine_connection_id
+                .as_deref()
+                .map(FromStr::from_str)
+                .transpose()?,
+            tendermint_connection_id: raw
+                .tendermint_connection_id
+                .as_deref()
+                .map(FromStr::from_str)
+                .transpose()?,
+            solo_machine_channel_id: raw
+                .solo_machine_channel_id
+                .as_deref()
+                .map(FromStr::from_str)
+                .transpose()?,
+            tendermint_channel_id: raw
+                .tendermint_channel_id
+                .as_deref()
+                .map(FromStr::from_str)
+                .transpose()?,
+            created_at: raw.created_at,
+            updated_at: raw.updated_at,
+        })
+    }
+}
+
+/// Starts a new connection handshake for given chain after creating solo machine client on IBC enabled chain (removes
+/// any previous connection handshake for the chain)
+pub async fn add_handshake<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    solo_machine_client_id: &ClientId,
+) -> Result<Handshake> {
+    let raw: RawHandshake = sqlx::query_as(
+        "INSERT INTO connection_handshakes (chain_id, step, solo_machine_client_id) VALUES ($1, $2, $3) ON CONFLICT (chain_id) DO UPDATE SET step = excluded.step, solo_machine_client_id = excluded.solo_machine_client_id, tendermint_client_id = NULL, solo_machine_connection_id = NULL, tendermint_connection_id = NULL, solo_machine_channel_id = NULL, tendermint_channel_id = NULL, created_at = $4, updated_at = $4 RETURNING *",
+    )
+    .bind(chain_id.to_string())
+    .bind(HandshakeStep::CreatedSoloMachineClient.to_string())
+    .bind(solo_machine_client_id.to_string())
+    .bind(Utc::now())
+    .fetch_one(executor)
+    .await
+    .context("unable to add connection handshake to database")?;
+
+    raw.try_into()
+}
+
+/// Fetches connection handshake for given chain from database
+pub async fn get_handshake<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+) -> Result<Option<Handshake>> {
+    sqlx::query_as("SELECT * FROM connection_handshakes WHERE chain_id = $1")
+        .bind(chain_id.to_string())
+        .fetch_optional(executor)
+        .await
+        .context("unable to query connection handshake from database")?
+        .map(|raw: RawHandshake| raw.try_into())
+        .transpose()
+}
+
+/// Updates the step and identifiers of connection handshake in database
+pub async fn update_handshake<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    handshake: &Handshake,
+) -> Result<()> {
+    let rows_affected = sqlx::query(
+        "UPDATE connection_handshakes SET step = $1, tendermint_client_id = $2, solo_machine_connection_id = $3, tendermint_connection_id = $4, solo_machine_channel_id = $5, tendermint_channel_id = $6, updated_at = $7 WHERE chain_id = $8",
+    )
+    .bind(handshake.step.to_string())
+    .bind(handshake.tendermint_client_id.as_ref().map(ToString::to_string))
+    .bind(handshake.solo_machine_connection_id.as_ref().map(ToString::to_string))
+    .bind(handshake.tendermint_connection_id.as_ref().map(ToString::to_string))
+    .bind(handshake.solo_machine_channel_id.as_ref().map(ToString::to_string))
+    .bind(handshake.tendermint_channel_id.as_ref().map(ToString::to_string))
+    .bind(Utc::now())
+    .bind(handshake.chain_id.to_string())
+    .execute(executor)
+    .await
+    .context("unable to update connection handshake in database")?
+    .rows_affected();
+
+    ensure!(
+        rows_affected == 1,
+        "rows_affected should be equal to 1 when updating connection handshake"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::{Context, Error, Result};
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, FromRow};
+
+use crate::{
+    ibc::core::ics24_host::identifier::{ChainId, ClientId},
+    Db,
+};
+
+/// Sequence with which new solo machine clients are created on IBC enabled chain
+pub const INITIAL_SEQUENCE: u64 = 1;
+
+/// State of a solo machine client on an IBC enabled chain (each client has its own sequence)
+#[derive(Debug, Clone)]
+pub struct SoloMachineClient {
+    /// Chain ID of IBC enabled chain
+    pub chain_id: ChainId,
+    /// Client ID of solo machine client on IBC enabled chain
+    pub client_id: ClientId,
+    /// Sequence of solo machine client (used when creating transactions on chain)
+    pub sequence: u64,
+    /// Consensus timestamp of solo machine client (i.e., timestamp of the last signature of solo machine for the client)
+    pub consensus_timestamp: DateTime<Utc>,
+    /// Creation time of solo machine client
+    pub created_at: DateTime<Utc>,
+    /// Last updation time of solo machine client
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+/// Raw state of a solo machine client stored in database
+struct RawSoloMachineClient {
+    /// Chain ID of IBC enabled chain
+    pub chain_id: String,
+    /// Client ID of solo machine client on IBC enabled chain
+    pub client_id: String,
+    /// Sequence of solo machine client (used when creating transactions on chain)
+    pub sequence: i64,
+    /// Consensus timestamp of solo machine client (i.e., timestamp of the last signature of solo machine for the client)
+    pub consensus_timestamp: DateTime<Utc>,
+    /// Creation time of solo machine client
+    pub created_at: DateTime<Utc>,
+    /// Last updation time of solo machine client
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<RawSoloMachineClient> for SoloMachineClient {
+    type Error = Error;
+
+    fn try_from(raw: RawSoloMachineClient) -> Result<Self, Self::Error> {
+        Ok(Self {
+            chain_id: raw.chain_id.parse()?,
+            client_id: raw.client_id.parse()?,
+            sequence: raw.sequence.try_into()?,
+            consensus_timestamp: raw.consensus_timestamp,
+            created_at: raw.created_at,
+            updated_at: raw.updated_at,
+        })
+    }
+}
+
+/// Adds a solo machine client of given chain with given sequence and consensus timestamp to database (does nothing if
+/// the client already exists). Returns `true` if a new client was added.
+pub async fn add_solo_machine_client<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    client_id: &ClientId,
+    sequence: u64,
+    consensus_timestamp: DateTime<Utc>,
+) -> Result<bool> {
+    let sequence: i64 = sequence
+        .try_into()
+        .context("sequence does not fit in i64")?;
+
+    let rows_affected = sqlx::query(
+        "INSERT INTO solo_machine_clients (chain_id, client_id, sequence, consensus_timestamp) VALUES ($1, $2, $3, $4) ON CONFLICT (chain_id, client_id) DO NOTHING",
+    )
+    .bind(chain_id.to_string())
+    .bind(client_id.to_string())
+    .bind(sequence)
+    .bind(consensus_timestamp)
+    .execute(executor)
+    .await
+    .context("unable to add solo machine client to database")?
+    .rows_affected();
+
+    Ok(rows_affected == 1)
+}
+
+/// Fetches solo machine client of given chain from database
+pub async fn get_solo_machine_client<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    client_id: &ClientId,
+) -> Result<Option<SoloMachineClient>> {
+    sqlx::query_as("SELECT * FROM solo_machine_clients WHERE chain_id = $1 AND client_id = $2")
+        .bind(chain_id.to_string())
+        .bind(client_id.to_string())
+        .fetch_optional(executor)
+        .await
+        .context("unable to query solo machine client from database")?
+        .map(|raw: RawSoloMachineClient| raw.try_into())
+        .transpose()
+}
+
+/// Sets consensus timestamp of given solo machine client (i.e., timestamp of a new signature of solo machine for the
+/// client)
+pub async fn set_consensus_timestamp<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    client_id: &ClientId,
+    consensus_timestamp: DateTime<Utc>,
+) -> Result<()> {
+    sqlx::query(
+        "UPDATE solo_machine_clients SET consensus_timestamp = $1, updated_at = $2 WHERE chain_id = $3 AND client_id = $4",
+    )
+    .bind(consensus_timestamp)
+    .bind(Utc::now())
+    .bind(chain_id.to_string())
+    .bind(client_id.to_string())
+    .execute(executor)
+    .await
+    .context("unable to set consensus timestamp of a solo machine client")?;
+
+    Ok(())
+}
+
+/// Increments sequence of given solo machine client
+pub async fn increment_sequence<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    client_id: &ClientId,
+) -> Result<SoloMachineClient> {
+    let raw: RawSoloMachineClient = sqlx::query_as(
+        "UPDATE solo_machine_clients SET sequence = sequence + 1, updated_at = $1 WHERE chain_id = $2 AND client_id = $3 RETURNING *",
+    )
+    .bind(Utc::now())
+    .bind(chain_id.to_string())
+    .bind(client_id.to_string())
+    .fetch_one(executor)
+    .await
+    .context("unable to increment sequence of a solo machine client")?;
+
+    raw.try_into()
+}
+
+/// Sets sequence of given solo machine client (used when sequence of solo machine client on IBC enabled chain is
+/// changed by a client update proposal)
+pub async fn set_sequence<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    client_id: &ClientId,
+    sequence: u64,
+) -> Result<SoloMachineClient> {
+    let sequence: i64 = sequence
+        .try_into()
+        .context("sequence does not fit in i64")?;
+
+    let raw: RawSoloMachineClient = sqlx::query_as(
+        "UPDATE solo_machine_clients SET sequence = $1, updated_at = $2 WHERE chain_id = $3 AND client_id = $4 RETURNING *",
+    )
+    .bind(sequence)
+    .bind(Utc::now())
+    .bind(chain_id.to_string())
+    .bind(client_id.to_string())
+    .fetch_one(executor)
+    .await
+    .context("unable to set sequence of a solo machine client")?;
+
+    raw.try_into()
+}
@@ -19,6 +19,7 @@ use crate::{
 
 #[derive(Debug, FromRow)]
 struct IbcData {
+    #[allow(dead_code)]
     path: String,
     data: Vec<u8>,
 }
@@ -44,6 +45,18 @@ pub async fn get_tendermint_client_state<'e>(
     get(executor, &path).await
 }
 
+/// Updates tendermint client state in database
+pub async fn update_tendermint_client_state<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    client_id: &ClientId,
+    client_state: &TendermintClientState,
+) -> Result<()> {
+    let path: String = ClientStatePath::new(client_id).into();
+    let data = proto_encode(client_state)?;
+
+    update(executor, &path, &data).await
+}
+
 /// Adds tendermint consensus state to database
 pub async fn add_tendermint_consensus_state<'e>(
     executor: impl Executor<'e, Database = Db>,
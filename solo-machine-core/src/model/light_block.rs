@@ -0,0 +1,275 @@
+use std::{
+    convert::TryFrom,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use anyhow::{anyhow, Context, Result};
+use sqlx::{types::Json, Executor, FromRow, Transaction};
+use tendermint::block::Height as BlockHeight;
+use tendermint_light_client::{
+    store::{memory::MemoryStore, LightStore},
+    types::{LightBlock, Status},
+};
+
+use crate::{ibc::core::ics24_host::identifier::ChainId, Db};
+
+/// Number of latest trusted (or verified) light blocks of a chain which are kept in database
+const MAX_PERSISTED_LIGHT_BLOCKS: i64 = 16;
+
+/// [`LightStore`] which persists light blocks of an IBC enabled chain in database
+///
+/// # Note
+///
+/// [`LightStore`] has a blocking API. So, instead of calling database from within it, the latest trusted (or verified)
+/// light block of chain is loaded in memory beforehand (using [`DbLightStore::load`]) and changes made by light client
+/// are written back to database afterwards (using [`DbLightStore::flush`]). Clones of a light store share the same
+/// state, so, a clone can be kept around for flushing after passing the light store to light client.
+///
+/// Only trusted and verified light blocks are persisted (unverified and failed ones are only needed during a single
+/// verification) and only the latest [`MAX_PERSISTED_LIGHT_BLOCKS`] of them are kept in database.
+#[derive(Debug, Clone)]
+pub struct DbLightStore {
+    chain_id: ChainId,
+    inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    store: MemoryStore,
+    changes: Vec<Change>,
+}
+
+/// Change made to light store which is not yet written to database
+#[derive(Debug)]
+enum Change {
+    Put(Box<LightBlock>, Status),
+    Remove(BlockHeight, Status),
+}
+
+impl DbLightStore {
+    /// Loads the latest trusted (or verified) light block of given chain from database (light client only verifies
+    /// from the highest trusted state)
+    pub async fn load<'e>(
+        executor: impl Executor<'e, Database = Db>,
+        chain_id: ChainId,
+    ) -> Result<Self> {
+        let mut store = MemoryStore::new();
+
+        if let Some((light_block, status)) = get_latest_light_block(executor, &chain_id).await? {
+            store.insert(light_block, status);
+        }
+
+        Ok(Self {
+            chain_id,
+            inner: Arc::new(Mutex::new(Inner {
+                store,
+                changes: Vec::new(),
+            })),
+        })
+    }
+
+    /// Writes all the pending changes made to light store to database and prunes light blocks which are no longer
+    /// needed (i.e., all but the latest trusted or verified ones)
+    pub async fn flush(&self, transaction: &mut Transaction<'_, Db>) -> Result<()> {
+        let changes = std::mem::take(&mut self.lock().changes);
+
+        for change in changes {
+            match change {
+                Change::Put(light_block, status) => {
+                    remove_light_blocks_at(&mut *transaction, &self.chain_id, light_block.height())
+                        .await?;
+
+                    if is_persisted(status) {
+                        add_light_block(&mut *transaction, &self.chain_id, &light_block, status)
+                            .await?;
+                    }
+                }
+                Change::Remove(height, status) => {
+                    remove_light_block(&mut *transaction, &self.chain_id, height, status).await?;
+                }
+            }
+        }
+
+        prune_light_blocks(&mut *transaction, &self.chain_id).await
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl LightStore for DbLightStore {
+    fn get(&self, height: BlockHeight, status: Status) -> Option<LightBlock> {
+        self.lock().store.get(height, status)
+    }
+
+    fn update(&mut self, light_block: &LightBlock, status: Status) {
+        let mut inner = self.lock();
+        inner.store.update(light_block, status);
+        inner
+            .changes
+            .push(Change::Put(Box::new(light_block.clone()), status));
+    }
+
+    fn insert(&mut self, light_block: LightBlock, status: Status) {
+        let mut inner = self.lock();
+        inner.store.insert(light_block.clone(), status);
+        inner
+            .changes
+            .push(Change::Put(Box::new(light_block), status));
+    }
+
+    fn remove(&mut self, height: BlockHeight, status: Status) {
+        let mut inner = self.lock();
+        inner.store.remove(height, status);
+        inner.changes.push(Change::Remove(height, status));
+    }
+
+    fn highest(&self, status: Status) -> Option<LightBlock> {
+        self.lock().store.highest(status)
+    }
+
+    fn lowest(&self, status: Status) -> Option<LightBlock> {
+        self.lock().store.lowest(status)
+    }
+
+    fn all(&self, status: Status) -> Box<dyn Iterator<Item = LightBlock>> {
+        self.lock().store.all(status)
+    }
+}
+
+#[derive(Debug, FromRow)]
+/// Raw light block stored in database
+struct RawLightBlock {
+    /// Status of light block
+    pub status: String,
+    /// Light block
+    pub light_block: Json<LightBlock>,
+}
+
+/// Adds a light block with given status to database (overrides any existing light block with same height and status)
+pub async fn add_light_block<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    light_block: &LightBlock,
+    status: Status,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO light_blocks (chain_id, height, status, light_block) VALUES ($1, $2, $3, $4) ON CONFLICT (chain_id, height, status) DO UPDATE SET light_block = excluded.light_block",
+    )
+    .bind(chain_id.to_string())
+    .bind(height_to_i64(light_block.height())?)
+    .bind(status_to_str(status))
+    .bind(Json(light_block))
+    .execute(executor)
+    .await
+    .context("unable to add light block to database")?;
+
+    Ok(())
+}
+
+/// Fetches the latest trusted (or verified) light block along with its status from database
+pub async fn get_latest_light_block<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+) -> Result<Option<(LightBlock, Status)>> {
+    let raw: Option<RawLightBlock> = sqlx::query_as(
+        "SELECT status, light_block FROM light_blocks WHERE chain_id = $1 AND status IN ($2, $3) ORDER BY height DESC LIMIT 1",
+    )
+    .bind(chain_id.to_string())
+    .bind(status_to_str(Status::Trusted))
+    .bind(status_to_str(Status::Verified))
+    .fetch_optional(executor)
+    .await
+    .context("unable to query latest light block from database")?;
+
+    raw.map(|raw| Ok((raw.light_block.0, status_from_str(&raw.status)?)))
+        .transpose()
+}
+
+/// Removes light block with given height and status from database
+pub async fn remove_light_block<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    height: BlockHeight,
+    status: Status,
+) -> Result<()> {
+    sqlx::query("DELETE FROM light_blocks WHERE chain_id = $1 AND height = $2 AND status = $3")
+        .bind(chain_id.to_string())
+        .bind(height_to_i64(height)?)
+        .bind(status_to_str(status))
+        .execute(executor)
+        .await
+        .context("unable to remove light block from database")?;
+
+    Ok(())
+}
+
+/// Removes light blocks with given height (with any status) from database
+async fn remove_light_blocks_at<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    height: BlockHeight,
+) -> Result<()> {
+    sqlx::query("DELETE FROM light_blocks WHERE chain_id = $1 AND height = $2")
+        .bind(chain_id.to_string())
+        .bind(height_to_i64(height)?)
+        .execute(executor)
+        .await
+        .context("unable to remove light blocks from database")?;
+
+    Ok(())
+}
+
+/// Removes all the light blocks of given chain from database except the latest [`MAX_PERSISTED_LIGHT_BLOCKS`] trusted
+/// (or verified) ones
+async fn prune_light_blocks<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+) -> Result<()> {
+    sqlx::query(
+        "DELETE FROM light_blocks WHERE chain_id = $1 AND (status NOT IN ($2, $3) OR height < (SELECT MIN(height) FROM (SELECT height FROM light_blocks WHERE chain_id = $1 AND status IN ($2, $3) ORDER BY height DESC LIMIT $4) AS latest_light_blocks))",
+    )
+    .bind(chain_id.to_string())
+    .bind(status_to_str(Status::Trusted))
+    .bind(status_to_str(Status::Verified))
+    .bind(MAX_PERSISTED_LIGHT_BLOCKS)
+    .execute(executor)
+    .await
+    .context("unable to prune light blocks in database")?;
+
+    Ok(())
+}
+
+/// Returns `true` if light blocks with given status are persisted in database
+fn is_persisted(status: Status) -> bool {
+    matches!(status, Status::Trusted | Status::Verified)
+}
+
+fn height_to_i64(height: BlockHeight) -> Result<i64> {
+    i64::try_from(height.value()).context("block height does not fit in i64")
+}
+
+fn status_to_str(status: Status) -> &'static str {
+    match status {
+        Status::Unverified => "unverified",
+        Status::Verified => "verified",
+        Status::Trusted => "trusted",
+        Status::Failed => "failed",
+    }
+}
+
+fn status_from_str(status: &str) -> Result<Status> {
+    match status {
+        "unverified" => Ok(Status::Unverified),
+        "verified" => Ok(Status::Verified),
+        "trusted" => Ok(Status::Trusted),
+        "failed" => Ok(Status::Failed),
+        _ => Err(anyhow!(
+            "invalid light block status in database: {}",
+            status
+        )),
+    }
+}
@@ -9,7 +9,10 @@ use serde::{Deserialize, Serialize};
 use sqlx::{types::Json, Executor, FromRow};
 
 use crate::{
-    ibc::core::ics24_host::identifier::{ChainId, Identifier},
+    ibc::{
+        apps::transfer::{amount::Amount, denom::Denom},
+        core::ics24_host::identifier::ChainId,
+    },
     Db,
 };
 
@@ -23,9 +26,9 @@ pub struct Operation {
     /// Address of the account
     pub address: String,
     /// Denom of tokens
-    pub denom: Identifier,
+    pub denom: Denom,
     /// Amount of tokens
-    pub amount: u64,
+    pub amount: Amount,
     /// Type of operation
     pub operation_type: OperationType,
     /// On-chain transaction hash (in hex)
@@ -45,8 +48,8 @@ pub struct RawOperation {
     pub address: String,
     /// Denom of tokens
     pub denom: String,
-    /// Amount of tokens
-    pub amount: Vec<u8>,
+    /// Amount of tokens (in decimal)
+    pub amount: String,
     /// Type of operation
     pub operation_type: Json<OperationType>,
     /// On-chain transaction hash (in hex)
@@ -62,7 +65,7 @@ impl From<Operation> for RawOperation {
             request_id: op.request_id,
             address: op.address,
             denom: op.denom.to_string(),
-            amount: op.amount.to_le_bytes().to_vec(),
+            amount: op.amount.to_string(),
             operation_type: Json(op.operation_type),
             transaction_hash: op.transaction_hash,
             created_at: op.created_at,
@@ -74,22 +77,12 @@ impl TryFrom<RawOperation> for Operation {
     type Error = Error;
 
     fn try_from(op: RawOperation) -> Result<Self, Self::Error> {
-        let mut amount_bytes = [0; 8];
-
-        ensure!(
-            op.amount.len() == 8,
-            "expected amount in u64 little endian bytes {}",
-            op.amount.len()
-        );
-
-        amount_bytes.copy_from_slice(&op.amount);
-
         Ok(Self {
             id: op.id,
             request_id: op.request_id,
             address: op.address,
             denom: op.denom.parse()?,
-            amount: u64::from_le_bytes(amount_bytes),
+            amount: op.amount.parse()?,
             operation_type: op.operation_type.0,
             transaction_hash: op.transaction_hash,
             created_at: op.created_at,
@@ -105,18 +98,44 @@ pub enum OperationType {
         /// Chain ID of IBC enabled chain
         chain_id: ChainId,
     },
+    /// Failed to mint tokens on IBC enabled chain (i.e., IBC enabled chain sent an error acknowledgement)
+    MintFailed {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Sequence of packet sent to IBC enabled chain
+        packet_sequence: u64,
+        /// Commitment of packet sent to IBC enabled chain (in hex)
+        packet_commitment: String,
+        /// Error in acknowledgement sent by IBC enabled chain
+        error: String,
+    },
     /// Burn some tokens on IBC enabled chain
     Burn {
         /// Chain ID of IBC enabled chain
         chain_id: ChainId,
     },
+    /// Refund burnt tokens on IBC enabled chain (after IBC packet timed out)
+    Refund {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+    },
+    /// Redeem tokens sent from IBC enabled chain to solo machine (by any account on IBC enabled chain)
+    Redeem {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+    },
 }
 
 impl fmt::Display for OperationType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Mint { chain_id } => write!(f, "mint [{}]", chain_id),
+            Self::MintFailed {
+                chain_id, error, ..
+            } => write!(f, "mint failed [{}]: {}", chain_id, error),
             Self::Burn { chain_id } => write!(f, "burn [{}]", chain_id),
+            Self::Refund { chain_id } => write!(f, "refund [{}]", chain_id),
+            Self::Redeem { chain_id } => write!(f, "redeem [{}]", chain_id),
         }
     }
 }
@@ -126,8 +145,8 @@ pub async fn add_operation<'e>(
     executor: impl Executor<'e, Database = Db>,
     request_id: Option<&str>,
     address: &str,
-    denom: &Identifier,
-    amount: u64,
+    denom: &Denom,
+    amount: &Amount,
     operation_type: &OperationType,
     transaction_hash: &str,
 ) -> Result<()> {
@@ -139,7 +158,7 @@ pub async fn add_operation<'e>(
     .bind(request_id)
     .bind(address)
     .bind(denom.to_string())
-    .bind(amount.to_le_bytes().to_vec())
+    .bind(amount.to_string())
     .bind(operation_type)
     .bind(transaction_hash)
     .execute(executor)
@@ -0,0 +1,219 @@
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::{ensure, Context, Error, Result};
+use chrono::{DateTime, Utc};
+use cosmos_sdk_proto::ibc::core::channel::v1::Packet;
+use prost::Message;
+use sqlx::{Executor, FromRow};
+
+use crate::{ibc::core::ics24_host::identifier::ChainId, proto::proto_encode, Db};
+
+/// Packet sent from IBC enabled chain to solo machine which is not yet acknowledged
+#[derive(Debug, Clone)]
+pub struct PendingAck {
+    /// Chain ID of IBC enabled chain
+    pub chain_id: ChainId,
+    /// Packet sent from IBC enabled chain
+    pub packet: Packet,
+    /// Block height of IBC enabled chain at which packet was sent
+    pub packet_height: u64,
+    /// Request ID for tracking purposes
+    pub request_id: Option<String>,
+    /// Number of failed attempts to acknowledge the packet
+    pub attempts: u32,
+    /// `true` if the packet cannot be acknowledged (i.e., all the attempts failed)
+    pub failed: bool,
+    /// Error in the last failed attempt to acknowledge the packet
+    pub last_error: Option<String>,
+    /// Time after which next attempt to acknowledge the packet is made
+    pub next_attempt_at: DateTime<Utc>,
+    /// Time at which this pending acknowledgement was created
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+/// Raw pending acknowledgement stored in database
+struct RawPendingAck {
+    /// Chain ID of IBC enabled chain
+    pub chain_id: String,
+    /// Packet sent from IBC enabled chain (protobuf encoded)
+    pub packet: Vec<u8>,
+    /// Block height of IBC enabled chain at which packet was sent
+    pub packet_height: i64,
+    /// Request ID for tracking purposes
+    pub request_id: Option<String>,
+    /// Number of failed attempts to acknowledge the packet
+    pub attempts: i32,
+    /// `true` if the packet cannot be acknowledged (i.e., all the attempts failed)
+    pub failed: bool,
+    /// Error in the last failed attempt to acknowledge the packet
+    pub last_error: Option<String>,
+    /// Time after which next attempt to acknowledge the packet is made
+    pub next_attempt_at: DateTime<Utc>,
+    /// Time at which this pending acknowledgement was created
+    pub created_at: DateTime<Utc>,
+}
+
+impl TryFrom<RawPendingAck> for PendingAck {
+    type Error = Error;
+
+    fn try_from(raw: RawPendingAck) -> Result<Self, Self::Error> {
+        Ok(Self {
+            chain_id: raw.chain_id.parse()?,
+            packet: Packet::decode(raw.packet.as_slice()).context("unable to decode packet")?,
+            packet_height: raw.packet_height.try_into()?,
+            request_id: raw.request_id,
+            attempts: raw.attempts.try_into()?,
+            failed: raw.failed,
+            last_error: raw.last_error,
+            next_attempt_at: raw.next_attempt_at,
+            created_at: raw.created_at,
+        })
+    }
+}
+
+/// Adds a pending acknowledgement for given packet to database (does nothing if the packet is already pending)
+pub async fn add_pending_ack<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    packet: &Packet,
+    packet_height: u64,
+    request_id: Option<&str>,
+) -> Result<()> {
+    let sequence: i64 = packet.sequence.try_into()?;
+    let packet_height: i64 = packet_height.try_into()?;
+
+    sqlx::query(
+        "INSERT INTO pending_acks (chain_id, port_id, channel_id, sequence, packet, packet_height, request_id, next_attempt_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8) ON CONFLICT (chain_id, port_id, channel_id, sequence) DO NOTHING",
+    )
+    .bind(chain_id.to_string())
+    .bind(&packet.source_port)
+    .bind(&packet.source_channel)
+    .bind(sequence)
+    .bind(proto_encode(packet)?)
+    .bind(packet_height)
+    .bind(request_id)
+    .bind(Utc::now())
+    .execute(executor)
+    .await
+    .context("unable to add pending acknowledgement to database")?;
+
+    Ok(())
+}
+
+/// Fetches pending acknowledgement of given packet from database
+pub async fn get_pending_ack<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    packet: &Packet,
+) -> Result<Option<PendingAck>> {
+    let sequence: i64 = packet.sequence.try_into()?;
+
+    sqlx::query_as(
+        "SELECT chain_id, packet, packet_height, request_id, attempts, failed, last_error, next_attempt_at, created_at FROM pending_acks WHERE chain_id = $1 AND port_id = $2 AND channel_id = $3 AND sequence = $4",
+    )
+    .bind(chain_id.to_string())
+    .bind(&packet.source_port)
+    .bind(&packet.source_channel)
+    .bind(sequence)
+    .fetch_optional(executor)
+    .await
+    .context("unable to query pending acknowledgement from database")?
+    .map(|raw: RawPendingAck| raw.try_into())
+    .transpose()
+}
+
+/// Fetches all the pending acknowledgements from database (ordered by creation time). Permanently failed
+/// acknowledgements are only included if `include_failed` is `true`.
+pub async fn get_pending_acks<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    include_failed: bool,
+) -> Result<Vec<PendingAck>> {
+    sqlx::query_as(
+        "SELECT chain_id, packet, packet_height, request_id, attempts, failed, last_error, next_attempt_at, created_at FROM pending_acks WHERE failed = FALSE OR $1 ORDER BY created_at, sequence",
+    )
+    .bind(include_failed)
+    .fetch_all(executor)
+    .await
+    .context("unable to query pending acknowledgements from database")?
+    .into_iter()
+    .map(|raw: RawPendingAck| raw.try_into())
+    .collect()
+}
+
+/// Fetches all the pending acknowledgements (except permanently failed ones) which are due for next attempt at given
+/// time from database (ordered by creation time)
+pub async fn get_due_pending_acks<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    now: DateTime<Utc>,
+) -> Result<Vec<PendingAck>> {
+    sqlx::query_as(
+        "SELECT chain_id, packet, packet_height, request_id, attempts, failed, last_error, next_attempt_at, created_at FROM pending_acks WHERE failed = FALSE AND next_attempt_at <= $1 ORDER BY created_at, sequence",
+    )
+    .bind(now)
+    .fetch_all(executor)
+    .await
+    .context("unable to query due pending acknowledgements from database")?
+    .into_iter()
+    .map(|raw: RawPendingAck| raw.try_into())
+    .collect()
+}
+
+/// Records a failed attempt to acknowledge given packet in database
+pub async fn update_pending_ack_failure<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    packet: &Packet,
+    attempts: u32,
+    failed: bool,
+    error: &str,
+    next_attempt_at: DateTime<Utc>,
+) -> Result<()> {
+    let sequence: i64 = packet.sequence.try_into()?;
+    let attempts: i32 = attempts.try_into()?;
+
+    let rows_affected = sqlx::query(
+        "UPDATE pending_acks SET attempts = $1, failed = $2, last_error = $3, next_attempt_at = $4 WHERE chain_id = $5 AND port_id = $6 AND channel_id = $7 AND sequence = $8",
+    )
+    .bind(attempts)
+    .bind(failed)
+    .bind(error)
+    .bind(next_attempt_at)
+    .bind(chain_id.to_string())
+    .bind(&packet.source_port)
+    .bind(&packet.source_channel)
+    .bind(sequence)
+    .execute(executor)
+    .await
+    .context("unable to update pending acknowledgement in database")?
+    .rows_affected();
+
+    ensure!(
+        rows_affected == 1,
+        "rows_affected should be equal to 1 when updating pending acknowledgement"
+    );
+
+    Ok(())
+}
+
+/// Removes pending acknowledgement of given packet from database (does nothing if the packet is not pending)
+pub async fn remove_pending_ack<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    packet: &Packet,
+) -> Result<()> {
+    let sequence: i64 = packet.sequence.try_into()?;
+
+    sqlx::query(
+        "DELETE FROM pending_acks WHERE chain_id = $1 AND port_id = $2 AND channel_id = $3 AND sequence = $4",
+    )
+    .bind(chain_id.to_string())
+    .bind(&packet.source_port)
+    .bind(&packet.source_channel)
+    .bind(sequence)
+    .execute(executor)
+    .await
+    .context("unable to remove pending acknowledgement from database")?;
+
+    Ok(())
+}
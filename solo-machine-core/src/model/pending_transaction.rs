@@ -0,0 +1,230 @@
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt,
+    str::FromStr,
+};
+
+use anyhow::{anyhow, ensure, Context, Error, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{types::Json, Executor, FromRow};
+
+use crate::{
+    ibc::{
+        apps::transfer::{amount::Amount, denom::Denom},
+        core::ics24_host::identifier::{ChainId, ChannelId},
+    },
+    Db,
+};
+
+/// Transaction sent to IBC enabled chain whose outcome was unknown when it was broadcasted (i.e., it was not included
+/// in a block before timeout). Changes made to the state of solo machine for the transaction are retained and the
+/// operation is finalized (or voided) once the transaction is reconciled.
+#[derive(Debug, Clone)]
+pub struct PendingTransaction {
+    /// On-chain transaction hash (in hex)
+    pub transaction_hash: String,
+    /// Chain ID of IBC enabled chain
+    pub chain_id: ChainId,
+    /// Request ID for tracking purposes
+    pub request_id: Option<String>,
+    /// Operation to be finalized once the transaction is included in a block
+    pub operation: PendingOperation,
+    /// Status of transaction
+    pub status: PendingTransactionStatus,
+    /// Time at which this pending transaction was created
+    pub created_at: DateTime<Utc>,
+    /// Time at which this pending transaction was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+/// Raw pending transaction stored in database
+struct RawPendingTransaction {
+    /// On-chain transaction hash (in hex)
+    pub transaction_hash: String,
+    /// Chain ID of IBC enabled chain
+    pub chain_id: String,
+    /// Request ID for tracking purposes
+    pub request_id: Option<String>,
+    /// Operation to be finalized once the transaction is included in a block
+    pub operation: Json<PendingOperation>,
+    /// Status of transaction
+    pub status: String,
+    /// Time at which this pending transaction was created
+    pub created_at: DateTime<Utc>,
+    /// Time at which this pending transaction was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<RawPendingTransaction> for PendingTransaction {
+    type Error = Error;
+
+    fn try_from(raw: RawPendingTransaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            transaction_hash: raw.transaction_hash,
+            chain_id: raw.chain_id.parse()?,
+            request_id: raw.request_id,
+            operation: raw.operation.0,
+            status: raw.status.parse()?,
+            created_at: raw.created_at,
+            updated_at: raw.updated_at,
+        })
+    }
+}
+
+/// Operations which are finalized once their transaction is included in a block
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PendingOperation {
+    /// Mint some tokens on IBC enabled chain
+    Mint {
+        /// Channel ID of solo machine
+        channel_id: ChannelId,
+        /// Address of receiver on IBC enabled chain
+        receiver: String,
+        /// Amount of tokens
+        amount: Amount,
+        /// Denom of tokens
+        denom: Denom,
+    },
+    /// Acknowledge a packet sent from IBC enabled chain to solo machine
+    Acknowledge {
+        /// Packet sent from IBC enabled chain (protobuf encoded, in hex)
+        packet: String,
+        /// Denom of tokens in packet
+        denom: Denom,
+        /// Reason for rejecting the packet (if it was rejected by acceptance policy)
+        rejection_reason: Option<String>,
+        /// `true` if tokens were redeemed by an account other than solo machine's (i.e., redemption is recorded)
+        redeemed: bool,
+    },
+    /// Refund burnt tokens on IBC enabled chain (after IBC packet timed out)
+    Refund {
+        /// Packet sent from IBC enabled chain (protobuf encoded, in hex)
+        packet: String,
+        /// Denom of tokens in packet
+        denom: Denom,
+    },
+    /// Any other transaction which does not record an account operation (e.g., connection handshake)
+    Other {
+        /// Description of transaction
+        description: String,
+    },
+}
+
+impl fmt::Display for PendingOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mint { .. } => write!(f, "mint"),
+            Self::Acknowledge { .. } => write!(f, "acknowledge packet"),
+            Self::Refund { .. } => write!(f, "refund"),
+            Self::Other { description } => write!(f, "{}", description),
+        }
+    }
+}
+
+/// Status of a pending transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PendingTransactionStatus {
+    /// Transaction is not yet included in a block
+    Pending,
+    /// Transaction was included in a block and its operation is finalized
+    Finalized,
+    /// Transaction was included in a block but it failed (i.e., its operation is voided)
+    Voided,
+}
+
+impl fmt::Display for PendingTransactionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pending => write!(f, "pending"),
+            Self::Finalized => write!(f, "finalized"),
+            Self::Voided => write!(f, "voided"),
+        }
+    }
+}
+
+impl FromStr for PendingTransactionStatus {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "finalized" => Ok(Self::Finalized),
+            "voided" => Ok(Self::Voided),
+            _ => Err(anyhow!("invalid pending transaction status: {}", s)),
+        }
+    }
+}
+
+/// Adds a pending transaction to database
+pub async fn add_pending_transaction<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    transaction_hash: &str,
+    chain_id: &ChainId,
+    request_id: Option<&str>,
+    operation: &PendingOperation,
+) -> Result<()> {
+    let rows_affected = sqlx::query(
+        "INSERT INTO pending_transactions (transaction_hash, chain_id, request_id, operation, status) VALUES ($1, $2, $3, $4, $5)",
+    )
+    .bind(transaction_hash)
+    .bind(chain_id.to_string())
+    .bind(request_id)
+    .bind(Json(operation))
+    .bind(PendingTransactionStatus::Pending.to_string())
+    .execute(executor)
+    .await
+    .context("unable to add pending transaction to database")?
+    .rows_affected();
+
+    ensure!(
+        rows_affected == 1,
+        "rows_affected should be equal to 1 when adding a pending transaction"
+    );
+
+    Ok(())
+}
+
+/// Fetches pending transaction with given hash from database
+pub async fn get_pending_transaction<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    transaction_hash: &str,
+) -> Result<Option<PendingTransaction>> {
+    sqlx::query_as(
+        "SELECT transaction_hash, chain_id, request_id, operation, status, created_at, updated_at FROM pending_transactions WHERE transaction_hash = $1",
+    )
+    .bind(transaction_hash)
+    .fetch_optional(executor)
+    .await
+    .context("unable to query pending transaction from database")?
+    .map(|raw: RawPendingTransaction| raw.try_into())
+    .transpose()
+}
+
+/// Updates status of a pending transaction in database (fails if the transaction is not pending anymore)
+pub async fn update_pending_transaction_status<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    transaction_hash: &str,
+    status: PendingTransactionStatus,
+) -> Result<()> {
+    let rows_affected = sqlx::query(
+        "UPDATE pending_transactions SET status = $1, updated_at = $2 WHERE transaction_hash = $3 AND status = $4",
+    )
+    .bind(status.to_string())
+    .bind(Utc::now())
+    .bind(transaction_hash)
+    .bind(PendingTransactionStatus::Pending.to_string())
+    .execute(executor)
+    .await
+    .context("unable to update status of pending transaction in database")?
+    .rows_affected();
+
+    ensure!(
+        rows_affected == 1,
+        "transaction {} is not pending",
+        transaction_hash
+    );
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::{Context, Error, Result};
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, FromRow};
+
+use crate::{
+    ibc::core::ics24_host::identifier::{ChainId, ClientId},
+    Db,
+};
+
+/// `SignBytes` signed by solo machine for a sequence of a solo machine client (used to refuse signing conflicting
+/// `SignBytes` for the same sequence, which can get solo machine client frozen)
+#[derive(Debug, Clone)]
+pub struct SignLedgerEntry {
+    /// Chain ID of IBC enabled chain
+    pub chain_id: ChainId,
+    /// Client ID of solo machine client on IBC enabled chain
+    pub client_id: ClientId,
+    /// Sequence of solo machine
+    pub sequence: u64,
+    /// Type of data in `SignBytes`
+    pub data_type: i32,
+    /// Hex encoded SHA-256 hash of data in `SignBytes`
+    pub data_hash: String,
+    /// Time at which `SignBytes` were signed
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+/// Raw sign-ledger entry stored in database
+struct RawSignLedgerEntry {
+    /// Chain ID of IBC enabled chain
+    pub chain_id: String,
+    /// Client ID of solo machine client on IBC enabled chain
+    pub client_id: String,
+    /// Sequence of solo machine
+    pub sequence: i64,
+    /// Type of data in `SignBytes`
+    pub data_type: i32,
+    /// Hex encoded SHA-256 hash of data in `SignBytes`
+    pub data_hash: String,
+    /// Time at which `SignBytes` were signed
+    pub created_at: DateTime<Utc>,
+}
+
+impl TryFrom<RawSignLedgerEntry> for SignLedgerEntry {
+    type Error = Error;
+
+    fn try_from(raw: RawSignLedgerEntry) -> Result<Self, Self::Error> {
+        Ok(Self {
+            chain_id: raw.chain_id.parse()?,
+            client_id: raw.client_id.parse()?,
+            sequence: raw.sequence.try_into()?,
+            data_type: raw.data_type,
+            data_hash: raw.data_hash,
+            created_at: raw.created_at,
+        })
+    }
+}
+
+/// Adds a sign-ledger entry to database (does nothing if an entry already exists for given sequence of given solo
+/// machine client). Returns `true` if a new entry was added.
+pub async fn add_sign_ledger_entry<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    client_id: &ClientId,
+    sequence: u64,
+    data_type: i32,
+    data_hash: &str,
+) -> Result<bool> {
+    let sequence: i64 = sequence.try_into()?;
+
+    let rows_affected = sqlx::query(
+        "INSERT INTO sign_ledger (chain_id, client_id, sequence, data_type, data_hash) VALUES ($1, $2, $3, $4, $5) ON CONFLICT (chain_id, client_id, sequence) DO NOTHING",
+    )
+    .bind(chain_id.to_string())
+    .bind(client_id.to_string())
+    .bind(sequence)
+    .bind(data_type)
+    .bind(data_hash)
+    .execute(executor)
+    .await
+    .context("unable to add sign-ledger entry to database")?
+    .rows_affected();
+
+    Ok(rows_affected == 1)
+}
+
+/// Fetches sign-ledger entry for given sequence of given solo machine client from database
+pub async fn get_sign_ledger_entry<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain_id: &ChainId,
+    client_id: &ClientId,
+    sequence: u64,
+) -> Result<Option<SignLedgerEntry>> {
+    let sequence: i64 = sequence.try_into()?;
+
+    sqlx::query_as(
+        "SELECT chain_id, client_id, sequence, data_type, data_hash, created_at FROM sign_ledger WHERE chain_id = $1 AND client_id = $2 AND sequence = $3",
+    )
+    .bind(chain_id.to_string())
+    .bind(client_id.to_string())
+    .bind(sequence)
+    .fetch_optional(executor)
+    .await
+    .context("unable to query sign-ledger entry from database")?
+    .map(|raw: RawSignLedgerEntry| raw.try_into())
+    .transpose()
+}
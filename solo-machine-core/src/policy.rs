@@ -0,0 +1,72 @@
+//! Acceptance policy for packets sent from IBC enabled chain to solo machine
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::ibc::{
+    apps::transfer::{amount::Amount, denom::Denom},
+    core::ics24_host::identifier::ChainId,
+};
+
+/// Token transfer packet sent from IBC enabled chain to solo machine
+#[derive(Debug, Clone)]
+pub struct IncomingPacket {
+    /// Chain ID of IBC enabled chain
+    pub chain_id: ChainId,
+    /// Denom of tokens (on solo machine)
+    pub denom: Denom,
+    /// Amount of tokens
+    pub amount: Amount,
+    /// Address of sender on IBC enabled chain
+    pub sender: String,
+    /// Address of receiver on solo machine
+    pub receiver: String,
+    /// Optional memo in packet data
+    pub memo: Option<String>,
+}
+
+/// Decision of acceptance policy for a packet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketDecision {
+    /// Accept the packet (i.e., send a success acknowledgement)
+    Accept,
+    /// Reject the packet (i.e., send an error acknowledgement with given reason so that IBC enabled chain refunds the
+    /// sender)
+    Reject {
+        /// Reason for rejecting the packet
+        reason: String,
+    },
+}
+
+/// This trait must be implemented by all the acceptance policies (consulted for every packet sent from IBC enabled
+/// chain to solo machine before acknowledging it)
+#[async_trait]
+pub trait AcceptancePolicy: Send + Sync {
+    /// Returns decision for given packet
+    async fn check(&self, packet: &IncomingPacket) -> Result<PacketDecision>;
+}
+
+#[async_trait]
+impl<T: AcceptancePolicy + ?Sized> AcceptancePolicy for Arc<T> {
+    async fn check(&self, packet: &IncomingPacket) -> Result<PacketDecision> {
+        (**self).check(packet).await
+    }
+}
+
+/// Acceptance policy which accepts all the packets
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AcceptAll;
+
+#[async_trait]
+impl AcceptancePolicy for AcceptAll {
+    async fn check(&self, _: &IncomingPacket) -> Result<PacketDecision> {
+        Ok(PacketDecision::Accept)
+    }
+}
+
+/// Trait to register an acceptance policy
+pub trait PolicyRegistrar {
+    /// Registers a new acceptance policy
+    fn register(&mut self, policy: Arc<dyn AcceptancePolicy>);
+}
@@ -40,13 +40,24 @@ pub mod ethermint {
     }
 }
 
-#[cfg(feature = "solomachine-v2")]
 pub mod ibc {
+    pub mod core {
+        pub mod client {
+            pub mod v1 {
+                tonic::include_proto!("ibc.core.client.v1");
+            }
+        }
+    }
+
     pub mod lightclients {
         pub mod solomachine {
             pub mod v2 {
                 tonic::include_proto!("ibc.lightclients.solomachine.v2");
             }
+
+            pub mod v3 {
+                tonic::include_proto!("ibc.lightclients.solomachine.v3");
+            }
         }
     }
 }
@@ -2,4 +2,7 @@
 pub(crate) mod chain_service;
 pub(crate) mod ibc_service;
 
-pub use self::{chain_service::ChainService, ibc_service::IbcService};
+pub use self::{
+    chain_service::ChainService,
+    ibc_service::{ClientStatus, IbcService, UnknownTxOutcome},
+};
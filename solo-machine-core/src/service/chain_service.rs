@@ -7,10 +7,13 @@ use tokio::sync::mpsc::UnboundedSender;
 
 use crate::{
     event::notify_event,
-    ibc::core::ics24_host::identifier::{ChainId, Identifier},
+    ibc::{
+        apps::transfer::denom::Denom,
+        core::ics24_host::identifier::{ChainId, ChannelId},
+    },
     model::{
-        chain::{self, chain_keys},
-        Chain, ChainConfig,
+        chain::{self, chain_keys, connection, solo_machine_client},
+        Chain, ChainConfig, ConnectionDetails,
     },
     DbPool, Event, ToPublicKey,
 };
@@ -70,15 +73,23 @@ impl ChainService {
         Ok(chain_id)
     }
 
-    /// Returns the final denom of a token on solo machine after sending it on given chain
-    pub async fn get_ibc_denom(&self, chain_id: &ChainId, denom: &Identifier) -> Result<String> {
+    /// Returns the final denom of a token on solo machine after sending it on given chain over given channel (uses
+    /// default channel of chain if channel is not provided)
+    pub async fn get_ibc_denom(
+        &self,
+        chain_id: &ChainId,
+        channel_id: Option<&ChannelId>,
+        denom: &Denom,
+    ) -> Result<String> {
         let chain = self
             .get(chain_id)
             .await?
             .ok_or_else(|| anyhow!("chain details not found when computing ibc denom"))?;
-        chain
-            .get_ibc_denom(denom)
-            .ok_or_else(|| anyhow!("connection details not found when computing ibc denom"))
+
+        let connection_details =
+            connection::select_connection(&self.db_pool, &chain, channel_id).await?;
+
+        Ok(connection_details.get_ibc_denom(denom))
     }
 
     /// Fetches details of a chain
@@ -86,6 +97,45 @@ impl ChainService {
         chain::get_chain(&self.db_pool, chain_id).await
     }
 
+    /// Fetches current sequence of solo machine client used by default connection of given chain (`None` if chain is
+    /// not connected)
+    pub async fn get_sequence(&self, chain: &Chain) -> Result<Option<u64>> {
+        let connection_details = match chain.connection_details {
+            None => return Ok(None),
+            Some(ref connection_details) => connection_details,
+        };
+
+        let client = solo_machine_client::get_solo_machine_client(
+            &self.db_pool,
+            &chain.id,
+            &connection_details.solo_machine_client_id,
+        )
+        .await?
+        .ok_or_else(|| {
+            anyhow!(
+                "solo machine client with id {} not found for chain with id {}",
+                connection_details.solo_machine_client_id,
+                chain.id
+            )
+        })?;
+
+        Ok(Some(client.sequence))
+    }
+
+    /// Fetches current packet sequence of default channel of given chain (`None` if chain is not connected)
+    pub async fn get_packet_sequence(&self, chain: &Chain) -> Result<Option<u64>> {
+        match chain.connection_details {
+            None => Ok(None),
+            Some(ref connection_details) => connection::get_packet_sequence(
+                &self.db_pool,
+                &chain.id,
+                &connection_details.solo_machine_channel_id,
+            )
+            .await
+            .map(Some),
+        }
+    }
+
     /// Fetches all the public keys associated with solo machine client on given chain
     pub async fn get_public_keys(
         &self,
@@ -96,18 +146,27 @@ impl ChainService {
         chain_keys::get_chain_keys(&self.db_pool, chain_id, limit, offset).await
     }
 
-    /// Fetches balance of given denom on IBC enabled chain
+    /// Fetches all the established connections (and channels) with given chain
+    pub async fn get_connections(&self, chain_id: &ChainId) -> Result<Vec<ConnectionDetails>> {
+        connection::get_connections(&self.db_pool, chain_id).await
+    }
+
+    /// Fetches balance of given denom (sent over given channel or default channel of chain) on IBC enabled chain
     pub async fn balance(
         &self,
         signer: impl ToPublicKey,
         chain_id: &ChainId,
-        denom: &Identifier,
+        channel_id: Option<&ChannelId>,
+        denom: &Denom,
     ) -> Result<Decimal> {
         let chain = self
             .get(chain_id)
             .await?
             .ok_or_else(|| anyhow!("chain details not found when fetching balance"))?;
 
-        chain.get_balance(signer, denom).await
+        let connection_details =
+            connection::select_connection(&self.db_pool, &chain, channel_id).await?;
+
+        chain.get_balance(signer, &connection_details, denom).await
     }
 }
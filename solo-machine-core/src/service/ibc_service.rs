@@ -1,6 +1,13 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    convert::{TryFrom, TryInto},
+    fmt,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
 
-use anyhow::{anyhow, ensure, Context, Result};
+use anyhow::{anyhow, ensure, Context, Error, Result};
+use chrono::{DateTime, TimeZone, Utc};
 use cosmos_sdk_proto::ibc::core::{
     channel::v1::{
         Channel, Counterparty as ChannelCounterparty, Order as ChannelOrder, Packet,
@@ -13,53 +20,168 @@ use cosmos_sdk_proto::ibc::core::{
         Version as ConnectionVersion,
     },
 };
+use cosmos_sdk_proto::{cosmos::tx::v1beta1::TxRaw, ics23::CommitmentProof};
+use prost::Message;
+use prost_types::Any;
+use sha2::{Digest, Sha256};
 use sqlx::{Executor, Transaction};
 use tendermint::{
     abci::{
         tag::{Key, Tag},
+        transaction::Hash as TransactionHash,
         Event as AbciEvent,
     },
+    block::Height as BlockHeight,
     trust_threshold::TrustThresholdFraction,
     Hash as TendermintHash,
 };
 use tendermint_light_client::{
-    builder::LightClientBuilder, light_client::Options, store::memory::MemoryStore,
-    store::LightStore, supervisor::Instance,
+    builder::LightClientBuilder, light_client::Options, store::LightStore, supervisor::Instance,
 };
 use tendermint_rpc::{
-    endpoint::broadcast::tx_commit::Response as TxCommitResponse, Client, HttpClient,
+    endpoint::broadcast::{
+        tx_commit::{Response as TxCommitResponse, TxResult},
+        tx_sync::Response as SyncResponse,
+    },
+    error::Code as RpcErrorCode,
+    query::Query,
+    Client, Error as RpcError, HttpClient, Order,
+};
+use tokio::{
+    sync::{mpsc::UnboundedSender, Mutex},
+    time::sleep,
+};
+use tonic::{
+    client::Grpc, codec::ProstCodec, codegen::http::uri::PathAndQuery, transport::Endpoint, Request,
 };
-use tokio::sync::mpsc::UnboundedSender;
 
 use crate::{
     cosmos::crypto::PublicKey,
     event::{notify_event, Event},
-    ibc::core::{
-        ics02_client::{client_type::ClientType, height::IHeight},
-        ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, Identifier, PortId},
+    ibc::{
+        apps::transfer::{amount::Amount, denom::Denom},
+        core::{
+            ics02_client::{client_type::ClientType, height::IHeight},
+            ics04_channel::{channel::ChannelOrdering, packet::IPacket},
+            ics23_vector_commitments::{proof_specs, verify_membership},
+            ics24_host::{
+                identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId},
+                path::{
+                    ChannelPath, ClientStatePath, ConnectionPath, PacketAcknowledgementPath,
+                    PacketCommitmentPath,
+                },
+            },
+        },
     },
     model::{
-        chain::{self, chain_keys},
+        chain::{
+            self, chain_keys, connection, handshake,
+            solo_machine_client::{self, SoloMachineClient},
+        },
         ibc as ibc_handler,
         operation::{self, Operation},
-        Chain, ConnectionDetails as ChainConnectionDetails, OperationType,
+        pending_ack,
+        pending_transaction::{
+            self, PendingOperation, PendingTransaction, PendingTransactionStatus,
+        },
+        BroadcastMode, Chain, ConnectionDetails as ChainConnectionDetails, DbLightStore, Handshake,
+        HandshakeStep, OperationType, PendingAck,
+    },
+    policy::{AcceptAll, AcceptancePolicy, IncomingPacket, PacketDecision},
+    proto::{
+        ibc::core::client::v1::{
+            ClientUpdateProposal, QueryClientStateRequest, QueryClientStateResponse,
+        },
+        proto_encode, AnyConvert,
     },
-    proto::proto_encode,
-    transaction_builder, Db, DbPool, Signer, ToPublicKey,
+    signer::{guard, offline::UnsignedMint},
+    transaction_builder::{self, TokenTransferPacketData},
+    Db, DbPool, Signer, ToPublicKey,
 };
 
+/// Version of ICS-20 (fungible token transfer) channels
+const ICS20_VERSION: &str = "ics20-1";
+/// Key of IBC store in multi-store of IBC enabled chain
+const IBC_STORE_KEY: &str = "ibc";
+/// Interval between polls for a new block on IBC enabled chain
+const BLOCK_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Maximum number of polls for a new block on IBC enabled chain
+const MAX_BLOCK_POLL_ATTEMPTS: u32 = 30;
+/// Number of transactions fetched per page when searching for packets sent from IBC enabled chain
+const TX_SEARCH_PAGE_SIZE: u8 = 100;
+/// Maximum number of attempts to acknowledge a packet sent from IBC enabled chain before it is marked as failed
+const MAX_ACK_ATTEMPTS: u32 = 8;
+/// Delay before first retry of a failed packet acknowledgement (doubled after every failed attempt)
+const ACK_RETRY_BASE_DELAY: Duration = Duration::from_secs(30);
+/// Maximum delay between retries of a failed packet acknowledgement
+const ACK_RETRY_MAX_DELAY: Duration = Duration::from_secs(60 * 60);
+/// Interval at which IBC enabled chain is polled for inclusion of a broadcasted transaction
+const TX_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Path of IBC client gRPC query for client state
+const CLIENT_STATE_QUERY_PATH: &str = "/ibc.core.client.v1.Query/ClientState";
+
 /// Used to connect, send tokens and receive tokens over IBC
+#[derive(Clone)]
 pub struct IbcService {
     db_pool: DbPool,
     notifier: Option<UnboundedSender<Event>>,
+    /// Policy consulted before acknowledging packets sent from IBC enabled chain (accepts all the packets by default)
+    policy: Arc<dyn AcceptancePolicy>,
+    /// Optional signer which pays fees of transactions sent to IBC enabled chain (fees are paid by the signer of
+    /// transaction by default)
+    fee_payer: Option<Arc<dyn Signer>>,
+    /// Serializes processing of packets sent from IBC enabled chain (so that packets of a burn are not acknowledged
+    /// twice by packet listener)
+    packet_lock: Arc<Mutex<()>>,
+}
+
+/// Status of solo machine client on IBC enabled chain
+#[derive(Debug, Clone)]
+pub struct ClientStatus {
+    /// Client ID of solo machine client on IBC enabled chain
+    pub client_id: ClientId,
+    /// Sequence of solo machine client
+    pub sequence: u64,
+    /// Whether solo machine client is frozen
+    pub frozen: bool,
+    /// Signer's public key in consensus state of solo machine client
+    pub public_key: Option<PublicKey>,
+}
+
+/// Error returned when a transaction broadcasted to IBC enabled chain is not included in a block before timeout. The
+/// transaction may still be included later, so, changes made to the state of solo machine for it (e.g., incremented
+/// sequences) are retained instead of being rolled back and the transaction is recorded as pending. Outcome of the
+/// transaction must be reconciled using its hash (see [`IbcService::reconcile`]).
+#[derive(Debug, Clone)]
+pub struct UnknownTxOutcome {
+    /// Hash of transaction on IBC enabled chain (in hex)
+    pub transaction_hash: String,
+    /// Time for which IBC enabled chain was polled for inclusion of transaction
+    pub timeout: Duration,
 }
 
+impl fmt::Display for UnknownTxOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "outcome of transaction {} is unknown: it was not confirmed to be included in a block within {} seconds (it may still be included later, reconcile it using its hash)",
+            self.transaction_hash,
+            self.timeout.as_secs()
+        )
+    }
+}
+
+impl std::error::Error for UnknownTxOutcome {}
+
 impl IbcService {
     /// Creates a new instance of IBC service
     pub fn new(db_pool: DbPool) -> Self {
         Self {
             db_pool,
             notifier: None,
+            policy: Arc::new(AcceptAll),
+            fee_payer: None,
+            packet_lock: Default::default(),
         }
     }
 
@@ -68,556 +190,3120 @@ impl IbcService {
         Self {
             db_pool,
             notifier: Some(notifier),
+            policy: Arc::new(AcceptAll),
+            fee_payer: None,
+            packet_lock: Default::default(),
         }
     }
 
-    /// Establishes connection with an IBC enabled chain
+    /// Sets acceptance policy which is consulted before acknowledging packets sent from IBC enabled chain
+    pub fn with_policy(mut self, policy: Arc<dyn AcceptancePolicy>) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Sets a separate signer which pays fees of all the transactions sent to IBC enabled chain
+    pub fn with_fee_payer(mut self, fee_payer: Arc<dyn Signer>) -> Self {
+        self.fee_payer = Some(fee_payer);
+        self
+    }
+
+    /// Establishes connection with an IBC enabled chain. If `resume` is `true`, continues an interrupted connection
+    /// handshake from its last confirmed step instead of starting a new one.
     pub async fn connect(
         &self,
         signer: impl Signer,
         chain_id: ChainId,
         memo: String,
         force: bool,
+        resume: bool,
     ) -> Result<()> {
-        let mut transaction = self
-            .db_pool
-            .begin()
-            .await
-            .context("unable to begin database transaction")?;
-
-        let mut chain = chain::get_chain(&mut transaction, &chain_id)
+        let chain = chain::get_chain(&self.db_pool, &chain_id)
             .await?
             .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
 
-        if !force {
+        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
+            .context("unable to connect to rpc client")?;
+
+        let mut handshake = if resume {
+            let handshake = handshake::get_handshake(&self.db_pool, &chain_id)
+                .await?
+                .ok_or_else(|| anyhow!("connection handshake for {} not found", chain_id))?;
+
             ensure!(
-                chain.connection_details.is_none(),
-                "connection is already established with given chain"
+                !handshake.step.is_final(),
+                "connection handshake with given chain is already complete"
             );
-        }
 
-        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
-            .context("unable to connect to rpc client")?;
-        let mut instance =
-            prepare_light_client(&chain, rpc_client.clone(), Box::new(MemoryStore::new()))?;
+            handshake
+        } else {
+            if !force {
+                ensure!(
+                    chain.connection_details.is_none(),
+                    "connection is already established with given chain"
+                );
+
+                if let Some(handshake) = handshake::get_handshake(&self.db_pool, &chain_id).await? {
+                    ensure!(
+                        handshake.step.is_final(),
+                        "an incomplete connection handshake exists for given chain (last step: {}), resume it or force create a new connection",
+                        handshake.step
+                    );
+                }
+            }
 
-        let solo_machine_client_id =
-            create_solo_machine_client(&signer, &rpc_client, &chain, memo.clone()).await?;
+            let solo_machine_client_id = create_solo_machine_client(
+                &signer,
+                self.fee_payer.as_ref(),
+                &rpc_client,
+                &chain,
+                memo.clone(),
+            )
+            .await?;
 
-        notify_event(
-            &self.notifier,
-            Event::CreatedSoloMachineClient {
-                client_id: solo_machine_client_id.clone(),
-            },
-        )?;
+            let mut transaction = self
+                .db_pool
+                .begin()
+                .await
+                .context("unable to begin database transaction")?;
 
-        let tendermint_client_id =
-            create_tendermint_client(&mut transaction, &mut instance, &chain).await?;
+            solo_machine_client::add_solo_machine_client(
+                &mut transaction,
+                &chain_id,
+                &solo_machine_client_id,
+                solo_machine_client::INITIAL_SEQUENCE,
+                chain.consensus_timestamp,
+            )
+            .await?;
 
-        notify_event(
-            &self.notifier,
-            Event::CreatedTendermintClient {
-                client_id: tendermint_client_id.clone(),
-            },
-        )?;
+            let handshake =
+                handshake::add_handshake(&mut transaction, &chain_id, &solo_machine_client_id)
+                    .await?;
 
-        let solo_machine_connection_id = connection_open_init(
-            &signer,
-            &rpc_client,
-            &chain,
-            &solo_machine_client_id,
-            &tendermint_client_id,
-            memo.clone(),
-        )
-        .await?;
+            transaction
+                .commit()
+                .await
+                .context("unable to commit transaction for creating solo machine client")?;
 
-        notify_event(
-            &self.notifier,
-            Event::InitializedConnectionOnTendermint {
-                connection_id: solo_machine_connection_id.clone(),
-            },
-        )?;
+            notify_event(
+                &self.notifier,
+                Event::CreatedSoloMachineClient {
+                    client_id: solo_machine_client_id,
+                },
+            )?;
 
-        let tendermint_connection_id = connection_open_try(
-            &mut transaction,
-            &tendermint_client_id,
-            &solo_machine_client_id,
-            &solo_machine_connection_id,
-        )
-        .await?;
+            handshake
+        };
 
-        notify_event(
-            &self.notifier,
-            Event::InitializedConnectionOnSoloMachine {
-                connection_id: tendermint_connection_id.clone(),
-            },
-        )?;
+        let solo_machine_client_id =
+            get_handshake_id(&handshake.solo_machine_client_id, "solo machine client id")?;
 
-        connection_open_ack(
-            &mut transaction,
-            &signer,
-            &rpc_client,
-            &mut chain,
-            &solo_machine_connection_id,
-            &tendermint_client_id,
-            &tendermint_connection_id,
-            memo.clone(),
-        )
-        .await?;
+        if !handshake.has_completed(HandshakeStep::CreatedTendermintClient) {
+            let light_store = DbLightStore::load(&self.db_pool, chain_id.clone()).await?;
+            let mut instance =
+                prepare_light_client(&chain, rpc_client.clone(), Box::new(light_store.clone()))?;
 
-        notify_event(
-            &self.notifier,
-            Event::ConfirmedConnectionOnTendermint {
-                connection_id: solo_machine_connection_id.clone(),
-            },
-        )?;
+            let mut transaction = self
+                .db_pool
+                .begin()
+                .await
+                .context("unable to begin database transaction")?;
 
-        connection_open_confirm(&mut transaction, &tendermint_connection_id).await?;
+            let tendermint_client_id =
+                create_tendermint_client(&mut transaction, &mut instance, &chain).await?;
+            light_store.flush(&mut transaction).await?;
 
-        notify_event(
-            &self.notifier,
-            Event::ConfirmedConnectionOnSoloMachine {
-                connection_id: tendermint_connection_id.clone(),
-            },
-        )?;
+            handshake.tendermint_client_id = Some(tendermint_client_id.clone());
+            confirm_handshake_step(
+                transaction,
+                &mut handshake,
+                HandshakeStep::CreatedTendermintClient,
+            )
+            .await?;
 
-        let solo_machine_channel_id = channel_open_init(
-            &signer,
-            &rpc_client,
-            &chain,
-            &solo_machine_connection_id,
-            memo.clone(),
-        )
-        .await?;
+            notify_event(
+                &self.notifier,
+                Event::CreatedTendermintClient {
+                    client_id: tendermint_client_id,
+                },
+            )?;
+        }
 
-        notify_event(
-            &self.notifier,
-            Event::InitializedChannelOnTendermint {
-                channel_id: solo_machine_channel_id.clone(),
-            },
-        )?;
+        let tendermint_client_id =
+            get_handshake_id(&handshake.tendermint_client_id, "tendermint client id")?;
 
-        let tendermint_channel_id = channel_open_try(
-            &mut transaction,
-            &chain.config.port_id,
-            &solo_machine_channel_id,
-            &tendermint_connection_id,
-        )
-        .await?;
+        if !handshake.has_completed(HandshakeStep::InitializedConnectionOnTendermint) {
+            let solo_machine_connection_id = connection_open_init(
+                &signer,
+                self.fee_payer.as_ref(),
+                &rpc_client,
+                &chain,
+                &solo_machine_client_id,
+                &tendermint_client_id,
+                memo.clone(),
+            )
+            .await?;
 
-        notify_event(
-            &self.notifier,
-            Event::InitializedChannelOnSoloMachine {
-                channel_id: tendermint_channel_id.clone(),
-            },
-        )?;
+            let transaction = self
+                .db_pool
+                .begin()
+                .await
+                .context("unable to begin database transaction")?;
 
-        channel_open_ack(
-            &mut transaction,
-            signer,
-            &rpc_client,
-            &mut chain,
-            &solo_machine_channel_id,
-            &tendermint_channel_id,
-            memo,
-        )
-        .await?;
+            handshake.solo_machine_connection_id = Some(solo_machine_connection_id.clone());
+            confirm_handshake_step(
+                transaction,
+                &mut handshake,
+                HandshakeStep::InitializedConnectionOnTendermint,
+            )
+            .await?;
 
-        notify_event(
-            &self.notifier,
-            Event::ConfirmedChannelOnTendermint {
-                channel_id: solo_machine_channel_id.clone(),
-            },
+            notify_event(
+                &self.notifier,
+                Event::InitializedConnectionOnTendermint {
+                    connection_id: solo_machine_connection_id,
+                },
+            )?;
+        }
+
+        let solo_machine_connection_id = get_handshake_id(
+            &handshake.solo_machine_connection_id,
+            "solo machine connection id",
         )?;
 
-        channel_open_confirm(
-            &mut transaction,
-            &chain.config.port_id,
-            &tendermint_channel_id,
-        )
-        .await?;
+        if !handshake.has_completed(HandshakeStep::InitializedConnectionOnSoloMachine) {
+            let mut transaction = self
+                .db_pool
+                .begin()
+                .await
+                .context("unable to begin database transaction")?;
 
-        notify_event(
-            &self.notifier,
-            Event::ConfirmedChannelOnSoloMachine {
-                channel_id: tendermint_channel_id.clone(),
-            },
-        )?;
+            let tendermint_connection_id = connection_open_try(
+                &mut transaction,
+                &tendermint_client_id,
+                &solo_machine_client_id,
+                &solo_machine_connection_id,
+            )
+            .await?;
 
-        let connection_details = ChainConnectionDetails {
-            solo_machine_client_id,
-            tendermint_client_id,
-            solo_machine_connection_id,
-            tendermint_connection_id,
-            solo_machine_channel_id,
-            tendermint_channel_id,
-        };
+            handshake.tendermint_connection_id = Some(tendermint_connection_id.clone());
+            confirm_handshake_step(
+                transaction,
+                &mut handshake,
+                HandshakeStep::InitializedConnectionOnSoloMachine,
+            )
+            .await?;
 
-        chain::add_connection_details(&mut transaction, &chain.id, &connection_details).await?;
+            notify_event(
+                &self.notifier,
+                Event::InitializedConnectionOnSoloMachine {
+                    connection_id: tendermint_connection_id,
+                },
+            )?;
+        }
 
-        notify_event(
-            &self.notifier,
-            Event::ConnectionEstablished {
-                chain_id,
-                connection_details,
-            },
+        let tendermint_connection_id = get_handshake_id(
+            &handshake.tendermint_connection_id,
+            "tendermint connection id",
         )?;
 
-        transaction
-            .commit()
-            .await
-            .context("unable to commit transaction for creating ibc connection")
-    }
+        if !handshake.has_completed(HandshakeStep::ConfirmedConnectionOnTendermint) {
+            let mut transaction = self
+                .db_pool
+                .begin()
+                .await
+                .context("unable to begin database transaction")?;
 
-    /// Mint some tokens on IBC enabled chain
-    #[allow(clippy::too_many_arguments)]
-    pub async fn mint(
-        &self,
-        signer: impl Signer,
-        chain_id: ChainId,
-        request_id: Option<String>,
-        amount: u64,
-        denom: Identifier,
-        receiver: Option<String>,
-        memo: String,
-    ) -> Result<String> {
-        let mut chain = chain::get_chain(&self.db_pool, &chain_id)
-            .await?
-            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
+            if let Err(e) = connection_open_ack(
+                &mut transaction,
+                &signer,
+                self.fee_payer.as_ref(),
+                &rpc_client,
+                &chain,
+                &solo_machine_client_id,
+                &solo_machine_connection_id,
+                &tendermint_client_id,
+                &tendermint_connection_id,
+                memo.clone(),
+            )
+            .await
+            {
+                return Err(retain_on_unknown_outcome(
+                    transaction,
+                    e,
+                    &chain.id,
+                    None,
+                    PendingOperation::Other {
+                        description: "connection open ack".to_owned(),
+                    },
+                )
+                .await);
+            }
 
-        let address = signer.to_account_address()?;
-        let receiver = receiver.unwrap_or_else(|| address.clone());
+            confirm_handshake_step(
+                transaction,
+                &mut handshake,
+                HandshakeStep::ConfirmedConnectionOnTendermint,
+            )
+            .await?;
 
-        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
-            .context("unable to connect to rpc client")?;
+            notify_event(
+                &self.notifier,
+                Event::ConfirmedConnectionOnTendermint {
+                    connection_id: solo_machine_connection_id.clone(),
+                },
+            )?;
+        }
 
-        let mut transaction = self
-            .db_pool
-            .begin()
-            .await
-            .context("unable to begin database transaction")?;
+        if !handshake.has_completed(HandshakeStep::ConfirmedConnectionOnSoloMachine) {
+            let mut transaction = self
+                .db_pool
+                .begin()
+                .await
+                .context("unable to begin database transaction")?;
 
-        let msg = transaction_builder::msg_token_send(
-            &mut transaction,
-            signer,
-            &rpc_client,
-            &mut chain,
-            amount,
-            &denom,
-            receiver.clone(),
-            memo,
-            request_id.as_deref(),
-        )
-        .await?;
+            connection_open_confirm(&mut transaction, &tendermint_connection_id).await?;
 
-        let response = rpc_client
-            .broadcast_tx_commit(proto_encode(&msg)?.into())
+            confirm_handshake_step(
+                transaction,
+                &mut handshake,
+                HandshakeStep::ConfirmedConnectionOnSoloMachine,
+            )
             .await?;
 
-        let transaction_hash = ensure_response_success(&response)?;
+            notify_event(
+                &self.notifier,
+                Event::ConfirmedConnectionOnSoloMachine {
+                    connection_id: tendermint_connection_id.clone(),
+                },
+            )?;
+        }
 
-        transaction
-            .commit()
-            .await
-            .context("unable to commit transaction for sending tokens over IBC")?;
+        if !handshake.has_completed(HandshakeStep::InitializedChannelOnTendermint) {
+            let solo_machine_channel_id = channel_open_init(
+                &signer,
+                self.fee_payer.as_ref(),
+                &rpc_client,
+                &chain,
+                &chain.config.port_id,
+                ChannelOrdering::Unordered,
+                ICS20_VERSION,
+                &solo_machine_connection_id,
+                memo.clone(),
+            )
+            .await?;
 
-        let success: bool = extract_attribute(
-            &response.deliver_tx.events,
-            "fungible_token_packet",
-            "success",
-        )?
-        .parse()?;
+            let transaction = self
+                .db_pool
+                .begin()
+                .await
+                .context("unable to begin database transaction")?;
 
-        if success {
-            operation::add_operation(
-                &self.db_pool,
-                request_id.as_deref(),
-                &receiver,
-                &denom,
-                amount,
-                &OperationType::Mint {
-                    chain_id: chain_id.clone(),
-                },
-                &transaction_hash,
+            handshake.solo_machine_channel_id = Some(solo_machine_channel_id.clone());
+            confirm_handshake_step(
+                transaction,
+                &mut handshake,
+                HandshakeStep::InitializedChannelOnTendermint,
             )
             .await?;
 
             notify_event(
                 &self.notifier,
-                Event::TokensMinted {
-                    chain_id,
-                    request_id,
-                    to_address: receiver,
-                    amount,
-                    denom,
-                    transaction_hash: transaction_hash.clone(),
+                Event::InitializedChannelOnTendermint {
+                    channel_id: solo_machine_channel_id,
                 },
             )?;
+        }
 
-            Ok(transaction_hash)
-        } else {
-            let error = extract_attribute(
-                &response.deliver_tx.events,
-                "write_acknowledgement",
-                "packet_ack",
+        let solo_machine_channel_id = get_handshake_id(
+            &handshake.solo_machine_channel_id,
+            "solo machine channel id",
+        )?;
+
+        if !handshake.has_completed(HandshakeStep::InitializedChannelOnSoloMachine) {
+            let mut transaction = self
+                .db_pool
+                .begin()
+                .await
+                .context("unable to begin database transaction")?;
+
+            let tendermint_channel_id = channel_open_try(
+                &mut transaction,
+                &chain.config.port_id,
+                ChannelOrdering::Unordered,
+                ICS20_VERSION,
+                &solo_machine_channel_id,
+                &tendermint_connection_id,
+            )
+            .await?;
+
+            handshake.tendermint_channel_id = Some(tendermint_channel_id.clone());
+            confirm_handshake_step(
+                transaction,
+                &mut handshake,
+                HandshakeStep::InitializedChannelOnSoloMachine,
+            )
+            .await?;
+
+            notify_event(
+                &self.notifier,
+                Event::InitializedChannelOnSoloMachine {
+                    channel_id: tendermint_channel_id,
+                },
             )?;
+        }
 
-            Err(anyhow!(
-                "Failed to mint tokens on IBC enabled chain: {}",
-                error
-            ))
+        let tendermint_channel_id =
+            get_handshake_id(&handshake.tendermint_channel_id, "tendermint channel id")?;
+
+        if !handshake.has_completed(HandshakeStep::ConfirmedChannelOnTendermint) {
+            let mut transaction = self
+                .db_pool
+                .begin()
+                .await
+                .context("unable to begin database transaction")?;
+
+            let port_id = chain.config.port_id.clone();
+
+            if let Err(e) = channel_open_ack(
+                &mut transaction,
+                &signer,
+                self.fee_payer.as_ref(),
+                &rpc_client,
+                &chain,
+                &solo_machine_client_id,
+                &port_id,
+                ICS20_VERSION,
+                &solo_machine_channel_id,
+                &tendermint_channel_id,
+                memo,
+            )
+            .await
+            {
+                return Err(retain_on_unknown_outcome(
+                    transaction,
+                    e,
+                    &chain.id,
+                    None,
+                    PendingOperation::Other {
+                        description: "channel open ack".to_owned(),
+                    },
+                )
+                .await);
+            }
+
+            confirm_handshake_step(
+                transaction,
+                &mut handshake,
+                HandshakeStep::ConfirmedChannelOnTendermint,
+            )
+            .await?;
+
+            notify_event(
+                &self.notifier,
+                Event::ConfirmedChannelOnTendermint {
+                    channel_id: solo_machine_channel_id.clone(),
+                },
+            )?;
         }
+
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("unable to begin database transaction")?;
+
+        channel_open_confirm(
+            &mut transaction,
+            &chain.config.port_id,
+            &tendermint_channel_id,
+        )
+        .await?;
+
+        let connection_details = ChainConnectionDetails {
+            port_id: chain.config.port_id.clone(),
+            solo_machine_client_id,
+            tendermint_client_id,
+            solo_machine_connection_id,
+            tendermint_connection_id,
+            solo_machine_channel_id,
+            tendermint_channel_id: tendermint_channel_id.clone(),
+        };
+
+        chain::add_connection_details(&mut transaction, &chain.id, &connection_details).await?;
+        connection::add_connection(&mut transaction, &chain.id, &connection_details).await?;
+
+        confirm_handshake_step(
+            transaction,
+            &mut handshake,
+            HandshakeStep::ConfirmedChannelOnSoloMachine,
+        )
+        .await?;
+
+        notify_event(
+            &self.notifier,
+            Event::ConfirmedChannelOnSoloMachine {
+                channel_id: tendermint_channel_id,
+            },
+        )?;
+
+        notify_event(
+            &self.notifier,
+            Event::ConnectionEstablished {
+                chain_id,
+                connection_details,
+            },
+        )
     }
 
-    /// Burn some tokens on IBC enabled chain
+    /// Opens a new IBC channel with given port, ordering and version on the existing (default) connection with an IBC
+    /// enabled chain (i.e., only executes channel handshake and reuses existing clients and connection)
     #[allow(clippy::too_many_arguments)]
-    pub async fn burn(
+    pub async fn open_channel(
         &self,
         signer: impl Signer,
         chain_id: ChainId,
-        request_id: Option<String>,
-        amount: u64,
-        denom: Identifier,
+        port_id: PortId,
+        ordering: ChannelOrdering,
+        version: String,
         memo: String,
-    ) -> Result<String> {
-        let mut chain = chain::get_chain(&self.db_pool, &chain_id)
+    ) -> Result<ChainConnectionDetails> {
+        let chain = chain::get_chain(&self.db_pool, &chain_id)
             .await?
             .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
 
+        let connection_details = chain.connection_details.clone().ok_or_else(|| {
+            anyhow!(
+                "connection is not established with chain with id {}",
+                chain_id
+            )
+        })?;
+
         let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
             .context("unable to connect to rpc client")?;
 
-        // TODO: Remove this code once fully tested that updating solo machine client is not needed
-        //
-        // let mut transaction = self
-        //     .db_pool
-        //     .begin()
-        //     .await
-        //     .context("unable to begin database transaction")?;
-
-        // let msg = transaction_builder::msg_update_solo_machine_client(
-        //     &mut transaction,
-        //     &signer,
-        //     &mut chain,
-        //     None,
-        //     memo.clone(),
-        // )
-        // .await?;
-
-        // let response = rpc_client
-        //     .broadcast_tx_commit(proto_encode(&msg)?.into())
-        //     .await?;
+        let solo_machine_channel_id = channel_open_init(
+            &signer,
+            self.fee_payer.as_ref(),
+            &rpc_client,
+            &chain,
+            &port_id,
+            ordering,
+            &version,
+            &connection_details.solo_machine_connection_id,
+            memo.clone(),
+        )
+        .await?;
 
-        // ensure_response_success(&response)?;
+        notify_event(
+            &self.notifier,
+            Event::InitializedChannelOnTendermint {
+                channel_id: solo_machine_channel_id.clone(),
+            },
+        )?;
 
-        // transaction
-        //     .commit()
-        //     .await
-        //     .context("unable to commit transaction for receiving tokens over IBC")?;
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("unable to begin database transaction")?;
 
-        let address = signer.to_account_address()?;
+        let tendermint_channel_id = channel_open_try(
+            &mut transaction,
+            &port_id,
+            ordering,
+            &version,
+            &solo_machine_channel_id,
+            &connection_details.tendermint_connection_id,
+        )
+        .await?;
 
-        let msg = transaction_builder::msg_token_receive(
+        if let Err(e) = channel_open_ack(
+            &mut transaction,
             &signer,
+            self.fee_payer.as_ref(),
+            &rpc_client,
             &chain,
-            amount,
-            &denom,
-            address.clone(),
-            memo.clone(),
-            request_id.as_deref(),
+            &connection_details.solo_machine_client_id,
+            &port_id,
+            &version,
+            &solo_machine_channel_id,
+            &tendermint_channel_id,
+            memo,
         )
-        .await?;
+        .await
+        {
+            return Err(retain_on_unknown_outcome(
+                transaction,
+                e,
+                &chain.id,
+                None,
+                PendingOperation::Other {
+                    description: "channel open ack".to_owned(),
+                },
+            )
+            .await);
+        }
 
-        let response = rpc_client
-            .broadcast_tx_commit(proto_encode(&msg)?.into())
-            .await?;
+        channel_open_confirm(&mut transaction, &port_id, &tendermint_channel_id).await?;
 
-        let transaction_hash = ensure_response_success(&response)?;
+        let new_connection_details = ChainConnectionDetails {
+            port_id,
+            solo_machine_channel_id,
+            tendermint_channel_id,
+            ..connection_details
+        };
 
-        operation::add_operation(
-            &self.db_pool,
-            request_id.as_deref(),
-            &address,
-            &denom,
-            amount,
-            &OperationType::Burn {
-                chain_id: chain_id.clone(),
+        connection::add_connection(&mut transaction, &chain_id, &new_connection_details).await?;
+
+        transaction
+            .commit()
+            .await
+            .context("unable to commit transaction for opening channel")?;
+
+        notify_event(
+            &self.notifier,
+            Event::ChannelOpened {
+                chain_id,
+                connection_details: new_connection_details.clone(),
             },
-            &transaction_hash,
-        )
-        .await?;
+        )?;
+
+        Ok(new_connection_details)
+    }
+
+    /// Accepts an IBC connection initialized by counterparty (e.g., using a relayer) on IBC enabled chain with solo
+    /// machine client (i.e., executes `ConnOpenTry` and `ConnOpenConfirm` on solo machine and `ConnOpenAck` on IBC
+    /// enabled chain) and returns the connection ID of IBC enabled chain on solo machine. Connection and client state of
+    /// solo machine client (which must have signer's public key and chain's diversifier) are verified using ICS-23
+    /// proofs against a freshly updated tendermint client before signing anything.
+    pub async fn accept_connection(
+        &self,
+        signer: impl Signer,
+        chain_id: ChainId,
+        solo_machine_connection_id: ConnectionId,
+        memo: String,
+    ) -> Result<ConnectionId> {
+        let chain = chain::get_chain(&self.db_pool, &chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
+
+        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
+            .context("unable to connect to rpc client")?;
+
+        // Tendermint client (on solo machine) used for verifying the connection is looked up from unverified
+        // connection first
+        let tendermint_client_id = get_counterparty_client_id(
+            query_ibc_store(
+                &rpc_client,
+                ConnectionPath::new(&solo_machine_connection_id).into_bytes(),
+            )
+            .await?
+            .ok_or_else(|| {
+                anyhow!(
+                    "connection with id {} not found on chain with id {}",
+                    solo_machine_connection_id,
+                    chain_id
+                )
+            })?,
+        )?;
+
+        ensure!(
+            ibc_handler::get_tendermint_client_state(&self.db_pool, &tendermint_client_id)
+                .await?
+                .is_some(),
+            "tendermint client with id {} not found on solo machine",
+            tendermint_client_id
+        );
+
+        let proof_height = self
+            .update_tendermint_client_with_id(&chain, tendermint_client_id.clone())
+            .await?;
+
+        let connection: ConnectionEnd = query_proven_ibc_store(
+            &self.db_pool,
+            &rpc_client,
+            &tendermint_client_id,
+            ConnectionPath::new(&solo_machine_connection_id).into_bytes(),
+            &proof_height,
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "unable to verify connection with id {} on chain with id {}",
+                solo_machine_connection_id, chain_id
+            )
+        })?;
+
+        ensure!(
+            connection.state() == ConnectionState::Init,
+            "connection with id {} on chain with id {} is not in INIT state",
+            solo_machine_connection_id,
+            chain_id
+        );
+
+        let solo_machine_client_id: ClientId = connection.client_id.parse()?;
+
+        ensure!(
+            get_counterparty_client_id(connection)? == tendermint_client_id,
+            "counterparty client of connection with id {} changed while accepting it",
+            solo_machine_connection_id
+        );
+
+        let (sequence, consensus_timestamp) = verify_solo_machine_client(
+            &self.db_pool,
+            &rpc_client,
+            &chain,
+            &signer,
+            &solo_machine_client_id,
+            &tendermint_client_id,
+            &proof_height,
+        )
+        .await?;
+
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("unable to begin database transaction")?;
+
+        // Sequence and consensus timestamp of a solo machine client already known to solo machine are retained (they
+        // may be ahead of the proven ones if the last transaction which used the client is yet to be included in a
+        // block)
+        solo_machine_client::add_solo_machine_client(
+            &mut transaction,
+            &chain_id,
+            &solo_machine_client_id,
+            sequence,
+            consensus_timestamp,
+        )
+        .await?;
+
+        let tendermint_connection_id = connection_open_try(
+            &mut transaction,
+            &tendermint_client_id,
+            &solo_machine_client_id,
+            &solo_machine_connection_id,
+        )
+        .await?;
+
+        if let Err(e) = connection_open_ack(
+            &mut transaction,
+            &signer,
+            self.fee_payer.as_ref(),
+            &rpc_client,
+            &chain,
+            &solo_machine_client_id,
+            &solo_machine_connection_id,
+            &tendermint_client_id,
+            &tendermint_connection_id,
+            memo,
+        )
+        .await
+        {
+            return Err(retain_on_unknown_outcome(
+                transaction,
+                e,
+                &chain.id,
+                None,
+                PendingOperation::Other {
+                    description: "connection open ack".to_owned(),
+                },
+            )
+            .await);
+        }
+
+        connection_open_confirm(&mut transaction, &tendermint_connection_id).await?;
+
+        transaction
+            .commit()
+            .await
+            .context("unable to commit transaction for accepting connection")?;
+
+        notify_event(
+            &self.notifier,
+            Event::ConnectionAccepted {
+                chain_id,
+                solo_machine_connection_id,
+                tendermint_connection_id: tendermint_connection_id.clone(),
+            },
+        )?;
+
+        Ok(tendermint_connection_id)
+    }
+
+    /// Accepts an IBC channel initialized by counterparty (e.g., using a relayer) on IBC enabled chain on an open
+    /// connection with solo machine client (i.e., executes `ChanOpenTry` and `ChanOpenConfirm` on solo machine and
+    /// `ChanOpenAck` on IBC enabled chain). The channel becomes the default channel of chain if chain does not have
+    /// one. Channel, its connection and client state of solo machine client are verified (same as
+    /// [`accept_connection`](Self::accept_connection)) before signing anything.
+    pub async fn accept_channel(
+        &self,
+        signer: impl Signer,
+        chain_id: ChainId,
+        port_id: PortId,
+        solo_machine_channel_id: ChannelId,
+        memo: String,
+    ) -> Result<ChainConnectionDetails> {
+        let chain = chain::get_chain(&self.db_pool, &chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
+
+        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
+            .context("unable to connect to rpc client")?;
+
+        // Tendermint client (on solo machine) used for verifying the channel and its connection is looked up from
+        // unverified channel and connection first
+        let unverified_channel: Channel = query_ibc_store(
+            &rpc_client,
+            ChannelPath::new(&port_id, &solo_machine_channel_id).into_bytes(),
+        )
+        .await?
+        .ok_or_else(|| {
+            anyhow!(
+                "channel with port id {} and channel id {} not found on chain with id {}",
+                port_id,
+                solo_machine_channel_id,
+                chain_id
+            )
+        })?;
+        let solo_machine_connection_id = get_connection_hop(&unverified_channel)?;
+        let tendermint_client_id = get_counterparty_client_id(
+            query_ibc_store(
+                &rpc_client,
+                ConnectionPath::new(&solo_machine_connection_id).into_bytes(),
+            )
+            .await?
+            .ok_or_else(|| {
+                anyhow!(
+                    "connection with id {} not found on chain with id {}",
+                    solo_machine_connection_id,
+                    chain_id
+                )
+            })?,
+        )?;
+
+        ensure!(
+            ibc_handler::get_tendermint_client_state(&self.db_pool, &tendermint_client_id)
+                .await?
+                .is_some(),
+            "tendermint client with id {} not found on solo machine",
+            tendermint_client_id
+        );
+
+        let proof_height = self
+            .update_tendermint_client_with_id(&chain, tendermint_client_id.clone())
+            .await?;
+
+        let channel: Channel = query_proven_ibc_store(
+            &self.db_pool,
+            &rpc_client,
+            &tendermint_client_id,
+            ChannelPath::new(&port_id, &solo_machine_channel_id).into_bytes(),
+            &proof_height,
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "unable to verify channel with port id {} and channel id {} on chain with id {}",
+                port_id, solo_machine_channel_id, chain_id
+            )
+        })?;
+
+        ensure!(
+            channel.state() == ChannelState::Init,
+            "channel with port id {} and channel id {} on chain with id {} is not in INIT state",
+            port_id,
+            solo_machine_channel_id,
+            chain_id
+        );
+        ensure!(
+            channel
+                .counterparty
+                .as_ref()
+                .map(|counterparty| &counterparty.port_id)
+                == Some(&port_id.to_string()),
+            "counterparty port id of channel should be {}",
+            port_id
+        );
+        ensure!(
+            get_connection_hop(&channel)? == solo_machine_connection_id,
+            "connection of channel with port id {} and channel id {} changed while accepting it",
+            port_id,
+            solo_machine_channel_id
+        );
+
+        let ordering = ChannelOrdering::try_from(channel.ordering())?;
+
+        let connection: ConnectionEnd = query_proven_ibc_store(
+            &self.db_pool,
+            &rpc_client,
+            &tendermint_client_id,
+            ConnectionPath::new(&solo_machine_connection_id).into_bytes(),
+            &proof_height,
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "unable to verify connection with id {} on chain with id {}",
+                solo_machine_connection_id, chain_id
+            )
+        })?;
+
+        ensure!(
+            connection.state() == ConnectionState::Open,
+            "connection with id {} on chain with id {} is not open",
+            solo_machine_connection_id,
+            chain_id
+        );
+
+        let solo_machine_client_id: ClientId = connection.client_id.parse()?;
+        let counterparty = connection
+            .counterparty
+            .ok_or_else(|| anyhow!("counterparty cannot be absent in connection"))?;
+        let tendermint_connection_id: ConnectionId = counterparty.connection_id.parse()?;
+
+        ensure!(
+            counterparty.client_id == tendermint_client_id.to_string(),
+            "counterparty client of connection with id {} changed while accepting channel",
+            solo_machine_connection_id
+        );
+
+        let (sequence, consensus_timestamp) = verify_solo_machine_client(
+            &self.db_pool,
+            &rpc_client,
+            &chain,
+            &signer,
+            &solo_machine_client_id,
+            &tendermint_client_id,
+            &proof_height,
+        )
+        .await?;
+
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("unable to begin database transaction")?;
+
+        ensure!(
+            ibc_handler::get_connection(&mut transaction, &tendermint_connection_id)
+                .await?
+                .map(|connection| connection.state() == ConnectionState::Open)
+                .unwrap_or(false),
+            "connection with id {} is not open on solo machine",
+            tendermint_connection_id
+        );
+
+        // Sequence and consensus timestamp of a solo machine client already known to solo machine are retained (they
+        // may be ahead of the proven ones if the last transaction which used the client is yet to be included in a
+        // block)
+        solo_machine_client::add_solo_machine_client(
+            &mut transaction,
+            &chain_id,
+            &solo_machine_client_id,
+            sequence,
+            consensus_timestamp,
+        )
+        .await?;
+
+        let tendermint_channel_id = channel_open_try(
+            &mut transaction,
+            &port_id,
+            ordering,
+            &channel.version,
+            &solo_machine_channel_id,
+            &tendermint_connection_id,
+        )
+        .await?;
+
+        if let Err(e) = channel_open_ack(
+            &mut transaction,
+            &signer,
+            self.fee_payer.as_ref(),
+            &rpc_client,
+            &chain,
+            &solo_machine_client_id,
+            &port_id,
+            &channel.version,
+            &solo_machine_channel_id,
+            &tendermint_channel_id,
+            memo,
+        )
+        .await
+        {
+            return Err(retain_on_unknown_outcome(
+                transaction,
+                e,
+                &chain.id,
+                None,
+                PendingOperation::Other {
+                    description: "channel open ack".to_owned(),
+                },
+            )
+            .await);
+        }
+
+        channel_open_confirm(&mut transaction, &port_id, &tendermint_channel_id).await?;
+
+        let connection_details = ChainConnectionDetails {
+            port_id,
+            solo_machine_client_id,
+            tendermint_client_id,
+            solo_machine_connection_id,
+            tendermint_connection_id,
+            solo_machine_channel_id,
+            tendermint_channel_id,
+        };
+
+        connection::add_connection(&mut transaction, &chain_id, &connection_details).await?;
+
+        if chain.connection_details.is_none() {
+            chain::add_connection_details(&mut transaction, &chain_id, &connection_details).await?;
+        }
+
+        transaction
+            .commit()
+            .await
+            .context("unable to commit transaction for accepting channel")?;
+
+        notify_event(
+            &self.notifier,
+            Event::ChannelOpened {
+                chain_id,
+                connection_details: connection_details.clone(),
+            },
+        )?;
+
+        Ok(connection_details)
+    }
+
+    /// Mint some tokens on IBC enabled chain over given channel (uses default channel of chain if channel is not
+    /// provided). `packet_memo` is included in the packet data (e.g., for forwarding tokens to another chain, see
+    /// [`ForwardMetadata`](crate::ibc::apps::transfer::forward::ForwardMetadata)) and is different from `memo` of
+    /// transactions.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn mint(
+        &self,
+        signer: impl Signer,
+        chain_id: ChainId,
+        channel_id: Option<ChannelId>,
+        request_id: Option<String>,
+        amount: Amount,
+        denom: Denom,
+        receiver: Option<String>,
+        packet_memo: Option<String>,
+        memo: String,
+    ) -> Result<String> {
+        let chain = chain::get_chain(&self.db_pool, &chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
+
+        let connection_details =
+            connection::select_connection(&self.db_pool, &chain, channel_id.as_ref()).await?;
+
+        let address = signer.to_account_address()?;
+        let receiver = receiver.unwrap_or_else(|| address.clone());
+
+        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
+            .context("unable to connect to rpc client")?;
+
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("unable to begin database transaction")?;
+
+        let msg = transaction_builder::msg_token_send(
+            &mut transaction,
+            signer,
+            self.fee_payer.as_ref(),
+            &rpc_client,
+            &chain,
+            &connection_details,
+            &amount,
+            &denom,
+            receiver.clone(),
+            packet_memo,
+            memo,
+            request_id.as_deref(),
+        )
+        .await?;
+
+        let response = match broadcast_tx(&rpc_client, chain.config.broadcast_mode, &msg).await {
+            Ok(response) => response,
+            Err(e) => {
+                return Err(retain_on_unknown_outcome(
+                    transaction,
+                    e,
+                    &chain.id,
+                    request_id.as_deref(),
+                    PendingOperation::Mint {
+                        channel_id: connection_details.solo_machine_channel_id.clone(),
+                        receiver: receiver.clone(),
+                        amount: amount.clone(),
+                        denom: denom.clone(),
+                    },
+                )
+                .await)
+            }
+        };
+
+        self.process_mint_response(
+            &rpc_client,
+            transaction,
+            response,
+            &chain,
+            &connection_details,
+            request_id,
+            receiver,
+            amount,
+            denom,
+        )
+        .await
+    }
+
+    /// Generates an unsigned mint (i.e., generate-only mode) over given channel (uses default channel of chain if
+    /// channel is not provided). The returned sign bytes must be signed offline, then the sign doc of transaction is
+    /// generated using [`prepare_mint_transaction`](Self::prepare_mint_transaction) and the mint is submitted using
+    /// [`submit_mint`](Self::submit_mint) once the sign doc is also signed offline. `signer` is only used for computing
+    /// the address of sender.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn prepare_mint(
+        &self,
+        signer: impl ToPublicKey,
+        chain_id: ChainId,
+        channel_id: Option<ChannelId>,
+        request_id: Option<String>,
+        amount: Amount,
+        denom: Denom,
+        receiver: Option<String>,
+        packet_memo: Option<String>,
+    ) -> Result<UnsignedMint> {
+        self.ensure_generate_only_supported(&signer)?;
+
+        let chain = chain::get_chain(&self.db_pool, &chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
+
+        let connection_details =
+            connection::select_connection(&self.db_pool, &chain, channel_id.as_ref()).await?;
+
+        let sender = signer.to_account_address()?;
+        let receiver = receiver.unwrap_or_else(|| sender.clone());
+
+        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
+            .context("unable to connect to rpc client")?;
+
+        let packet = transaction_builder::token_send_packet(
+            &self.db_pool,
+            &rpc_client,
+            &chain,
+            &connection_details,
+            &amount,
+            &denom,
+            sender.clone(),
+            receiver.clone(),
+            packet_memo,
+        )
+        .await?;
+
+        let client = transaction_builder::get_solo_machine_client(
+            &self.db_pool,
+            &chain,
+            &connection_details.solo_machine_client_id,
+        )
+        .await?;
+
+        let sign_bytes =
+            transaction_builder::packet_commitment_sign_bytes(&chain, &client, &packet)?;
+
+        Ok(UnsignedMint {
+            chain_id,
+            request_id,
+            sender,
+            receiver,
+            amount,
+            denom,
+            packet: proto_encode(&packet)?,
+            sign_bytes,
+            sign_doc: None,
+        })
+    }
+
+    /// Adds sign doc of transaction sent to IBC enabled chain to a mint generated using
+    /// [`prepare_mint`](Self::prepare_mint) after its sign bytes are signed offline. The sign doc contains the packet
+    /// commitment proof (i.e., signature over sign bytes) and must be signed offline by `signer` (which also pays the
+    /// fees). Nothing is signed or changed on solo machine.
+    pub async fn prepare_mint_transaction(
+        &self,
+        signer: impl ToPublicKey,
+        mut unsigned_mint: UnsignedMint,
+        memo: String,
+    ) -> Result<UnsignedMint> {
+        self.ensure_generate_only_supported(&signer)?;
+
+        let signature = sign_bytes_signature(&unsigned_mint)?;
+
+        let chain = chain::get_chain(&self.db_pool, &unsigned_mint.chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", unsigned_mint.chain_id))?;
+
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("unable to begin database transaction")?;
+
+        let (packet, _, client) =
+            verify_unsigned_mint(&mut transaction, &chain, &unsigned_mint).await?;
+
+        let proof_commitment =
+            transaction_builder::timestamped_signature(&chain, &client, signature)?;
+
+        let sign_doc = transaction_builder::msg_recv_packet_sign_doc(
+            signer,
+            &chain,
+            &client,
+            packet,
+            proof_commitment,
+            memo,
+        )
+        .await?;
+
+        unsigned_mint.sign_doc = Some(sign_doc);
+
+        Ok(unsigned_mint)
+    }
+
+    /// Submits a mint generated using [`prepare_mint`](Self::prepare_mint) and
+    /// [`prepare_mint_transaction`](Self::prepare_mint_transaction) after its sign bytes and sign doc are signed
+    /// offline (the transaction is assembled from the signed sign doc, so, nothing is signed online). Fails if state of
+    /// solo machine has changed after generating the mint (i.e., it must be generated again).
+    pub async fn submit_mint(&self, unsigned_mint: UnsignedMint) -> Result<String> {
+        let signature = sign_bytes_signature(&unsigned_mint)?;
+
+        let sign_doc = unsigned_mint.sign_doc.as_ref().ok_or_else(|| {
+            anyhow!("sign doc of transaction is missing, prepare the transaction of mint first")
+        })?;
+        let sign_doc_signature = hex::decode(
+            sign_doc
+                .signature
+                .as_deref()
+                .ok_or_else(|| anyhow!("signature over sign doc is missing"))?,
+        )
+        .context("unable to decode hex bytes of signature over sign doc")?;
+
+        let chain = chain::get_chain(&self.db_pool, &unsigned_mint.chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", unsigned_mint.chain_id))?;
+
+        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
+            .context("unable to connect to rpc client")?;
+
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("unable to begin database transaction")?;
+
+        let (packet, connection_details, client) =
+            verify_unsigned_mint(&mut transaction, &chain, &unsigned_mint).await?;
+
+        // Sign bytes are already signed offline, so, they're only reserved in sign-ledger to refuse signing
+        // conflicting sign bytes for the same sequence
+        guard::reserve_sign_bytes(
+            &mut transaction,
+            &chain,
+            &client.client_id,
+            &unsigned_mint.sign_bytes.sign_bytes,
+        )
+        .await?;
+        solo_machine_client::set_consensus_timestamp(
+            &mut transaction,
+            &chain.id,
+            &client.client_id,
+            client.consensus_timestamp,
+        )
+        .await?;
+
+        let proof_commitment =
+            transaction_builder::timestamped_signature(&chain, &client, signature)?;
+
+        let msg = transaction_builder::msg_recv_packet_signed_offline(
+            &mut transaction,
+            &chain,
+            &connection_details,
+            packet,
+            proof_commitment,
+            sign_doc,
+            sign_doc_signature,
+        )
+        .await?;
+
+        let UnsignedMint {
+            request_id,
+            receiver,
+            amount,
+            denom,
+            ..
+        } = unsigned_mint;
+
+        let response = match broadcast_tx(&rpc_client, chain.config.broadcast_mode, &msg).await {
+            Ok(response) => response,
+            Err(e) => {
+                return Err(retain_on_unknown_outcome(
+                    transaction,
+                    e,
+                    &chain.id,
+                    request_id.as_deref(),
+                    PendingOperation::Mint {
+                        channel_id: connection_details.solo_machine_channel_id.clone(),
+                        receiver: receiver.clone(),
+                        amount: amount.clone(),
+                        denom: denom.clone(),
+                    },
+                )
+                .await)
+            }
+        };
+
+        self.process_mint_response(
+            &rpc_client,
+            transaction,
+            response,
+            &chain,
+            &connection_details,
+            request_id,
+            receiver,
+            amount,
+            denom,
+        )
+        .await
+    }
+
+    /// Ensures that mints can be generated for given signer in generate-only mode (both, sign bytes and sign doc are
+    /// signed by a single key offline)
+    fn ensure_generate_only_supported(&self, signer: impl ToPublicKey) -> Result<()> {
+        ensure!(
+            !matches!(signer.to_public_key()?, PublicKey::Multisig(_)),
+            "generate-only mode is not supported for multisig signers"
+        );
+        ensure!(
+            self.fee_payer.is_none(),
+            "generate-only mode is not supported with a separate fee payer"
+        );
+
+        Ok(())
+    }
+
+    /// Processes response of mint transaction (i.e., records the operation and emits events)
+    #[allow(clippy::too_many_arguments)]
+    async fn process_mint_response<C>(
+        &self,
+        rpc_client: &C,
+        transaction: Transaction<'_, Db>,
+        response: TxCommitResponse,
+        chain: &Chain,
+        connection_details: &ChainConnectionDetails,
+        request_id: Option<String>,
+        receiver: String,
+        amount: Amount,
+        denom: Denom,
+    ) -> Result<String>
+    where
+        C: Client + Send + Sync,
+    {
+        let transaction_hash = ensure_response_success(&response)?;
+        let chain_id = chain.id.clone();
+
+        transaction
+            .commit()
+            .await
+            .context("unable to commit transaction for sending tokens over IBC")?;
+
+        let success: bool = extract_attribute(
+            &response.deliver_tx.events,
+            "fungible_token_packet",
+            "success",
+        )?
+        .parse()?;
+
+        if success {
+            operation::add_operation(
+                &self.db_pool,
+                request_id.as_deref(),
+                &receiver,
+                &denom,
+                &amount,
+                &OperationType::Mint {
+                    chain_id: chain_id.clone(),
+                },
+                &transaction_hash,
+            )
+            .await?;
+
+            notify_event(
+                &self.notifier,
+                Event::TokensMinted {
+                    chain_id,
+                    request_id,
+                    to_address: receiver,
+                    amount,
+                    denom,
+                    transaction_hash: transaction_hash.clone(),
+                },
+            )?;
+
+            Ok(transaction_hash)
+        } else {
+            let acknowledgement = extract_attribute(
+                &response.deliver_tx.events,
+                "write_acknowledgement",
+                "packet_ack",
+            )?;
+
+            let packet = extract_packets_from_events(&response.deliver_tx.events, "recv_packet")?
+                .pop()
+                .ok_or_else(|| anyhow!("`recv_packet` event is missing from transaction"))?;
+
+            let proof_height = self
+                .get_tendermint_client_height_after(
+                    rpc_client,
+                    chain,
+                    connection_details,
+                    response.height,
+                )
+                .await?;
+
+            let error = process_error_acknowledgement(
+                &self.db_pool,
+                rpc_client,
+                connection_details,
+                &packet,
+                &acknowledgement,
+                &proof_height,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "unable to process acknowledgement of failed mint (transaction hash: {}): {}",
+                    transaction_hash, acknowledgement
+                )
+            })?;
+
+            operation::add_operation(
+                &self.db_pool,
+                request_id.as_deref(),
+                &receiver,
+                &denom,
+                &amount,
+                &OperationType::MintFailed {
+                    chain_id: chain_id.clone(),
+                    packet_sequence: packet.sequence,
+                    packet_commitment: hex::encode_upper(packet.commitment_bytes()?),
+                    error: error.clone(),
+                },
+                &transaction_hash,
+            )
+            .await?;
+
+            notify_event(
+                &self.notifier,
+                Event::MintFailed {
+                    chain_id,
+                    request_id,
+                    to_address: receiver,
+                    amount,
+                    denom,
+                    error: error.clone(),
+                    transaction_hash: transaction_hash.clone(),
+                },
+            )?;
+
+            Err(anyhow!(
+                "Failed to mint tokens on IBC enabled chain (transaction hash: {}): {}",
+                transaction_hash,
+                error
+            ))
+        }
+    }
+
+    /// Burn some tokens on IBC enabled chain which were sent over given channel (uses default channel of chain if
+    /// channel is not provided)
+    #[allow(clippy::too_many_arguments)]
+    pub async fn burn(
+        &self,
+        signer: impl Signer,
+        chain_id: ChainId,
+        channel_id: Option<ChannelId>,
+        request_id: Option<String>,
+        amount: Amount,
+        denom: Denom,
+        memo: String,
+    ) -> Result<String> {
+        let chain = chain::get_chain(&self.db_pool, &chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
+
+        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
+            .context("unable to connect to rpc client")?;
+
+        // TODO: Remove this code once fully tested that updating solo machine client is not needed
+        //
+        // let mut transaction = self
+        //     .db_pool
+        //     .begin()
+        //     .await
+        //     .context("unable to begin database transaction")?;
+
+        // let msg = transaction_builder::msg_update_solo_machine_client(
+        //     &mut transaction,
+        //     &signer,
+        //     &mut chain,
+        //     None,
+        //     memo.clone(),
+        // )
+        // .await?;
+
+        // let response = rpc_client
+        //     .broadcast_tx_commit(proto_encode(&msg)?.into())
+        //     .await?;
+
+        // ensure_response_success(&response)?;
+
+        // transaction
+        //     .commit()
+        //     .await
+        //     .context("unable to commit transaction for receiving tokens over IBC")?;
+
+        let connection_details =
+            connection::select_connection(&self.db_pool, &chain, channel_id.as_ref()).await?;
+
+        let address = signer.to_account_address()?;
+
+        let _packet_lock = self.packet_lock.lock().await;
+
+        let msg = transaction_builder::msg_token_receive(
+            &self.db_pool,
+            &signer,
+            self.fee_payer.as_ref(),
+            &chain,
+            &connection_details,
+            &amount,
+            &denom,
+            address.clone(),
+            memo.clone(),
+            request_id.as_deref(),
+        )
+        .await?;
+
+        let response = broadcast_tx(&rpc_client, chain.config.broadcast_mode, &msg).await?;
+
+        let transaction_hash = ensure_response_success(&response)?;
+
+        operation::add_operation(
+            &self.db_pool,
+            request_id.as_deref(),
+            &address,
+            &denom,
+            &amount,
+            &OperationType::Burn {
+                chain_id: chain_id.clone(),
+            },
+            &transaction_hash,
+        )
+        .await?;
+
+        notify_event(
+            &self.notifier,
+            Event::TokensBurnt {
+                chain_id,
+                request_id: request_id.clone(),
+                from_address: address,
+                amount,
+                denom,
+                transaction_hash: transaction_hash.clone(),
+            },
+        )?;
+
+        if let Err(e) = self
+            .process_packets(
+                signer,
+                &rpc_client,
+                &chain,
+                &connection_details,
+                extract_packets(&response)?,
+                response.height,
+                memo,
+                request_id,
+            )
+            .await
+        {
+            // Create a warning instead of returning an error because IBC transfer is successful even if processing of
+            // packets (i.e., sending acks) fails
+            notify_event(
+                &self.notifier,
+                Event::Warning {
+                    message: e.to_string(),
+                },
+            )?;
+        }
+
+        Ok(transaction_hash)
+    }
+
+    /// Updates signer for future IBC transactions (on all the solo machine clients used by connections with chain)
+    pub async fn update_signer(
+        &self,
+        signer: impl Signer,
+        chain_id: ChainId,
+        new_public_key: PublicKey,
+        memo: String,
+    ) -> Result<()> {
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("unable to begin database transaction")?;
+
+        let chain = chain::get_chain(&mut transaction, &chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
+
+        // Public key and diversifier of solo machine are shared by all the solo machine clients of chain, so, all of
+        // them are updated in the same transaction
+        let solo_machine_client_ids =
+            connection::get_solo_machine_client_ids(&mut transaction, &chain_id).await?;
+
+        chain_keys::add_chain_key(&mut transaction, &chain_id, &new_public_key.encode()).await?;
+
+        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
+            .context("unable to connect to rpc client")?;
+
+        let msg = transaction_builder::msg_update_solo_machine_client(
+            &mut transaction,
+            &signer,
+            self.fee_payer.as_ref(),
+            &chain,
+            &solo_machine_client_ids,
+            Some(&new_public_key),
+            None,
+            memo.clone(),
+        )
+        .await?;
+
+        let response = match broadcast_tx(&rpc_client, chain.config.broadcast_mode, &msg).await {
+            Ok(response) => response,
+            Err(e) => {
+                return Err(retain_on_unknown_outcome(
+                    transaction,
+                    e,
+                    &chain.id,
+                    None,
+                    PendingOperation::Other {
+                        description: "update signer".to_owned(),
+                    },
+                )
+                .await)
+            }
+        };
+
+        ensure_response_success(&response)?;
+
+        transaction
+            .commit()
+            .await
+            .context("unable to commit transaction for receiving tokens over IBC")?;
+
+        notify_event(
+            &self.notifier,
+            Event::SignerUpdated {
+                chain_id,
+                old_public_key: signer.to_public_key()?,
+                new_public_key,
+            },
+        )
+    }
+
+    /// Updates diversifier of solo machine for future IBC transactions (on all the solo machine clients used by
+    /// connections with chain). Signer's public key is also updated in the same headers if a new public key is
+    /// provided.
+    pub async fn update_diversifier(
+        &self,
+        signer: impl Signer,
+        chain_id: ChainId,
+        new_diversifier: String,
+        new_public_key: Option<PublicKey>,
+        memo: String,
+    ) -> Result<()> {
+        ensure!(
+            !new_diversifier.trim().is_empty(),
+            "new diversifier cannot be blank"
+        );
+
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("unable to begin database transaction")?;
+
+        let chain = chain::get_chain(&mut transaction, &chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
+
+        // Public key and diversifier of solo machine are shared by all the solo machine clients of chain, so, all of
+        // them are updated in the same transaction
+        let solo_machine_client_ids =
+            connection::get_solo_machine_client_ids(&mut transaction, &chain_id).await?;
+
+        let old_diversifier = chain.config.diversifier.clone();
+
+        ensure!(
+            new_diversifier != old_diversifier,
+            "new diversifier is the same as current diversifier of {}: {}",
+            chain_id,
+            old_diversifier
+        );
+
+        if let Some(ref new_public_key) = new_public_key {
+            chain_keys::add_chain_key(&mut transaction, &chain_id, &new_public_key.encode())
+                .await?;
+        }
+
+        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
+            .context("unable to connect to rpc client")?;
+
+        let msg = transaction_builder::msg_update_solo_machine_client(
+            &mut transaction,
+            &signer,
+            self.fee_payer.as_ref(),
+            &chain,
+            &solo_machine_client_ids,
+            new_public_key.as_ref(),
+            Some(&new_diversifier),
+            memo.clone(),
+        )
+        .await?;
+
+        let chain =
+            chain::set_diversifier(&mut transaction, chain, new_diversifier.clone()).await?;
+
+        let response = match broadcast_tx(&rpc_client, chain.config.broadcast_mode, &msg).await {
+            Ok(response) => response,
+            Err(e) => {
+                return Err(retain_on_unknown_outcome(
+                    transaction,
+                    e,
+                    &chain.id,
+                    None,
+                    PendingOperation::Other {
+                        description: "update diversifier".to_owned(),
+                    },
+                )
+                .await)
+            }
+        };
+
+        ensure_response_success(&response)?;
+
+        transaction
+            .commit()
+            .await
+            .context("unable to commit transaction for updating diversifier")?;
+
+        notify_event(
+            &self.notifier,
+            Event::DiversifierUpdated {
+                chain_id: chain_id.clone(),
+                old_diversifier,
+                new_diversifier,
+            },
+        )?;
+
+        match new_public_key {
+            None => Ok(()),
+            Some(new_public_key) => notify_event(
+                &self.notifier,
+                Event::SignerUpdated {
+                    chain_id,
+                    old_public_key: signer.to_public_key()?,
+                    new_public_key,
+                },
+            ),
+        }
+    }
+
+    /// Closes given IBC channel with given chain (closes channel on solo machine and confirms it on IBC enabled chain)
+    /// and removes its connection details. Closes default channel of chain if channel is not provided.
+    pub async fn close_channel(
+        &self,
+        signer: impl Signer,
+        chain_id: ChainId,
+        channel_id: Option<ChannelId>,
+        memo: String,
+    ) -> Result<()> {
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("unable to begin database transaction")?;
+
+        let chain = chain::get_chain(&mut transaction, &chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
+
+        let connection_details =
+            connection::select_connection(&mut transaction, &chain, channel_id.as_ref()).await?;
+
+        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
+            .context("unable to connect to rpc client")?;
+
+        channel_close_init(
+            &mut transaction,
+            &connection_details.port_id,
+            &connection_details.tendermint_channel_id,
+        )
+        .await?;
+
+        if let Err(e) = channel_close_confirm(
+            &mut transaction,
+            &signer,
+            self.fee_payer.as_ref(),
+            &rpc_client,
+            &chain,
+            &connection_details.solo_machine_client_id,
+            &connection_details.port_id,
+            &connection_details.solo_machine_channel_id,
+            &connection_details.tendermint_channel_id,
+            memo,
+        )
+        .await
+        {
+            return Err(retain_on_unknown_outcome(
+                transaction,
+                e,
+                &chain.id,
+                None,
+                PendingOperation::Other {
+                    description: "channel close confirm".to_owned(),
+                },
+            )
+            .await);
+        }
+
+        connection::remove_connection(
+            &mut transaction,
+            &chain_id,
+            &connection_details.solo_machine_channel_id,
+        )
+        .await?;
+
+        let is_default_channel = chain
+            .connection_details
+            .as_ref()
+            .map(|default| &default.solo_machine_channel_id)
+            == Some(&connection_details.solo_machine_channel_id);
+
+        if is_default_channel {
+            // Latest remaining connection becomes the default connection of chain
+            match connection::get_connections(&mut transaction, &chain_id)
+                .await?
+                .pop()
+            {
+                Some(default) => {
+                    chain::add_connection_details(&mut transaction, &chain_id, &default).await?
+                }
+                None => chain::remove_connection_details(&mut transaction, &chain_id).await?,
+            }
+        }
+
+        transaction
+            .commit()
+            .await
+            .context("unable to commit transaction for closing channel")?;
+
+        notify_event(
+            &self.notifier,
+            Event::ChannelClosed {
+                chain_id,
+                solo_machine_channel_id: connection_details.solo_machine_channel_id,
+                tendermint_channel_id: connection_details.tendermint_channel_id,
+            },
+        )
+    }
+
+    /// Updates tendermint client (used by given channel or default channel of chain if channel is not provided) on
+    /// solo machine to latest verified header of IBC enabled chain and returns its new height
+    pub async fn update_tendermint_client(
+        &self,
+        chain_id: ChainId,
+        channel_id: Option<ChannelId>,
+    ) -> Result<Height> {
+        let chain = chain::get_chain(&self.db_pool, &chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
+
+        let tendermint_client_id =
+            connection::select_connection(&self.db_pool, &chain, channel_id.as_ref())
+                .await?
+                .tendermint_client_id;
+
+        self.update_tendermint_client_with_id(&chain, tendermint_client_id)
+            .await
+    }
+
+    /// Updates tendermint client with given ID on solo machine to latest verified header of IBC enabled chain and
+    /// returns its new height
+    async fn update_tendermint_client_with_id(
+        &self,
+        chain: &Chain,
+        tendermint_client_id: ClientId,
+    ) -> Result<Height> {
+        let client_state =
+            ibc_handler::get_tendermint_client_state(&self.db_pool, &tendermint_client_id)
+                .await?
+                .ok_or_else(|| {
+                    anyhow!(
+                        "client state for tendermint client with id {} not found",
+                        tendermint_client_id
+                    )
+                })?;
+
+        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
+            .context("unable to connect to rpc client")?;
+
+        let light_store = DbLightStore::load(&self.db_pool, chain.id.clone()).await?;
+        let mut instance = prepare_light_client(chain, rpc_client, Box::new(light_store.clone()))?;
+
+        let (client_state, consensus_state) =
+            transaction_builder::msg_update_tendermint_client(chain, &mut instance, &client_state)?;
+
+        let latest_height = client_state
+            .latest_height
+            .clone()
+            .ok_or_else(|| anyhow!("latest height cannot be absent in client state"))?;
+
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("unable to begin database transaction")?;
+
+        light_store.flush(&mut transaction).await?;
+        ibc_handler::add_tendermint_consensus_state(
+            &mut transaction,
+            &tendermint_client_id,
+            &latest_height,
+            &consensus_state,
+        )
+        .await?;
+        ibc_handler::update_tendermint_client_state(
+            &mut transaction,
+            &tendermint_client_id,
+            &client_state,
+        )
+        .await?;
+
+        transaction
+            .commit()
+            .await
+            .context("unable to commit transaction for updating tendermint client")?;
+
+        notify_event(
+            &self.notifier,
+            Event::TendermintClientUpdated {
+                chain_id: chain.id.clone(),
+                client_id: tendermint_client_id,
+                latest_height: latest_height.to_string(),
+            },
+        )?;
+
+        Ok(latest_height)
+    }
+
+    /// Queries status of solo machine client (used by given channel or default channel of chain if channel is not
+    /// provided) on IBC enabled chain. Notifies [Event::ClientFrozen] if the client is frozen.
+    pub async fn client_status(
+        &self,
+        chain_id: ChainId,
+        channel_id: Option<ChannelId>,
+    ) -> Result<ClientStatus> {
+        let chain = chain::get_chain(&self.db_pool, &chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
+
+        let client_id = connection::select_connection(&self.db_pool, &chain, channel_id.as_ref())
+            .await?
+            .solo_machine_client_id;
+
+        let status = query_client_status(&chain, client_id).await?;
+
+        if status.frozen {
+            notify_event(
+                &self.notifier,
+                Event::ClientFrozen {
+                    chain_id,
+                    client_id: status.client_id.clone(),
+                    sequence: status.sequence,
+                },
+            )?;
+        }
+
+        Ok(status)
+    }
+
+    /// Creates a substitute solo machine client on IBC enabled chain for recovering frozen solo machine client (used by
+    /// given channel or default channel of chain if channel is not provided) and returns the `ClientUpdateProposal`
+    /// which should be submitted to governance of IBC enabled chain. Substitute client is created with given signer's
+    /// public key (which must be different from the public key of frozen client).
+    pub async fn create_substitute_client(
+        &self,
+        signer: impl Signer,
+        chain_id: ChainId,
+        channel_id: Option<ChannelId>,
+        title: String,
+        description: String,
+        memo: String,
+    ) -> Result<ClientUpdateProposal> {
+        let chain = chain::get_chain(&self.db_pool, &chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
+
+        let subject_client_id =
+            connection::select_connection(&self.db_pool, &chain, channel_id.as_ref())
+                .await?
+                .solo_machine_client_id;
+
+        let status = query_client_status(&chain, subject_client_id).await?;
+
+        ensure!(
+            status.frozen,
+            "solo machine client with id {} is not frozen",
+            status.client_id
+        );
+        ensure!(
+            status.public_key.as_ref().map(PublicKey::encode)
+                != Some(signer.to_public_key()?.encode()),
+            "public key of substitute client must be different from the public key of frozen client"
+        );
+
+        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
+            .context("unable to connect to rpc client")?;
+
+        let substitute_client_id =
+            create_solo_machine_client(&signer, self.fee_payer.as_ref(), &rpc_client, &chain, memo)
+                .await?;
+
+        notify_event(
+            &self.notifier,
+            Event::SubstituteClientCreated {
+                chain_id,
+                subject_client_id: status.client_id.clone(),
+                substitute_client_id: substitute_client_id.clone(),
+            },
+        )?;
+
+        Ok(ClientUpdateProposal {
+            title,
+            description,
+            subject_client_id: status.client_id.to_string(),
+            substitute_client_id: substitute_client_id.to_string(),
+        })
+    }
+
+    /// Re-points local state of solo machine to recovered solo machine client (used by given channel or default
+    /// channel of chain if channel is not provided) after `ClientUpdateProposal` passed on IBC enabled chain. The
+    /// recovered client keeps its client ID (so that connection details remain unchanged) but takes over the sequence
+    /// and public key of substitute client, which are set as the sequence and signer's public key of solo machine.
+    pub async fn recover_client(
+        &self,
+        signer: impl Signer,
+        chain_id: ChainId,
+        channel_id: Option<ChannelId>,
+    ) -> Result<ClientStatus> {
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("unable to begin database transaction")?;
+
+        let chain = chain::get_chain(&mut transaction, &chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
+
+        let client_id =
+            connection::select_connection(&mut transaction, &chain, channel_id.as_ref())
+                .await?
+                .solo_machine_client_id;
+
+        let status = query_client_status(&chain, client_id).await?;
+
+        ensure!(
+            !status.frozen,
+            "solo machine client with id {} is still frozen (client update proposal has not passed yet)",
+            status.client_id
+        );
+
+        let public_key = signer.to_public_key()?;
+
+        ensure!(
+            status.public_key.as_ref().map(PublicKey::encode) == Some(public_key.encode()),
+            "public key of solo machine client with id {} does not match signer's public key",
+            status.client_id
+        );
+
+        solo_machine_client::set_sequence(
+            &mut transaction,
+            &chain_id,
+            &status.client_id,
+            status.sequence,
+        )
+        .await?;
+        chain_keys::add_chain_key(&mut transaction, &chain_id, &public_key.encode()).await?;
+
+        transaction
+            .commit()
+            .await
+            .context("unable to commit transaction for recovering solo machine client")?;
+
+        notify_event(
+            &self.notifier,
+            Event::ClientRecovered {
+                chain_id,
+                client_id: status.client_id.clone(),
+                sequence: status.sequence,
+                public_key,
+            },
+        )?;
+
+        Ok(status)
+    }
+
+    /// Listens for packets sent from IBC enabled chain to solo machine (e.g., token transfers by any account on IBC
+    /// enabled chain) over all the established channels and acknowledges them. IBC enabled chains are polled for new
+    /// `send_packet` events at given interval (starting from the height up to which each channel was already
+    /// searched, which is persisted in database). Failures in processing packets are notified as warnings and retried
+    /// in the next poll.
+    pub async fn listen_packets(
+        &self,
+        signer: impl Signer,
+        memo: String,
+        poll_interval: Duration,
+    ) -> Result<()> {
+        loop {
+            let chains = match chain::get_chains(&self.db_pool).await {
+                Ok(chains) => chains,
+                Err(e) => {
+                    notify_event(
+                        &self.notifier,
+                        Event::Warning {
+                            message: format!("unable to fetch chains: {}", e),
+                        },
+                    )?;
+                    vec![]
+                }
+            };
+
+            for chain in chains {
+                let connections = match connection::get_connections(&self.db_pool, &chain.id).await
+                {
+                    Ok(connections) => connections,
+                    Err(e) => {
+                        notify_event(
+                            &self.notifier,
+                            Event::Warning {
+                                message: format!(
+                                    "unable to fetch connections of chain with id {}: {}",
+                                    chain.id, e
+                                ),
+                            },
+                        )?;
+                        continue;
+                    }
+                };
+
+                for connection_details in connections {
+                    if let Err(e) = self
+                        .receive_packets(&signer, &chain.id, &connection_details, memo.clone())
+                        .await
+                    {
+                        notify_event(
+                            &self.notifier,
+                            Event::Warning {
+                                message: format!(
+                                    "unable to process packets sent over channel {} of chain with id {}: {}",
+                                    connection_details.solo_machine_channel_id, chain.id, e
+                                ),
+                            },
+                        )?;
+                    }
+                }
+            }
+
+            sleep(poll_interval).await;
+        }
+    }
+
+    /// Retries acknowledging pending packets (sent from IBC enabled chain to solo machine) which are due for next
+    /// attempt (only for given chain, if provided). If `force` is `true`, retries all the pending packets (including
+    /// the permanently failed ones) immediately.
+    pub async fn retry_acks(
+        &self,
+        signer: impl Signer,
+        chain_id: Option<ChainId>,
+        force: bool,
+        memo: String,
+    ) -> Result<()> {
+        let _packet_lock = self.packet_lock.lock().await;
+
+        let pending_acks = if force {
+            pending_ack::get_pending_acks(&self.db_pool, true).await?
+        } else {
+            pending_ack::get_due_pending_acks(&self.db_pool, Utc::now()).await?
+        };
+
+        // Packets are grouped by channel and request ID so that they can be processed together
+        let mut groups: HashMap<(ChainId, String, Option<String>), Vec<PendingAck>> =
+            HashMap::new();
+
+        for pending_ack in pending_acks {
+            if chain_id.is_some() && chain_id.as_ref() != Some(&pending_ack.chain_id) {
+                continue;
+            }
+
+            groups
+                .entry((
+                    pending_ack.chain_id.clone(),
+                    pending_ack.packet.source_channel.clone(),
+                    pending_ack.request_id.clone(),
+                ))
+                .or_default()
+                .push(pending_ack);
+        }
+
+        for ((chain_id, channel_id, request_id), pending_acks) in groups {
+            if let Err(e) = self
+                .retry_pending_acks(
+                    &signer,
+                    &chain_id,
+                    &channel_id,
+                    request_id,
+                    pending_acks,
+                    memo.clone(),
+                )
+                .await
+            {
+                notify_event(
+                    &self.notifier,
+                    Event::Warning {
+                        message: format!(
+                            "unable to retry acknowledgements of packets sent over channel {} of chain with id {}: {}",
+                            channel_id, chain_id, e
+                        ),
+                    },
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Retries acknowledging pending packets which are due for next attempt at given interval
+    pub async fn run_ack_retry_worker(
+        &self,
+        signer: impl Signer,
+        memo: String,
+        interval: Duration,
+    ) -> Result<()> {
+        loop {
+            if let Err(e) = self.retry_acks(&signer, None, false, memo.clone()).await {
+                notify_event(
+                    &self.notifier,
+                    Event::Warning {
+                        message: format!("unable to retry pending acknowledgements: {}", e),
+                    },
+                )?;
+            }
+
+            sleep(interval).await;
+        }
+    }
+
+    /// Fetches history of all operations
+    pub async fn history(
+        &self,
+        signer: impl ToPublicKey,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<Operation>> {
+        let account_address = signer.to_account_address()?;
+        operation::get_operations(&self.db_pool, &account_address, limit, offset).await
+    }
+
+    /// Reconciles a transaction whose outcome was unknown when it was broadcasted (see [`UnknownTxOutcome`]). If the
+    /// transaction is included in a block, its operation is finalized (or voided if the transaction failed). Returns
+    /// the pending transaction with its updated status (which remains pending if the transaction is still not included
+    /// in a block).
+    pub async fn reconcile(&self, transaction_hash: &str) -> Result<PendingTransaction> {
+        let hash: TransactionHash = transaction_hash
+            .parse()
+            .map_err(|err| anyhow!("invalid transaction hash {}: {}", transaction_hash, err))?;
+        // Hashes of pending transactions are stored in upper case hex
+        let transaction_hash = hash.to_string();
+
+        let mut pending =
+            pending_transaction::get_pending_transaction(&self.db_pool, &transaction_hash)
+                .await?
+                .ok_or_else(|| {
+                    anyhow!(
+                        "pending transaction with hash {} not found",
+                        transaction_hash
+                    )
+                })?;
+
+        if pending.status != PendingTransactionStatus::Pending {
+            return Ok(pending);
+        }
+
+        let chain = chain::get_chain(&self.db_pool, &pending.chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", pending.chain_id))?;
+
+        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
+            .context("unable to connect to rpc client")?;
+
+        let response = match query_tx(&rpc_client, hash).await? {
+            Some(response) => response,
+            None => return Ok(pending),
+        };
+
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("unable to begin database transaction")?;
+
+        // Changes made to the state of solo machine for a failed transaction are not rolled back because the signatures
+        // over them may have already been used
+        pending.status = if response.deliver_tx.code.is_ok() {
+            PendingTransactionStatus::Finalized
+        } else {
+            PendingTransactionStatus::Voided
+        };
+
+        pending_transaction::update_pending_transaction_status(
+            &mut transaction,
+            &transaction_hash,
+            pending.status,
+        )
+        .await?;
+
+        if pending.status == PendingTransactionStatus::Voided {
+            transaction
+                .commit()
+                .await
+                .context("unable to commit transaction for voiding pending transaction")?;
+
+            return Ok(pending);
+        }
+
+        match pending.operation.clone() {
+            PendingOperation::Mint {
+                channel_id,
+                receiver,
+                amount,
+                denom,
+            } => {
+                let connection_details =
+                    connection::get_connection(&self.db_pool, &chain.id, &channel_id)
+                        .await?
+                        .ok_or_else(|| {
+                            anyhow!(
+                                "connection with channel id {} not found for chain with id {}",
+                                channel_id,
+                                chain.id
+                            )
+                        })?;
+
+                self.process_mint_response(
+                    &rpc_client,
+                    transaction,
+                    response,
+                    &chain,
+                    &connection_details,
+                    pending.request_id.clone(),
+                    receiver,
+                    amount,
+                    denom,
+                )
+                .await?;
+            }
+            PendingOperation::Acknowledge {
+                packet,
+                denom,
+                rejection_reason,
+                redeemed,
+            } => {
+                transaction
+                    .commit()
+                    .await
+                    .context("unable to commit transaction for finalizing pending transaction")?;
+
+                let (packet, packet_data) = decode_pending_packet(&packet)?;
+
+                self.finish_ack(
+                    &chain.id,
+                    &packet.source_channel.parse()?,
+                    &packet,
+                    packet_data,
+                    denom,
+                    rejection_reason,
+                    redeemed,
+                    pending.transaction_hash.clone(),
+                )
+                .await?;
+            }
+            PendingOperation::Refund { packet, denom } => {
+                transaction
+                    .commit()
+                    .await
+                    .context("unable to commit transaction for finalizing pending transaction")?;
+
+                let (packet, packet_data) = decode_pending_packet(&packet)?;
+
+                self.finish_refund(
+                    &chain.id,
+                    pending.request_id.clone(),
+                    packet_data,
+                    denom,
+                    pending.transaction_hash.clone(),
+                )
+                .await?;
+
+                pending_ack::remove_pending_ack(&self.db_pool, &chain.id, &packet).await?;
+            }
+            PendingOperation::Other { .. } => {
+                transaction
+                    .commit()
+                    .await
+                    .context("unable to commit transaction for finalizing pending transaction")?;
+            }
+        }
+
+        Ok(pending)
+    }
+
+    /// Searches for packets sent from IBC enabled chain over given channel in blocks starting at packet search height
+    /// of channel and processes the ones which are not yet acknowledged (or timed out). Advances packet search height
+    /// of channel (in database) past all the processed blocks.
+    async fn receive_packets(
+        &self,
+        signer: impl Signer,
+        chain_id: &ChainId,
+        connection_details: &ChainConnectionDetails,
+        memo: String,
+    ) -> Result<()> {
+        let chain = chain::get_chain(&self.db_pool, chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
+
+        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
+            .context("unable to connect to rpc client")?;
+
+        let port_id = connection_details.port_id.to_string();
+        let channel_id = connection_details.solo_machine_channel_id.to_string();
+
+        let query = Query::eq("send_packet.packet_src_port", port_id.as_str())
+            .and_eq("send_packet.packet_src_channel", channel_id.as_str())
+            .and_gte(
+                "tx.height",
+                connection::get_packet_search_height(
+                    &self.db_pool,
+                    chain_id,
+                    &connection_details.solo_machine_channel_id,
+                )
+                .await?,
+            );
+
+        let mut txs = vec![];
+        let mut page = 1;
+
+        loop {
+            let response = rpc_client
+                .tx_search(
+                    query.clone(),
+                    false,
+                    page,
+                    TX_SEARCH_PAGE_SIZE,
+                    Order::Ascending,
+                )
+                .await?;
+
+            let is_last_page = response.txs.is_empty()
+                || txs.len() + response.txs.len() >= response.total_count as usize;
+
+            txs.extend(response.txs);
+
+            if is_last_page {
+                break;
+            }
+
+            page += 1;
+        }
+
+        for tx in txs {
+            let _packet_lock = self.packet_lock.lock().await;
+
+            let mut packets = vec![];
+
+            for packet in extract_packets_from_events(&tx.tx_result.events, "send_packet")? {
+                // Pending packets are retried by ack retry worker
+                if packet.source_port == port_id
+                    && packet.source_channel == channel_id
+                    && pending_ack::get_pending_ack(&self.db_pool, chain_id, &packet)
+                        .await?
+                        .is_none()
+                    && is_packet_committed(&rpc_client, &packet).await?
+                {
+                    packets.push(packet);
+                }
+            }
+
+            // Failed packets are persisted as pending acknowledgements (and retried later by ack retry worker). So,
+            // they should not block the processing of packets in next blocks.
+            if let Err(e) = self
+                .process_packets(
+                    &signer,
+                    &rpc_client,
+                    &chain,
+                    connection_details,
+                    packets,
+                    tx.height,
+                    memo.clone(),
+                    None,
+                )
+                .await
+            {
+                notify_event(
+                    &self.notifier,
+                    Event::Warning {
+                        message: e.to_string(),
+                    },
+                )?;
+            }
+
+            connection::set_packet_search_height(
+                &self.db_pool,
+                chain_id,
+                &connection_details.solo_machine_channel_id,
+                tx.height.value() + 1,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Retries acknowledging given pending packets (sent over given channel with given request ID)
+    async fn retry_pending_acks(
+        &self,
+        signer: impl Signer,
+        chain_id: &ChainId,
+        channel_id: &str,
+        request_id: Option<String>,
+        pending_acks: Vec<PendingAck>,
+        memo: String,
+    ) -> Result<()> {
+        let chain = chain::get_chain(&self.db_pool, chain_id)
+            .await?
+            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
+
+        let connection_details =
+            connection::select_connection(&self.db_pool, &chain, Some(&channel_id.parse()?))
+                .await?;
+
+        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
+            .context("unable to connect to rpc client")?;
+
+        let mut packets = vec![];
+        let mut packets_height = 0;
+
+        for pending_ack in pending_acks {
+            if is_packet_committed(&rpc_client, &pending_ack.packet).await? {
+                packets_height = packets_height.max(pending_ack.packet_height);
+                packets.push(pending_ack.packet);
+            } else {
+                // Packet is already acknowledged (or timed out) on IBC enabled chain
+                pending_ack::remove_pending_ack(&self.db_pool, chain_id, &pending_ack.packet)
+                    .await?;
+            }
+        }
+
+        if packets.is_empty() {
+            return Ok(());
+        }
+
+        self.process_packets(
+            signer,
+            &rpc_client,
+            &chain,
+            &connection_details,
+            packets,
+            BlockHeight::try_from(packets_height)
+                .map_err(|e| anyhow!("invalid block height: {}", e))?,
+            memo,
+            request_id,
+        )
+        .await
+    }
+
+    /// Processes packets sent from IBC enabled chain to solo machine, i.e., acknowledges them (or times them out if
+    /// they've timed out on solo machine). Packets which are not sent by signer are recorded as redeem operations.
+    ///
+    /// All the packets are persisted as pending acknowledgements until they're processed successfully, so that failed
+    /// ones can be retried later.
+    #[allow(clippy::too_many_arguments)]
+    async fn process_packets<C>(
+        &self,
+        signer: impl Signer,
+        rpc_client: &C,
+        chain: &Chain,
+        connection_details: &ChainConnectionDetails,
+        packets: Vec<Packet>,
+        packets_height: BlockHeight,
+        memo: String,
+        request_id: Option<String>,
+    ) -> Result<()>
+    where
+        C: Client + Send + Sync,
+    {
+        if packets.is_empty() {
+            return Ok(());
+        }
+
+        for packet in packets.iter() {
+            pending_ack::add_pending_ack(
+                &self.db_pool,
+                &chain.id,
+                packet,
+                packets_height.value(),
+                request_id.as_deref(),
+            )
+            .await?;
+        }
+
+        let proof_height = match self
+            .get_tendermint_client_height_after(
+                rpc_client,
+                chain,
+                connection_details,
+                packets_height,
+            )
+            .await
+        {
+            Ok(proof_height) => proof_height,
+            Err(e) => {
+                for packet in packets.iter() {
+                    self.record_ack_failure(&chain.id, packet, &e).await?;
+                }
+
+                return Err(e);
+            }
+        };
+
+        let address = signer.to_account_address()?;
+        let mut result = Ok(());
+
+        for packet in packets {
+            let sequence = packet.sequence;
+            let pending_packet = packet.clone();
+
+            if let Err(e) = self
+                .process_packet(
+                    &signer,
+                    rpc_client,
+                    chain,
+                    connection_details,
+                    packet,
+                    &proof_height,
+                    &address,
+                    memo.clone(),
+                    request_id.clone(),
+                )
+                .await
+            {
+                self.record_ack_failure(&chain.id, &pending_packet, &e)
+                    .await?;
+
+                if result.is_ok() {
+                    result = Err(e.context(format!(
+                        "unable to process packet with sequence {}",
+                        sequence
+                    )));
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Processes a packet sent from IBC enabled chain to solo machine and removes it from pending acknowledgements
+    #[allow(clippy::too_many_arguments)]
+    async fn process_packet<C>(
+        &self,
+        signer: impl Signer,
+        rpc_client: &C,
+        chain: &Chain,
+        connection_details: &ChainConnectionDetails,
+        packet: Packet,
+        proof_height: &Height,
+        address: &str,
+        memo: String,
+        request_id: Option<String>,
+    ) -> Result<()>
+    where
+        C: Client + Send + Sync,
+    {
+        ensure!(
+            connection_details.port_id.to_string() == packet.source_port,
+            "invalid source port id"
+        );
+        ensure!(
+            connection_details.solo_machine_channel_id.to_string() == packet.source_channel,
+            "invalid source channel id"
+        );
+        ensure!(
+            connection_details.port_id.to_string() == packet.destination_port,
+            "invalid destination port id"
+        );
+        ensure!(
+            connection_details.tendermint_channel_id.to_string() == packet.destination_channel,
+            "invalid destination channel id"
+        );
+
+        verify_packet_commitment(
+            &self.db_pool,
+            rpc_client,
+            connection_details,
+            &packet,
+            proof_height,
+        )
+        .await?;
+
+        let packet_data: TokenTransferPacketData =
+            serde_json::from_slice(&packet.data).context("unable to decode packet data")?;
+
+        let denom = match get_packet_denom(connection_details, &packet_data) {
+            Ok(denom) => denom,
+            Err(e) => {
+                pending_ack::remove_pending_ack(&self.db_pool, &chain.id, &packet).await?;
+
+                return notify_event(
+                    &self.notifier,
+                    Event::Warning {
+                        message: format!(
+                            "skipping packet with sequence {}: {}",
+                            packet.sequence, e
+                        ),
+                    },
+                );
+            }
+        };
+
+        // Timeouts are checked against the stored consensus timestamp of client (i.e., the timestamp which IBC enabled
+        // chain uses when verifying a proof of timeout)
+        let client = solo_machine_client::get_solo_machine_client(
+            &self.db_pool,
+            &chain.id,
+            &connection_details.solo_machine_client_id,
+        )
+        .await?
+        .ok_or_else(|| {
+            anyhow!(
+                "solo machine client with id {} not found for chain with id {}",
+                connection_details.solo_machine_client_id,
+                chain.id
+            )
+        })?;
+
+        if is_packet_timed_out(&client, &packet)? {
+            let pending_packet = packet.clone();
+
+            self.refund_packet(
+                &signer,
+                rpc_client,
+                chain,
+                connection_details,
+                packet,
+                packet_data,
+                denom,
+                memo,
+                request_id,
+            )
+            .await?;
+
+            return pending_ack::remove_pending_ack(&self.db_pool, &chain.id, &pending_packet)
+                .await;
+        }
+
+        let pending_packet = packet.clone();
+
+        let decision = self
+            .policy
+            .check(&IncomingPacket {
+                chain_id: chain.id.clone(),
+                denom: denom.clone(),
+                amount: packet_data.amount.clone(),
+                sender: packet_data.sender.clone(),
+                receiver: packet_data.receiver.clone(),
+                memo: packet_data.memo.clone(),
+            })
+            .await
+            .context("unable to check packet against acceptance policy")?;
+
+        let rejection_reason = match decision {
+            PacketDecision::Accept => None,
+            PacketDecision::Reject { reason } => Some(reason),
+        };
+
+        // Redemption of tokens is only recorded if they were sent by an account other than solo machine's
+        let redeemed = rejection_reason.is_none() && packet_data.sender != address;
+
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("unable to begin database transaction")?;
+
+        let msg = transaction_builder::msg_token_receive_ack(
+            &mut transaction,
+            &signer,
+            self.fee_payer.as_ref(),
+            chain,
+            connection_details,
+            packet,
+            rejection_reason.as_deref(),
+            memo,
+            request_id.as_deref(),
+        )
+        .await?;
+
+        let response = match broadcast_tx(rpc_client, chain.config.broadcast_mode, &msg).await {
+            Ok(response) => response,
+            Err(e) => {
+                return Err(retain_on_unknown_outcome(
+                    transaction,
+                    e,
+                    &chain.id,
+                    request_id.as_deref(),
+                    PendingOperation::Acknowledge {
+                        packet: hex::encode(proto_encode(&pending_packet)?),
+                        denom: denom.clone(),
+                        rejection_reason: rejection_reason.clone(),
+                        redeemed,
+                    },
+                )
+                .await)
+            }
+        };
+
+        transaction
+            .commit()
+            .await
+            .context("unable to commit transaction for processing IBC packets")?;
+
+        let transaction_hash = ensure_response_success(&response)?;
+
+        self.finish_ack(
+            &chain.id,
+            &connection_details.solo_machine_channel_id,
+            &pending_packet,
+            packet_data,
+            denom,
+            rejection_reason,
+            redeemed,
+            transaction_hash,
+        )
+        .await
+    }
+
+    /// Removes given packet from pending acknowledgements after it is acknowledged (also records the redemption of
+    /// tokens if `redeemed` is `true`)
+    #[allow(clippy::too_many_arguments)]
+    async fn finish_ack(
+        &self,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        packet: &Packet,
+        packet_data: TokenTransferPacketData,
+        denom: Denom,
+        rejection_reason: Option<String>,
+        redeemed: bool,
+        transaction_hash: String,
+    ) -> Result<()> {
+        pending_ack::remove_pending_ack(&self.db_pool, chain_id, packet).await?;
 
         notify_event(
             &self.notifier,
-            Event::TokensBurnt {
-                chain_id,
-                request_id: request_id.clone(),
-                from_address: address,
-                amount,
-                denom,
+            Event::PacketAcknowledged {
+                chain_id: chain_id.clone(),
+                channel_id: channel_id.clone(),
+                sequence: packet.sequence,
                 transaction_hash: transaction_hash.clone(),
             },
         )?;
 
-        if let Err(e) = self
-            .process_packets(
-                signer,
-                &rpc_client,
-                &mut chain,
-                extract_packets(&response)?,
-                memo,
-                request_id,
+        if let Some(reason) = rejection_reason {
+            return notify_event(
+                &self.notifier,
+                Event::PacketRejected {
+                    chain_id: chain_id.clone(),
+                    channel_id: channel_id.clone(),
+                    sequence: packet.sequence,
+                    from_address: packet_data.sender,
+                    amount: packet_data.amount,
+                    denom,
+                    reason,
+                    transaction_hash,
+                },
+            );
+        }
+
+        if redeemed {
+            operation::add_operation(
+                &self.db_pool,
+                None,
+                &packet_data.sender,
+                &denom,
+                &packet_data.amount,
+                &OperationType::Redeem {
+                    chain_id: chain_id.clone(),
+                },
+                &transaction_hash,
             )
-            .await
-        {
-            // Create a warning instead of returning an error because IBC transfer is successful even if processing of
-            // packets (i.e., sending acks) fails
+            .await?;
+
             notify_event(
                 &self.notifier,
-                Event::Warning {
-                    message: e.to_string(),
+                Event::TokensRedeemed {
+                    chain_id: chain_id.clone(),
+                    from_address: packet_data.sender,
+                    to_address: packet_data.receiver,
+                    amount: packet_data.amount,
+                    denom,
+                    transaction_hash,
                 },
             )?;
         }
 
-        Ok(transaction_hash)
+        Ok(())
     }
 
-    /// Updates signer for future IBC transactions
-    pub async fn update_signer(
+    /// Records a failed attempt to acknowledge given packet (with exponential backoff for next attempt). Marks the
+    /// packet as failed if maximum number of attempts is reached.
+    async fn record_ack_failure(
+        &self,
+        chain_id: &ChainId,
+        packet: &Packet,
+        error: &Error,
+    ) -> Result<()> {
+        let pending_ack =
+            match pending_ack::get_pending_ack(&self.db_pool, chain_id, packet).await? {
+                Some(pending_ack) => pending_ack,
+                None => return Ok(()),
+            };
+
+        let attempts = pending_ack.attempts + 1;
+        let failed = attempts >= MAX_ACK_ATTEMPTS;
+        let next_attempt_at =
+            Utc::now() + chrono::Duration::from_std(get_ack_retry_delay(attempts))?;
+
+        pending_ack::update_pending_ack_failure(
+            &self.db_pool,
+            chain_id,
+            packet,
+            attempts,
+            failed,
+            &error.to_string(),
+            next_attempt_at,
+        )
+        .await?;
+
+        if failed {
+            notify_event(
+                &self.notifier,
+                Event::PacketAckFailed {
+                    chain_id: chain_id.clone(),
+                    channel_id: packet.source_channel.parse()?,
+                    sequence: packet.sequence,
+                    attempts,
+                    error: error.to_string(),
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Times out given packet (sent from IBC enabled chain to solo machine) so that IBC enabled chain refunds the
+    /// burnt tokens
+    #[allow(clippy::too_many_arguments)]
+    async fn refund_packet<C>(
         &self,
         signer: impl Signer,
-        chain_id: ChainId,
-        new_public_key: PublicKey,
+        rpc_client: &C,
+        chain: &Chain,
+        connection_details: &ChainConnectionDetails,
+        packet: Packet,
+        packet_data: TokenTransferPacketData,
+        denom: Denom,
         memo: String,
-    ) -> Result<()> {
+        request_id: Option<String>,
+    ) -> Result<()>
+    where
+        C: Client + Send + Sync,
+    {
+        let pending_operation = PendingOperation::Refund {
+            packet: hex::encode(proto_encode(&packet)?),
+            denom: denom.clone(),
+        };
+
         let mut transaction = self
             .db_pool
             .begin()
             .await
             .context("unable to begin database transaction")?;
 
-        let mut chain = chain::get_chain(&mut transaction, &chain_id)
-            .await?
-            .ok_or_else(|| anyhow!("chain details for {} not found", chain_id))?;
-
-        chain_keys::add_chain_key(&mut transaction, &chain_id, &new_public_key.encode()).await?;
-
-        let rpc_client = HttpClient::new(chain.config.rpc_addr.as_str())
-            .context("unable to connect to rpc client")?;
-
-        let msg = transaction_builder::msg_update_solo_machine_client(
+        let msg = transaction_builder::msg_token_receive_timeout(
             &mut transaction,
             &signer,
-            &mut chain,
-            Some(&new_public_key),
-            memo.clone(),
+            self.fee_payer.as_ref(),
+            chain,
+            connection_details,
+            packet,
+            memo,
+            request_id.as_deref(),
         )
         .await?;
 
-        let response = rpc_client
-            .broadcast_tx_commit(proto_encode(&msg)?.into())
-            .await?;
-
-        ensure_response_success(&response)?;
+        let response = match broadcast_tx(rpc_client, chain.config.broadcast_mode, &msg).await {
+            Ok(response) => response,
+            Err(e) => {
+                return Err(retain_on_unknown_outcome(
+                    transaction,
+                    e,
+                    &chain.id,
+                    request_id.as_deref(),
+                    pending_operation,
+                )
+                .await)
+            }
+        };
 
         transaction
             .commit()
             .await
-            .context("unable to commit transaction for receiving tokens over IBC")?;
+            .context("unable to commit transaction for processing IBC packets")?;
+
+        let transaction_hash = ensure_response_success(&response)?;
+
+        self.finish_refund(&chain.id, request_id, packet_data, denom, transaction_hash)
+            .await
+    }
+
+    /// Records refund of burnt tokens after the packet is timed out on IBC enabled chain
+    async fn finish_refund(
+        &self,
+        chain_id: &ChainId,
+        request_id: Option<String>,
+        packet_data: TokenTransferPacketData,
+        denom: Denom,
+        transaction_hash: String,
+    ) -> Result<()> {
+        operation::add_operation(
+            &self.db_pool,
+            request_id.as_deref(),
+            &packet_data.sender,
+            &denom,
+            &packet_data.amount,
+            &OperationType::Refund {
+                chain_id: chain_id.clone(),
+            },
+            &transaction_hash,
+        )
+        .await?;
 
         notify_event(
             &self.notifier,
-            Event::SignerUpdated {
-                chain_id,
-                old_public_key: signer.to_public_key()?,
-                new_public_key,
+            Event::TokensRefunded {
+                chain_id: chain_id.clone(),
+                request_id,
+                to_address: packet_data.sender,
+                amount: packet_data.amount,
+                denom,
+                transaction_hash,
             },
         )
     }
 
-    /// Fetches history of all operations
-    pub async fn history(
-        &self,
-        signer: impl ToPublicKey,
-        limit: u32,
-        offset: u32,
-    ) -> Result<Vec<Operation>> {
-        let account_address = signer.to_account_address()?;
-        operation::get_operations(&self.db_pool, &account_address, limit, offset).await
-    }
-
-    async fn process_packets<C>(
+    /// Returns a height of tendermint client on solo machine which is greater than given block height (i.e., its
+    /// consensus state contains app hash committing to the state after given block). Updates tendermint client if
+    /// needed.
+    async fn get_tendermint_client_height_after<C>(
         &self,
-        signer: impl Signer,
         rpc_client: &C,
-        chain: &mut Chain,
-        packets: Vec<Packet>,
-        memo: String,
-        request_id: Option<String>,
-    ) -> Result<()>
+        chain: &Chain,
+        connection_details: &ChainConnectionDetails,
+        block_height: BlockHeight,
+    ) -> Result<Height>
     where
         C: Client + Send + Sync,
     {
-        let connection_details = chain.connection_details.clone().ok_or_else(|| {
-            anyhow!(
-                "connection details for chain with id {} are missing",
-                chain.id
-            )
-        })?;
-
-        for packet in packets {
-            ensure!(
-                chain.config.port_id.to_string() == packet.source_port,
-                "invalid source port id"
-            );
-            ensure!(
-                connection_details.solo_machine_channel_id.to_string() == packet.source_channel,
-                "invalid source channel id"
-            );
-            ensure!(
-                chain.config.port_id.to_string() == packet.destination_port,
-                "invalid destination port id"
-            );
-            ensure!(
-                connection_details.tendermint_channel_id.to_string() == packet.destination_channel,
-                "invalid destination channel id"
-            );
-
-            let mut transaction = self
-                .db_pool
-                .begin()
-                .await
-                .context("unable to begin database transaction")?;
+        let tendermint_client_id = &connection_details.tendermint_client_id;
+
+        let latest_height =
+            ibc_handler::get_tendermint_client_state(&self.db_pool, tendermint_client_id)
+                .await?
+                .ok_or_else(|| {
+                    anyhow!(
+                        "client state for tendermint client with id {} not found",
+                        tendermint_client_id
+                    )
+                })?
+                .latest_height
+                .ok_or_else(|| anyhow!("latest height cannot be absent in client state"))?;
+
+        if latest_height.revision_height > block_height.value() {
+            return Ok(latest_height);
+        }
 
-            let msg = transaction_builder::msg_token_receive_ack(
-                &mut *transaction,
-                &signer,
-                &mut *chain,
-                packet,
-                memo.clone(),
-                request_id.as_deref(),
-            )
-            .await?;
+        let mut attempts = 0;
 
-            let response = rpc_client
-                .broadcast_tx_commit(proto_encode(&msg)?.into())
-                .await?;
+        while rpc_client.status().await?.sync_info.latest_block_height <= block_height {
+            attempts += 1;
 
-            transaction
-                .commit()
-                .await
-                .context("unable to commit transaction for processing IBC packets")?;
+            ensure!(
+                attempts < MAX_BLOCK_POLL_ATTEMPTS,
+                "timed out while waiting for block after height {} on chain with id {}",
+                block_height,
+                chain.id
+            );
 
-            ensure_response_success(&response)?;
+            sleep(BLOCK_POLL_INTERVAL).await;
         }
 
-        Ok(())
+        self.update_tendermint_client(
+            chain.id.clone(),
+            Some(connection_details.solo_machine_channel_id.clone()),
+        )
+        .await
     }
 }
 
 async fn create_solo_machine_client<C>(
     signer: impl Signer,
+    fee_payer: Option<&Arc<dyn Signer>>,
     rpc_client: &C,
     chain: &Chain,
     memo: String,
@@ -625,11 +3311,10 @@ async fn create_solo_machine_client<C>(
 where
     C: Client + Send + Sync,
 {
-    let msg = transaction_builder::msg_create_solo_machine_client(signer, chain, memo).await?;
+    let msg =
+        transaction_builder::msg_create_solo_machine_client(signer, fee_payer, chain, memo).await?;
 
-    let response = rpc_client
-        .broadcast_tx_commit(proto_encode(&msg)?.into())
-        .await?;
+    let response = broadcast_tx(rpc_client, chain.config.broadcast_mode, &msg).await?;
 
     ensure_response_success(&response)?;
 
@@ -664,6 +3349,7 @@ async fn create_tendermint_client(
 
 async fn connection_open_init<C>(
     signer: impl Signer,
+    fee_payer: Option<&Arc<dyn Signer>>,
     rpc_client: &C,
     chain: &Chain,
     solo_machine_client_id: &ClientId,
@@ -675,6 +3361,7 @@ where
 {
     let msg = transaction_builder::msg_connection_open_init(
         signer,
+        fee_payer,
         chain,
         solo_machine_client_id,
         tendermint_client_id,
@@ -682,9 +3369,7 @@ where
     )
     .await?;
 
-    let response = rpc_client
-        .broadcast_tx_commit(proto_encode(&msg)?.into())
-        .await?;
+    let response = broadcast_tx(rpc_client, chain.config.broadcast_mode, &msg).await?;
 
     ensure_response_success(&response)?;
 
@@ -730,8 +3415,10 @@ async fn connection_open_try<'e>(
 async fn connection_open_ack<C>(
     transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
+    fee_payer: Option<&Arc<dyn Signer>>,
     rpc_client: &C,
-    chain: &mut Chain,
+    chain: &Chain,
+    solo_machine_client_id: &ClientId,
     solo_machine_connection_id: &ConnectionId,
     tendermint_client_id: &ClientId,
     tendermint_connection_id: &ConnectionId,
@@ -743,7 +3430,9 @@ where
     let msg = transaction_builder::msg_connection_open_ack(
         transaction,
         signer,
+        fee_payer,
         chain,
+        solo_machine_client_id,
         solo_machine_connection_id,
         tendermint_client_id,
         tendermint_connection_id,
@@ -751,9 +3440,7 @@ where
     )
     .await?;
 
-    let response = rpc_client
-        .broadcast_tx_commit(proto_encode(&msg)?.into())
-        .await?;
+    let response = broadcast_tx(rpc_client, chain.config.broadcast_mode, &msg).await?;
 
     ensure_response_success(&response)?;
 
@@ -772,23 +3459,34 @@ async fn connection_open_confirm(
     ibc_handler::update_connection(&mut *transaction, connection_id, &connection).await
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn channel_open_init<C>(
     signer: impl Signer,
+    fee_payer: Option<&Arc<dyn Signer>>,
     rpc_client: &C,
     chain: &Chain,
+    port_id: &PortId,
+    ordering: ChannelOrdering,
+    version: &str,
     solo_machine_connection_id: &ConnectionId,
     memo: String,
 ) -> Result<ChannelId>
 where
     C: Client + Send + Sync,
 {
-    let msg =
-        transaction_builder::msg_channel_open_init(signer, chain, solo_machine_connection_id, memo)
-            .await?;
+    let msg = transaction_builder::msg_channel_open_init(
+        signer,
+        fee_payer,
+        chain,
+        port_id,
+        ordering.into(),
+        version,
+        solo_machine_connection_id,
+        memo,
+    )
+    .await?;
 
-    let response = rpc_client
-        .broadcast_tx_commit(proto_encode(&msg)?.into())
-        .await?;
+    let response = broadcast_tx(rpc_client, chain.config.broadcast_mode, &msg).await?;
 
     ensure_response_success(&response)?;
 
@@ -803,6 +3501,8 @@ where
 async fn channel_open_try<'e>(
     executor: impl Executor<'e, Database = Db>,
     port_id: &PortId,
+    ordering: ChannelOrdering,
+    version: &str,
     solo_machine_channel_id: &ChannelId,
     tendermint_connection_id: &ConnectionId,
 ) -> Result<ChannelId> {
@@ -810,13 +3510,13 @@ async fn channel_open_try<'e>(
 
     let channel = Channel {
         state: ChannelState::Tryopen.into(),
-        ordering: ChannelOrder::Unordered.into(),
+        ordering: ChannelOrder::from(ordering).into(),
         counterparty: Some(ChannelCounterparty {
             port_id: port_id.to_string(),
             channel_id: solo_machine_channel_id.to_string(),
         }),
         connection_hops: vec![tendermint_connection_id.to_string()],
-        version: "ics20-1".to_string(),
+        version: version.to_string(),
     };
 
     ibc_handler::add_channel(executor, port_id, &channel_id, &channel).await?;
@@ -824,11 +3524,16 @@ async fn channel_open_try<'e>(
     Ok(channel_id)
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn channel_open_ack<C>(
     transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
+    fee_payer: Option<&Arc<dyn Signer>>,
     rpc_client: &C,
-    chain: &mut Chain,
+    chain: &Chain,
+    solo_machine_client_id: &ClientId,
+    port_id: &PortId,
+    version: &str,
     solo_machine_channel_id: &ChannelId,
     tendermint_channel_id: &ChannelId,
     memo: String,
@@ -839,16 +3544,18 @@ where
     let msg = transaction_builder::msg_channel_open_ack(
         transaction,
         signer,
+        fee_payer,
         chain,
+        solo_machine_client_id,
+        port_id,
+        version,
         solo_machine_channel_id,
         tendermint_channel_id,
         memo,
     )
     .await?;
 
-    let response = rpc_client
-        .broadcast_tx_commit(proto_encode(&msg)?.into())
-        .await?;
+    let response = broadcast_tx(rpc_client, chain.config.broadcast_mode, &msg).await?;
 
     ensure_response_success(&response)?;
 
@@ -871,7 +3578,89 @@ async fn channel_open_confirm(
         })?;
     channel.set_state(ChannelState::Open);
 
-    ibc_handler::update_channel(&mut *transaction, port_id, channel_id, &channel).await
+    ibc_handler::update_channel(&mut *transaction, port_id, channel_id, &channel).await
+}
+
+async fn channel_close_init(
+    transaction: &mut Transaction<'_, Db>,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<()> {
+    let mut channel = ibc_handler::get_channel(&mut *transaction, port_id, channel_id)
+        .await?
+        .ok_or_else(|| {
+            anyhow!(
+                "channel for channel id ({}) and port id ({}) not found",
+                channel_id,
+                port_id
+            )
+        })?;
+
+    ensure!(
+        channel.state() != ChannelState::Closed,
+        "channel for channel id ({}) and port id ({}) is already closed",
+        channel_id,
+        port_id
+    );
+
+    channel.set_state(ChannelState::Closed);
+
+    ibc_handler::update_channel(&mut *transaction, port_id, channel_id, &channel).await
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn channel_close_confirm<C>(
+    transaction: &mut Transaction<'_, Db>,
+    signer: impl Signer,
+    fee_payer: Option<&Arc<dyn Signer>>,
+    rpc_client: &C,
+    chain: &Chain,
+    solo_machine_client_id: &ClientId,
+    port_id: &PortId,
+    solo_machine_channel_id: &ChannelId,
+    tendermint_channel_id: &ChannelId,
+    memo: String,
+) -> Result<()>
+where
+    C: Client + Send + Sync,
+{
+    let msg = transaction_builder::msg_channel_close_confirm(
+        transaction,
+        signer,
+        fee_payer,
+        chain,
+        solo_machine_client_id,
+        port_id,
+        solo_machine_channel_id,
+        tendermint_channel_id,
+        memo,
+    )
+    .await?;
+
+    let response = broadcast_tx(rpc_client, chain.config.broadcast_mode, &msg).await?;
+
+    ensure_response_success(&response)?;
+
+    Ok(())
+}
+
+async fn confirm_handshake_step(
+    mut transaction: Transaction<'_, Db>,
+    handshake: &mut Handshake,
+    step: HandshakeStep,
+) -> Result<()> {
+    handshake.step = step;
+    handshake::update_handshake(&mut transaction, handshake).await?;
+
+    transaction.commit().await.context(format!(
+        "unable to commit transaction for connection handshake step: {}",
+        step
+    ))
+}
+
+fn get_handshake_id<T: Clone>(id: &Option<T>, name: &str) -> Result<T> {
+    id.clone()
+        .ok_or_else(|| anyhow!("{} is missing from connection handshake", name))
 }
 
 fn prepare_light_client(
@@ -879,6 +3668,16 @@ fn prepare_light_client(
     rpc_client: HttpClient,
     light_store: Box<dyn LightStore>,
 ) -> Result<Instance> {
+    // Continue from latest trusted (or verified) light block in store if it is still within trusting period. Otherwise,
+    // fallback to trust root in chain config.
+    let trust_from_store = light_store
+        .highest_trusted_or_verified()
+        .map(|light_block| {
+            let header_time = SystemTime::from(light_block.signed_header.header.time);
+            header_time + chain.config.trusting_period > SystemTime::now()
+        })
+        .unwrap_or(false);
+
     let builder = LightClientBuilder::prod(
         chain.node_id,
         rpc_client,
@@ -895,19 +3694,28 @@ fn prepare_light_client(
         Some(chain.config.rpc_timeout),
     );
 
-    let builder = builder.trust_primary_at(
-        chain.config.trusted_height,
-        TendermintHash::Sha256(chain.config.trusted_hash),
-    )?;
+    let builder = if trust_from_store {
+        builder.trust_from_store()?
+    } else {
+        builder.trust_primary_at(
+            chain.config.trusted_height,
+            TendermintHash::Sha256(chain.config.trusted_hash),
+        )?
+    };
 
     Ok(builder.build())
 }
 
 fn extract_packets(response: &TxCommitResponse) -> Result<Vec<Packet>> {
+    extract_packets_from_events(&response.deliver_tx.events, "send_packet")
+}
+
+/// Extracts packets from given events of given type (e.g., `send_packet`, `recv_packet`)
+fn extract_packets_from_events(events: &[AbciEvent], event_type: &str) -> Result<Vec<Packet>> {
     let mut packets = vec![];
 
-    for event in response.deliver_tx.events.iter() {
-        if event.type_str == "send_packet" {
+    for event in events.iter() {
+        if event.type_str == event_type {
             let mut attributes = HashMap::new();
 
             for tag in event.attributes.iter() {
@@ -958,6 +3766,737 @@ fn extract_packets(response: &TxCommitResponse) -> Result<Vec<Packet>> {
     Ok(packets)
 }
 
+/// Returns delay before next attempt to acknowledge a packet after given number of failed attempts
+/// Decodes a packet sent from IBC enabled chain (protobuf encoded, in hex) recorded in a pending operation along with
+/// its data
+fn decode_pending_packet(packet: &str) -> Result<(Packet, TokenTransferPacketData)> {
+    let packet = hex::decode(packet).context("unable to decode hex bytes of packet")?;
+    let packet = Packet::decode(packet.as_slice()).context("unable to decode packet")?;
+    let packet_data =
+        serde_json::from_slice(&packet.data).context("unable to decode packet data")?;
+
+    Ok((packet, packet_data))
+}
+
+fn get_ack_retry_delay(attempts: u32) -> Duration {
+    2u32.checked_pow(attempts.saturating_sub(1))
+        .and_then(|multiplier| ACK_RETRY_BASE_DELAY.checked_mul(multiplier))
+        .map(|delay| delay.min(ACK_RETRY_MAX_DELAY))
+        .unwrap_or(ACK_RETRY_MAX_DELAY)
+}
+
+/// Returns the signature over sign bytes of given mint (which is filled by offline signer)
+fn sign_bytes_signature(unsigned_mint: &UnsignedMint) -> Result<Vec<u8>> {
+    hex::decode(
+        unsigned_mint
+            .sign_bytes
+            .signature
+            .as_deref()
+            .ok_or_else(|| anyhow!("signature over sign bytes is missing"))?,
+    )
+    .context("unable to decode hex bytes of signature")
+}
+
+/// Verifies that state of solo machine has not changed after generating given mint (i.e., its sign bytes are still the
+/// sign bytes of packet commitment proof for the current sequence of solo machine client). Returns the packet of mint,
+/// connection details of its channel and solo machine client (with consensus timestamp of sign bytes).
+async fn verify_unsigned_mint(
+    transaction: &mut Transaction<'_, Db>,
+    chain: &Chain,
+    unsigned_mint: &UnsignedMint,
+) -> Result<(Packet, ChainConnectionDetails, SoloMachineClient)> {
+    let packet =
+        Packet::decode(unsigned_mint.packet.as_slice()).context("unable to decode packet")?;
+
+    let solo_machine_channel_id = packet
+        .destination_channel
+        .parse()
+        .context("invalid destination channel in packet")?;
+    let connection_details =
+        connection::get_connection(&mut *transaction, &chain.id, &solo_machine_channel_id)
+            .await?
+            .ok_or_else(|| {
+                anyhow!(
+                    "connection with channel id {} not found for chain with id {}",
+                    solo_machine_channel_id,
+                    chain.id
+                )
+            })?;
+
+    let mut client = solo_machine_client::get_solo_machine_client(
+        &mut *transaction,
+        &chain.id,
+        &connection_details.solo_machine_client_id,
+    )
+    .await?
+    .ok_or_else(|| {
+        anyhow!(
+            "solo machine client with id {} not found for chain with id {}",
+            connection_details.solo_machine_client_id,
+            chain.id
+        )
+    })?;
+
+    let sign_bytes = &unsigned_mint.sign_bytes;
+
+    // Sign bytes were generated with the consensus timestamp at the time of generating the mint, which must not be
+    // behind the consensus timestamp of client (it may have advanced in the meantime)
+    let sign_bytes_timestamp = i64::try_from(sign_bytes.timestamp)
+        .ok()
+        .and_then(|timestamp| Utc.timestamp_opt(timestamp, 0).single())
+        .ok_or_else(|| anyhow!("invalid timestamp in sign bytes: {}", sign_bytes.timestamp))?;
+
+    ensure!(
+        sign_bytes_timestamp >= client.consensus_timestamp,
+        "consensus timestamp of solo machine client has advanced after generating the mint, generate it again"
+    );
+    client.consensus_timestamp = sign_bytes_timestamp;
+
+    let packet_sequence = connection::get_packet_sequence(
+        &mut *transaction,
+        &chain.id,
+        &connection_details.solo_machine_channel_id,
+    )
+    .await?;
+
+    let current_sign_bytes =
+        transaction_builder::packet_commitment_sign_bytes(chain, &client, &packet)?;
+
+    ensure!(
+        packet.sequence == packet_sequence && current_sign_bytes.sign_bytes == sign_bytes.sign_bytes,
+        "state of solo machine has changed after generating the mint (sequence: {}, packet sequence: {}), generate it again",
+        client.sequence,
+        packet_sequence
+    );
+
+    Ok((packet, connection_details, client))
+}
+
+/// Returns `true` if given packet (sent from IBC enabled chain to solo machine) has timed out on solo machine (height
+/// and timestamp of solo machine are the sequence and consensus timestamp of solo machine client used by the packet's
+/// connection)
+fn is_packet_timed_out(client: &SoloMachineClient, packet: &Packet) -> Result<bool> {
+    let timed_out_on_height = match packet.timeout_height {
+        Some(ref timeout_height) if !timeout_height.is_zero() => {
+            client.sequence >= timeout_height.revision_height
+        }
+        _ => false,
+    };
+
+    let consensus_timestamp: u64 = client
+        .consensus_timestamp
+        .timestamp()
+        .try_into()
+        .context("unable to convert unix timestamp to u64")?;
+
+    let timed_out_on_timestamp =
+        packet.timeout_timestamp != 0 && consensus_timestamp >= packet.timeout_timestamp;
+
+    Ok(timed_out_on_height || timed_out_on_timestamp)
+}
+
+/// Returns denom of tokens (on solo machine) sent to solo machine in given packet data. Tokens which were sent from
+/// solo machine are un-prefixed when they come back, all the other tokens are tracked as vouchers prefixed with the
+/// port and channel of solo machine.
+fn get_packet_denom(
+    connection_details: &ChainConnectionDetails,
+    packet_data: &TokenTransferPacketData,
+) -> Result<Denom> {
+    let denom: Denom = packet_data.denom.parse().context(format!(
+        "invalid denom in packet data: {}",
+        packet_data.denom
+    ))?;
+
+    if denom.has_prefix(
+        &connection_details.port_id,
+        &connection_details.solo_machine_channel_id,
+    ) {
+        denom
+            .remove_prefix()
+            .ok_or_else(|| anyhow!("invalid denom in packet data: {}", packet_data.denom))
+    } else {
+        Ok(denom.add_prefix(
+            &connection_details.port_id,
+            &connection_details.tendermint_channel_id,
+        ))
+    }
+}
+
+/// Processes error acknowledgement of given packet (sent from solo machine to IBC enabled chain), i.e., verifies that
+/// the acknowledgement is committed on IBC enabled chain (using ICS-23 proof of acknowledgement commitment against the
+/// consensus state of tendermint client at given height) and returns the error in acknowledgement
+async fn process_error_acknowledgement<'e, C>(
+    executor: impl Executor<'e, Database = Db>,
+    rpc_client: &C,
+    connection_details: &ChainConnectionDetails,
+    packet: &Packet,
+    acknowledgement: &str,
+    proof_height: &Height,
+) -> Result<String>
+where
+    C: Client + Send + Sync,
+{
+    let path = PacketAcknowledgementPath::new(
+        &packet.destination_port.parse()?,
+        &packet.destination_channel.parse()?,
+        packet.sequence,
+    );
+
+    let acknowledgement_commitment = query_proven_ibc_store_raw(
+        executor,
+        rpc_client,
+        &connection_details.tendermint_client_id,
+        path.into_bytes(),
+        proof_height,
+    )
+    .await
+    .with_context(|| {
+        format!(
+            "unable to verify acknowledgement commitment of packet with sequence {}",
+            packet.sequence
+        )
+    })?;
+
+    ensure!(
+        acknowledgement_commitment == Sha256::digest(acknowledgement.as_bytes()).as_slice(),
+        "acknowledgement of packet with sequence {} does not match the one committed on IBC enabled chain",
+        packet.sequence
+    );
+
+    let acknowledgement: serde_json::Value =
+        serde_json::from_str(acknowledgement).context("unable to decode acknowledgement")?;
+
+    acknowledgement
+        .get("error")
+        .and_then(serde_json::Value::as_str)
+        .map(ToOwned::to_owned)
+        .ok_or_else(|| anyhow!("acknowledgement is not an error acknowledgement"))
+}
+
+/// Returns `true` if commitment of given packet (sent from IBC enabled chain to solo machine) is still present on IBC
+/// enabled chain, i.e., the packet is neither acknowledged nor timed out yet
+async fn is_packet_committed<C>(rpc_client: &C, packet: &Packet) -> Result<bool>
+where
+    C: Client + Send + Sync,
+{
+    let path = PacketCommitmentPath::new(
+        &packet.source_port.parse()?,
+        &packet.source_channel.parse()?,
+        packet.sequence,
+    );
+
+    Ok(query_ibc_store_raw(rpc_client, path.into_bytes())
+        .await?
+        .is_some())
+}
+
+/// Queries value at given path in IBC store of IBC enabled chain and decodes it. Returns `None` if there is no value
+/// at given path.
+async fn query_ibc_store<C, T>(rpc_client: &C, path: Vec<u8>) -> Result<Option<T>>
+where
+    C: Client + Send + Sync,
+    T: Message + Default,
+{
+    query_ibc_store_raw(rpc_client, path)
+        .await?
+        .map(|value| T::decode(value.as_slice()).context("unable to decode value from IBC store"))
+        .transpose()
+}
+
+/// Queries status of given solo machine client from IBC enabled chain (using IBC client gRPC query)
+async fn query_client_status(chain: &Chain, client_id: ClientId) -> Result<ClientStatus> {
+    let endpoint = Endpoint::new(chain.config.grpc_addr.clone()).context("invalid grpc address")?;
+    let channel = endpoint.connect().await.with_context(|| {
+        format!(
+            "unable to connect to grpc query client at {}",
+            chain.config.grpc_addr
+        )
+    })?;
+
+    let mut grpc = Grpc::new(channel);
+    grpc.ready()
+        .await
+        .map_err(|err| anyhow!("grpc query client is not ready: {}", err))?;
+
+    let response: QueryClientStateResponse = grpc
+        .unary(
+            Request::new(QueryClientStateRequest {
+                client_id: client_id.to_string(),
+            }),
+            PathAndQuery::from_static(CLIENT_STATE_QUERY_PATH),
+            ProstCodec::default(),
+        )
+        .await
+        .with_context(|| format!("unable to query client state of {}", client_id))?
+        .into_inner();
+
+    let client_state = chain
+        .config
+        .solo_machine_version
+        .decode_client_state(
+            response
+                .client_state
+                .as_ref()
+                .ok_or_else(|| anyhow!("client state of {} not found", client_id))?,
+        )
+        .with_context(|| {
+            format!(
+                "{} is not a solo machine {} client",
+                client_id, chain.config.solo_machine_version
+            )
+        })?;
+
+    let public_key = client_state
+        .consensus_state
+        .and_then(|consensus_state| consensus_state.public_key)
+        .map(|public_key| PublicKey::from_any(&public_key))
+        .transpose()?;
+
+    Ok(ClientStatus {
+        client_id,
+        sequence: client_state.sequence,
+        frozen: client_state.is_frozen,
+        public_key,
+    })
+}
+
+/// Queries raw value at given path in IBC store of IBC enabled chain. Returns `None` if there is no value at given
+/// path.
+async fn query_ibc_store_raw<C>(rpc_client: &C, path: Vec<u8>) -> Result<Option<Vec<u8>>>
+where
+    C: Client + Send + Sync,
+{
+    let response = rpc_client
+        .abci_query(
+            Some(format!("store/{}/key", IBC_STORE_KEY).parse().unwrap()),
+            path,
+            None,
+            false,
+        )
+        .await?;
+
+    ensure!(
+        response.code.is_ok(),
+        "unable to query IBC store of IBC enabled chain: {}",
+        response.log
+    );
+
+    if response.value.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(response.value))
+}
+
+/// Verifies that given packet is committed on IBC enabled chain using ICS-23 proof of packet commitment against the
+/// consensus state of tendermint client at given height
+async fn verify_packet_commitment<'e, C>(
+    executor: impl Executor<'e, Database = Db>,
+    rpc_client: &C,
+    connection_details: &ChainConnectionDetails,
+    packet: &Packet,
+    proof_height: &Height,
+) -> Result<()>
+where
+    C: Client + Send + Sync,
+{
+    let commitment_path = PacketCommitmentPath::new(
+        &connection_details.port_id,
+        &connection_details.solo_machine_channel_id,
+        packet.sequence,
+    );
+
+    let commitment = query_proven_ibc_store_raw(
+        executor,
+        rpc_client,
+        &connection_details.tendermint_client_id,
+        commitment_path.into_bytes(),
+        proof_height,
+    )
+    .await
+    .context("unable to verify packet commitment")?;
+
+    ensure!(
+        commitment == packet.commitment_bytes()?,
+        "packet commitment on IBC enabled chain does not match packet with sequence {}",
+        packet.sequence
+    );
+
+    Ok(())
+}
+
+/// Returns client ID of counterparty (i.e., tendermint client on solo machine) of given connection on IBC enabled chain
+fn get_counterparty_client_id(connection: ConnectionEnd) -> Result<ClientId> {
+    connection
+        .counterparty
+        .ok_or_else(|| anyhow!("counterparty cannot be absent in connection"))?
+        .client_id
+        .parse()
+}
+
+/// Returns the only connection hop of given channel on IBC enabled chain
+fn get_connection_hop(channel: &Channel) -> Result<ConnectionId> {
+    ensure!(
+        channel.connection_hops.len() == 1,
+        "channel should have exactly one connection hop"
+    );
+
+    channel.connection_hops[0].parse()
+}
+
+/// Verifies that given client on IBC enabled chain is a solo machine client (which is not frozen) with public key of
+/// given signer and diversifier of chain, using ICS-23 proof of its client state against the consensus state of given
+/// tendermint client at given height. Returns the proven sequence and consensus timestamp of solo machine client.
+async fn verify_solo_machine_client<'e, C>(
+    executor: impl Executor<'e, Database = Db>,
+    rpc_client: &C,
+    chain: &Chain,
+    signer: impl ToPublicKey,
+    solo_machine_client_id: &ClientId,
+    tendermint_client_id: &ClientId,
+    proof_height: &Height,
+) -> Result<(u64, DateTime<Utc>)>
+where
+    C: Client + Send + Sync,
+{
+    let any_client_state: Any = query_proven_ibc_store(
+        executor,
+        rpc_client,
+        tendermint_client_id,
+        ClientStatePath::new(solo_machine_client_id).into_bytes(),
+        proof_height,
+    )
+    .await
+    .with_context(|| {
+        format!(
+            "unable to verify client state of {} on chain with id {}",
+            solo_machine_client_id, chain.id
+        )
+    })?;
+
+    let client_state = chain
+        .config
+        .solo_machine_version
+        .decode_client_state(&any_client_state)
+        .with_context(|| {
+            format!(
+                "{} is not a solo machine {} client",
+                solo_machine_client_id, chain.config.solo_machine_version
+            )
+        })?;
+
+    ensure!(
+        !client_state.is_frozen,
+        "solo machine client with id {} is frozen",
+        solo_machine_client_id
+    );
+
+    let consensus_state = client_state.consensus_state.ok_or_else(|| {
+        anyhow!(
+            "consensus state cannot be absent in client state of {}",
+            solo_machine_client_id
+        )
+    })?;
+
+    ensure!(
+        consensus_state.public_key == Some(signer.to_public_key()?.to_any()?),
+        "public key of solo machine client with id {} does not match signer's public key",
+        solo_machine_client_id
+    );
+    ensure!(
+        consensus_state.diversifier == chain.config.diversifier,
+        "diversifier of solo machine client with id {} ({}) does not match diversifier of chain ({})",
+        solo_machine_client_id,
+        consensus_state.diversifier,
+        chain.config.diversifier
+    );
+
+    let consensus_timestamp = i64::try_from(consensus_state.timestamp)
+        .ok()
+        .and_then(|timestamp| Utc.timestamp_opt(timestamp, 0).single())
+        .ok_or_else(|| {
+            anyhow!(
+                "invalid consensus timestamp of solo machine client with id {}: {}",
+                solo_machine_client_id,
+                consensus_state.timestamp
+            )
+        })?;
+
+    Ok((client_state.sequence, consensus_timestamp))
+}
+
+/// Queries value at given path in IBC store of IBC enabled chain, verifies it using ICS-23 proof against the consensus
+/// state of given tendermint client at given height and decodes it
+async fn query_proven_ibc_store<'e, C, T>(
+    executor: impl Executor<'e, Database = Db>,
+    rpc_client: &C,
+    tendermint_client_id: &ClientId,
+    path: Vec<u8>,
+    proof_height: &Height,
+) -> Result<T>
+where
+    C: Client + Send + Sync,
+    T: Message + Default,
+{
+    let value = query_proven_ibc_store_raw(
+        executor,
+        rpc_client,
+        tendermint_client_id,
+        path,
+        proof_height,
+    )
+    .await?;
+
+    T::decode(value.as_slice()).context("unable to decode value from IBC store")
+}
+
+/// Queries raw value at given path in IBC store of IBC enabled chain and verifies it using ICS-23 proof against the
+/// consensus state of given tendermint client at given height. Fails if there is no value at given path.
+async fn query_proven_ibc_store_raw<'e, C>(
+    executor: impl Executor<'e, Database = Db>,
+    rpc_client: &C,
+    tendermint_client_id: &ClientId,
+    path: Vec<u8>,
+    proof_height: &Height,
+) -> Result<Vec<u8>>
+where
+    C: Client + Send + Sync,
+{
+    let consensus_state =
+        ibc_handler::get_tendermint_consensus_state(executor, tendermint_client_id, proof_height)
+            .await?
+            .ok_or_else(|| {
+                anyhow!(
+                    "consensus state for tendermint client with id {} at height {} not found",
+                    tendermint_client_id,
+                    proof_height.to_string()
+                )
+            })?;
+
+    let root = consensus_state
+        .root
+        .ok_or_else(|| anyhow!("commitment root cannot be absent in consensus state"))?
+        .hash;
+
+    // App hash in header at height `h` commits to the state after executing block at height `h - 1`
+    let query_height = proof_height
+        .revision_height
+        .checked_sub(1)
+        .ok_or_else(|| anyhow!("invalid proof height: {}", proof_height.to_string()))?;
+
+    let response = rpc_client
+        .abci_query(
+            Some(format!("store/{}/key", IBC_STORE_KEY).parse().unwrap()),
+            path.clone(),
+            Some(
+                query_height
+                    .try_into()
+                    .map_err(|e| anyhow!("invalid block height: {}", e))?,
+            ),
+            true,
+        )
+        .await?;
+
+    ensure!(
+        response.code.is_ok(),
+        "unable to query IBC store of IBC enabled chain: {}",
+        response.log
+    );
+    ensure!(
+        !response.value.is_empty(),
+        "value at path {} not found in IBC store of IBC enabled chain at height {}",
+        String::from_utf8_lossy(&path),
+        query_height
+    );
+
+    let proofs = response
+        .proof
+        .ok_or_else(|| anyhow!("proof of value is missing in query response"))?
+        .ops
+        .into_iter()
+        .map(|op| CommitmentProof::decode(op.data.as_slice()))
+        .collect::<Result<Vec<_>, _>>()
+        .context("unable to decode proof of value")?;
+
+    verify_membership(
+        &proof_specs(),
+        &root,
+        &proofs,
+        &[IBC_STORE_KEY.as_bytes(), &path],
+        &response.value,
+    )
+    .with_context(|| {
+        format!(
+            "unable to verify proof of value at path {} in IBC store",
+            String::from_utf8_lossy(&path)
+        )
+    })?;
+
+    Ok(response.value)
+}
+
+/// Broadcasts transaction to IBC enabled chain using given broadcast mode and returns its response after it is included
+/// in a block (for `sync` and `async` modes, the response is built from transaction query)
+async fn broadcast_tx<C>(
+    rpc_client: &C,
+    broadcast_mode: BroadcastMode,
+    tx: &TxRaw,
+) -> Result<TxCommitResponse>
+where
+    C: Client + Send + Sync,
+{
+    let tx = proto_encode(tx)?;
+
+    let (response, timeout) = match broadcast_mode {
+        BroadcastMode::Commit => return Ok(rpc_client.broadcast_tx_commit(tx.into()).await?),
+        BroadcastMode::Sync { timeout } => {
+            (rpc_client.broadcast_tx_sync(tx.into()).await?, timeout)
+        }
+        BroadcastMode::Async { timeout } => {
+            // `broadcast_tx_async` does not wait for `CheckTx`, so, its response code is always zero
+            let response = rpc_client.broadcast_tx_async(tx.into()).await?;
+
+            (
+                SyncResponse {
+                    code: response.code,
+                    data: response.data,
+                    log: response.log,
+                    hash: response.hash,
+                },
+                timeout,
+            )
+        }
+    };
+
+    ensure!(
+        response.code.is_ok(),
+        "check_tx response contains error code: {}",
+        response.log
+    );
+
+    wait_for_tx(rpc_client, response.hash, timeout).await
+}
+
+/// Polls IBC enabled chain for transaction with given hash until it is included in a block or timeout passes
+async fn wait_for_tx<C>(
+    rpc_client: &C,
+    hash: TransactionHash,
+    timeout: Duration,
+) -> Result<TxCommitResponse>
+where
+    C: Client + Send + Sync,
+{
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        // Transaction may still be included in a block if querying it fails, so, its outcome is unknown
+        let response = query_tx(rpc_client, hash).await.map_err(|err| {
+            err.context(UnknownTxOutcome {
+                transaction_hash: hash.to_string(),
+                timeout,
+            })
+        })?;
+
+        if let Some(response) = response {
+            return Ok(response);
+        }
+
+        if Instant::now() >= deadline {
+            return Err(UnknownTxOutcome {
+                transaction_hash: hash.to_string(),
+                timeout,
+            }
+            .into());
+        }
+
+        sleep(TX_POLL_INTERVAL).await;
+    }
+}
+
+/// Queries transaction with given hash from IBC enabled chain (returns `None` if it is not included in a block yet)
+async fn query_tx<C>(rpc_client: &C, hash: TransactionHash) -> Result<Option<TxCommitResponse>>
+where
+    C: Client + Send + Sync,
+{
+    let response = match rpc_client.tx(hash, false).await {
+        Ok(response) => response,
+        Err(err) if is_tx_not_found(&err) => return Ok(None),
+        Err(err) => {
+            return Err(Error::new(err).context(format!(
+                "unable to query transaction {} from IBC enabled chain",
+                hash
+            )))
+        }
+    };
+
+    let deliver_tx = response.tx_result;
+
+    // Only the transactions which passed `CheckTx` can be included in a block
+    Ok(Some(TxCommitResponse {
+        check_tx: TxResult::default(),
+        deliver_tx: TxResult {
+            code: deliver_tx.code,
+            data: Some(deliver_tx.data),
+            log: deliver_tx.log,
+            info: deliver_tx.info,
+            gas_wanted: deliver_tx.gas_wanted,
+            gas_used: deliver_tx.gas_used,
+            events: deliver_tx.events,
+            codespace: deliver_tx.codespace,
+        },
+        hash: response.hash,
+        height: response.height,
+    }))
+}
+
+/// Returns `true` if given error returned by `tx` query denotes that the transaction is not found (tendermint returns
+/// an internal error with `tx (<hash>) not found` as its data until the transaction is included in a block)
+fn is_tx_not_found(error: &RpcError) -> bool {
+    error.code() == RpcErrorCode::InternalError
+        && matches!(error.data(), Some(data) if data.contains("not found"))
+}
+
+/// Commits given database transaction if given error is caused by a transaction whose outcome is unknown (see
+/// [`UnknownTxOutcome`]), so that the changes made to the state of solo machine for it (e.g., incremented sequences)
+/// are not reused by future transactions. The transaction is also recorded as pending (along with given operation) so
+/// that it can be reconciled later (see [`IbcService::reconcile`]). Otherwise, database transaction is rolled back.
+/// Returns the given error.
+async fn retain_on_unknown_outcome(
+    mut transaction: Transaction<'_, Db>,
+    error: Error,
+    chain_id: &ChainId,
+    request_id: Option<&str>,
+    operation: PendingOperation,
+) -> Error {
+    let transaction_hash = match error.downcast_ref::<UnknownTxOutcome>() {
+        None => return error,
+        Some(unknown_outcome) => unknown_outcome.transaction_hash.clone(),
+    };
+
+    if let Err(add_error) = pending_transaction::add_pending_transaction(
+        &mut transaction,
+        &transaction_hash,
+        chain_id,
+        request_id,
+        &operation,
+    )
+    .await
+    {
+        return error.context(format!(
+            "unable to record pending transaction after unknown outcome of transaction: {}",
+            add_error
+        ));
+    }
+
+    match transaction.commit().await {
+        Ok(()) => error,
+        Err(commit_error) => error.context(format!(
+            "unable to commit database transaction after unknown outcome of transaction: {}",
+            commit_error
+        )),
+    }
+}
+
 fn ensure_response_success(response: &TxCommitResponse) -> Result<String> {
     ensure!(
         response.check_tx.code.is_ok(),
@@ -1,8 +1,13 @@
 //! Utilities for signing transactions
+pub(crate) mod guard;
+pub mod multisig;
+pub mod offline;
+
 use std::{fmt, str::FromStr, sync::Arc};
 
 use anyhow::{anyhow, Error, Result};
 use async_trait::async_trait;
+use cosmos_sdk_proto::cosmos::crypto::multisig::v1beta1::CompactBitArray;
 
 use crate::cosmos::crypto::PublicKey;
 
@@ -40,9 +45,9 @@ impl FromStr for AddressAlgo {
 }
 
 /// Type of message given to a signer
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum Message<'a> {
-    /// [cosmos_sdk_proto::ibc::lightclients::solomachine::v1::SignBytes]
+    /// `SignBytes` of solo machine client protocol (of the version configured for chain)
     SignBytes(&'a [u8]),
     /// [cosmos_sdk_proto::cosmos::tx::v1beta1::SignDoc]
     SignDoc(&'a [u8]),
@@ -107,18 +112,78 @@ impl<T: ToPublicKey + ?Sized> ToPublicKey for Arc<T> {
     }
 }
 
+/// Signatures produced by members of a multisig signer
+#[derive(Debug, Clone)]
+pub struct MultiSignature {
+    /// Bit array denoting the members (in order of public keys in multisig public key) who signed the message
+    pub bit_array: CompactBitArray,
+    /// Signatures of members (in order of bits set in `bit_array`)
+    pub signatures: Vec<Vec<u8>>,
+}
+
+/// Error returned by [Signer::multi_sign] when some of the members requested to sign a message (using `signers`) fail
+/// to do so. A new set of members excluding the failed ones can be requested to sign the message again.
+#[derive(Debug, Clone)]
+pub struct UnavailableMembers {
+    /// Indices of members (in order of public keys in multisig public key) who failed to sign the message
+    pub members: Vec<usize>,
+    /// Errors returned by the failed members (in order of `members`)
+    pub errors: Vec<String>,
+}
+
+impl fmt::Display for UnavailableMembers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "members of multisig signer failed to sign message: ")?;
+
+        for (i, (member, error)) in self.members.iter().zip(self.errors.iter()).enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "member {}: {}", member, error)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for UnavailableMembers {}
+
 /// This trait must be implemented by all the transaction signers (e.g. mnemonic, ledger, etc.)
 #[async_trait]
 pub trait Signer: ToPublicKey + Send + Sync {
     /// Signs the given message
     async fn sign(&self, request_id: Option<&str>, message: Message<'_>) -> Result<Vec<u8>>;
+
+    /// Signs the given message by members of a multisig signer. If `signers` is provided, exactly those members sign
+    /// the message (fails with [UnavailableMembers] if any of them fails to sign), otherwise, any `threshold` number of
+    /// members can sign it. This is only implemented by multisig signers (i.e., signers with [PublicKey::Multisig]
+    /// public key).
+    async fn multi_sign(
+        &self,
+        _request_id: Option<&str>,
+        _message: Message<'_>,
+        _signers: Option<&CompactBitArray>,
+    ) -> Result<MultiSignature> {
+        Err(anyhow!("signer does not support multi signatures"))
+    }
 }
 
 #[async_trait]
+#[allow(clippy::needless_lifetimes)]
 impl<T: Signer> Signer for &T {
     async fn sign(&self, request_id: Option<&str>, message: Message<'_>) -> Result<Vec<u8>> {
         (*self).sign(request_id, message).await
     }
+
+    async fn multi_sign(
+        &self,
+        request_id: Option<&str>,
+        message: Message<'_>,
+        signers: Option<&CompactBitArray>,
+    ) -> Result<MultiSignature> {
+        (*self).multi_sign(request_id, message, signers).await
+    }
 }
 
 #[async_trait]
@@ -126,10 +191,25 @@ impl<T: Signer + ?Sized> Signer for Arc<T> {
     async fn sign(&self, request_id: Option<&str>, message: Message<'_>) -> Result<Vec<u8>> {
         (**self).sign(request_id, message).await
     }
+
+    async fn multi_sign(
+        &self,
+        request_id: Option<&str>,
+        message: Message<'_>,
+        signers: Option<&CompactBitArray>,
+    ) -> Result<MultiSignature> {
+        (**self).multi_sign(request_id, message, signers).await
+    }
 }
 
 /// Trait to register a signer
 pub trait SignerRegistrar {
     /// Registers a new signer
     fn register(&mut self, signer: Arc<dyn Signer>);
+
+    /// Returns prefix of environment variables from which signer should read its arguments (e.g., `SOLO_FEE_PAYER_`
+    /// when loading a fee payer signer)
+    fn env_prefix(&self) -> &str {
+        "SOLO_"
+    }
 }
@@ -0,0 +1,121 @@
+//! Persistent sign-ledger guard (refuses to sign conflicting `SignBytes` for the same sequence of solo machine, which
+//! would allow anyone to freeze solo machine client by submitting misbehaviour)
+use std::fmt::Display;
+
+use anyhow::{anyhow, ensure, Context, Result};
+use sha2::{Digest, Sha256};
+use sqlx::Transaction;
+
+use crate::{
+    ibc::{
+        client::ics06_solo_machine::SoloMachineVersion,
+        core::ics24_host::identifier::{ChainId, ClientId},
+    },
+    model::{sign_ledger, Chain},
+    Db,
+};
+
+/// Reserves given protobuf encoded `SignBytes` in sign-ledger of given solo machine client (on given chain) within given
+/// database transaction. Must be called before the `SignBytes` are signed (or before a signature produced offline is
+/// used) and the transaction must be committed before the signature is broadcasted. Reserving the same `SignBytes`
+/// again does nothing and an error is returned if different `SignBytes` were already reserved for the same sequence.
+///
+/// The entry is inserted on the primary key of sign-ledger, so, a concurrent reservation of the same sequence waits
+/// for this transaction to finish and is then compared with the committed entry.
+pub(crate) async fn reserve_sign_bytes(
+    transaction: &mut Transaction<'_, Db>,
+    chain: &Chain,
+    client_id: &ClientId,
+    sign_bytes: &[u8],
+) -> Result<()> {
+    let data = SignedData::new(chain.config.solo_machine_version, sign_bytes)?;
+
+    let added = sign_ledger::add_sign_ledger_entry(
+        &mut *transaction,
+        &chain.id,
+        client_id,
+        data.sequence,
+        data.data_type,
+        &data.data_hash,
+    )
+    .await?;
+
+    if added {
+        return Ok(());
+    }
+
+    let entry =
+        sign_ledger::get_sign_ledger_entry(&mut *transaction, &chain.id, client_id, data.sequence)
+            .await?
+            .ok_or_else(|| {
+                anyhow!(
+                    "sign-ledger entry for sequence {} of {} on {} not found",
+                    data.sequence,
+                    client_id,
+                    chain.id
+                )
+            })?;
+
+    data.ensure_same(
+        &chain.id,
+        client_id,
+        entry.data_type,
+        &entry.data_hash,
+        entry.created_at,
+    )
+}
+
+/// Data in `SignBytes` which is recorded in sign-ledger
+struct SignedData {
+    sequence: u64,
+    data_type: i32,
+    data_hash: String,
+}
+
+impl SignedData {
+    /// Decodes given protobuf encoded `SignBytes` of given solo machine client protocol version. In v3, there is no
+    /// type of data and path is not a part of data, so, length prefixed path is hashed along with data.
+    fn new(version: SoloMachineVersion, sign_bytes: &[u8]) -> Result<Self> {
+        let sign_bytes = version
+            .decode_sign_bytes(sign_bytes)
+            .context("unable to decode sign bytes")?;
+
+        let mut hasher = Sha256::new();
+
+        if version == SoloMachineVersion::V3 {
+            hasher.update((sign_bytes.path.len() as u64).to_be_bytes());
+            hasher.update(&sign_bytes.path);
+        }
+
+        hasher.update(&sign_bytes.data);
+
+        Ok(Self {
+            sequence: sign_bytes.sequence,
+            data_type: sign_bytes.data_type,
+            data_hash: hex::encode_upper(hasher.finalize()),
+        })
+    }
+
+    /// Ensures that this data is the same as the data already signed for the same sequence
+    fn ensure_same(
+        &self,
+        chain_id: &ChainId,
+        client_id: &ClientId,
+        data_type: i32,
+        data_hash: &str,
+        signed_at: impl Display,
+    ) -> Result<()> {
+        ensure!(
+            self.data_type == data_type && self.data_hash == data_hash,
+            "refusing to sign conflicting sign bytes for sequence {} of {} on {} (sign bytes with data type {} and data hash {} were already signed at {})",
+            self.sequence,
+            client_id,
+            chain_id,
+            data_type,
+            data_hash,
+            signed_at
+        );
+
+        Ok(())
+    }
+}
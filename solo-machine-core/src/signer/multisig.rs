@@ -0,0 +1,374 @@
+//! Multisig (k-of-n) signer which collects signatures from its members (i.e., other signers or operators submitting
+//! their signatures to a collection endpoint)
+use std::{
+    collections::BTreeMap,
+    convert::TryFrom,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use cosmos_sdk_proto::cosmos::{
+    crypto::multisig::v1beta1::CompactBitArray,
+    tx::signing::v1beta1::{
+        signature_descriptor::data::{Single as SingleSignatureData, Sum as SignatureData},
+        SignMode,
+    },
+};
+use futures_util::stream::{FuturesUnordered, StreamExt};
+use tokio::sync::oneshot;
+
+use crate::cosmos::{
+    bit_array::BitArray,
+    crypto::{MultisigPublicKey, PublicKey},
+};
+
+use super::{Message, MultiSignature, Signer, ToPublicKey, UnavailableMembers};
+
+/// Multisig signer which signs messages using at least `threshold` number of its members
+pub struct MultisigSigner {
+    threshold: u32,
+    members: Vec<Arc<dyn Signer>>,
+    account_prefix: String,
+}
+
+impl MultisigSigner {
+    /// Creates a new multisig signer (order of members is the order of public keys in multisig public key)
+    pub fn new(
+        threshold: u32,
+        members: Vec<Arc<dyn Signer>>,
+        account_prefix: String,
+    ) -> Result<Self> {
+        ensure!(
+            threshold > 0,
+            "multisig threshold must be greater than zero"
+        );
+        ensure!(
+            usize::try_from(threshold)? <= members.len(),
+            "multisig threshold ({}) cannot be greater than number of members ({})",
+            threshold,
+            members.len()
+        );
+
+        Ok(Self {
+            threshold,
+            members,
+            account_prefix,
+        })
+    }
+}
+
+impl ToPublicKey for MultisigSigner {
+    fn to_public_key(&self) -> Result<PublicKey> {
+        let public_keys = self
+            .members
+            .iter()
+            .map(|member| member.to_public_key())
+            .collect::<Result<_>>()?;
+
+        Ok(PublicKey::Multisig(MultisigPublicKey {
+            threshold: self.threshold,
+            public_keys,
+        }))
+    }
+
+    fn get_account_prefix(&self) -> &str {
+        &self.account_prefix
+    }
+
+    fn to_account_address(&self) -> Result<String> {
+        self.to_public_key()?.account_address(&self.account_prefix)
+    }
+}
+
+#[async_trait]
+impl Signer for MultisigSigner {
+    async fn sign(&self, _: Option<&str>, _: Message<'_>) -> Result<Vec<u8>> {
+        Err(anyhow!("multisig signer can only produce multi signatures"))
+    }
+
+    async fn multi_sign(
+        &self,
+        request_id: Option<&str>,
+        message: Message<'_>,
+        signers: Option<&CompactBitArray>,
+    ) -> Result<MultiSignature> {
+        let required = match signers {
+            None => usize::try_from(self.threshold)?,
+            Some(signers) => {
+                ensure!(
+                    signers.len() == self.members.len(),
+                    "bit array size ({}) is not equal to number of members of multisig signer ({})",
+                    signers.len(),
+                    self.members.len()
+                );
+
+                signers.num_true_bits_before(signers.len())
+            }
+        };
+
+        // All the members are asked to sign the message concurrently and signing stops as soon as required number of
+        // signatures are collected
+        let mut pending = self
+            .members
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| signers.map(|signers| signers.get(*index)).unwrap_or(true))
+            .map(|(index, member)| async move { (index, member.sign(request_id, message).await) })
+            .collect::<FuturesUnordered<_>>();
+
+        let mut signatures = BTreeMap::new();
+        let mut errors = Vec::new();
+
+        while let Some((index, result)) = pending.next().await {
+            match result {
+                Ok(signature) => {
+                    signatures.insert(index, signature);
+
+                    if signatures.len() == required {
+                        break;
+                    }
+                }
+                Err(err) => errors.push((index, err)),
+            }
+        }
+
+        // When specific members are requested to sign the message, failed members are returned so that the message can
+        // be signed again by other members
+        if signers.is_some() && !errors.is_empty() {
+            let (members, errors) = errors
+                .into_iter()
+                .map(|(index, err)| (index, format!("{:#}", err)))
+                .unzip();
+
+            return Err(UnavailableMembers { members, errors }.into());
+        }
+
+        ensure!(
+            signatures.len() == required,
+            "unable to collect enough signatures from members of multisig signer (collected: {}, required: {}): {}",
+            signatures.len(),
+            required,
+            errors
+                .iter()
+                .map(|(index, err)| format!("member {}: {}", index, err))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        let mut bit_array = CompactBitArray::new(self.members.len());
+
+        for index in signatures.keys() {
+            bit_array.set(*index, true);
+        }
+
+        Ok(MultiSignature {
+            bit_array,
+            signatures: signatures.into_values().collect(),
+        })
+    }
+}
+
+/// Request for signature of an operator (i.e., a member of multisig signer who submits signatures to
+/// [SignatureCollector])
+#[derive(Debug, Clone)]
+pub struct SignatureRequest {
+    /// ID of signature request (used when submitting signature)
+    pub id: u64,
+    /// Public key of operator who must sign the message
+    pub public_key: PublicKey,
+    /// Request ID for tracking purposes
+    pub request_id: Option<String>,
+    /// Type of message (`sign-bytes` or `sign-doc`)
+    pub message_type: &'static str,
+    /// Message to be signed
+    pub message: Vec<u8>,
+    /// Time at which signature was requested
+    pub created_at: DateTime<Utc>,
+}
+
+/// Collection endpoint for signatures of operators (operators poll pending signature requests and submit their
+/// signatures back)
+#[derive(Clone, Default)]
+pub struct SignatureCollector {
+    state: Arc<Mutex<CollectorState>>,
+}
+
+#[derive(Default)]
+struct CollectorState {
+    next_id: u64,
+    pending: BTreeMap<u64, PendingSignature>,
+}
+
+struct PendingSignature {
+    request: SignatureRequest,
+    sender: oneshot::Sender<Vec<u8>>,
+}
+
+impl SignatureCollector {
+    /// Creates a new signature collector
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns all the pending signature requests (only for given operator if its hex encoded public key is provided)
+    pub fn pending_requests(&self, public_key: Option<&str>) -> Vec<SignatureRequest> {
+        self.state()
+            .pending
+            .values()
+            .map(|pending| &pending.request)
+            .filter(|request| {
+                public_key
+                    .map(|public_key| request.public_key.encode().eq_ignore_ascii_case(public_key))
+                    .unwrap_or(true)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Submits signature of an operator for given signature request (signature is verified against operator's public
+    /// key before accepting it)
+    pub fn submit(&self, id: u64, signature: Vec<u8>) -> Result<()> {
+        let mut state = self.state();
+
+        let request = &state
+            .pending
+            .get(&id)
+            .ok_or_else(|| anyhow!("signature request with id {} does not exist", id))?
+            .request;
+
+        let signature_data = SignatureData::Single(SingleSignatureData {
+            signature: signature.clone(),
+            mode: SignMode::Unspecified.into(),
+        });
+
+        request
+            .public_key
+            .verify_signature(&request.message, &signature_data)
+            .context("invalid signature for signature request")?;
+
+        let pending = state.pending.remove(&id).unwrap();
+
+        pending
+            .sender
+            .send(signature)
+            .map_err(|_| anyhow!("signature request with id {} is no longer pending", id))
+    }
+
+    /// Requests signature from operator with given public key and waits for it until given timeout
+    async fn collect(
+        &self,
+        public_key: &PublicKey,
+        request_id: Option<&str>,
+        message: Message<'_>,
+        timeout: Duration,
+    ) -> Result<Vec<u8>> {
+        let (sender, receiver) = oneshot::channel();
+
+        let id = {
+            let mut state = self.state();
+
+            let id = state.next_id;
+            state.next_id += 1;
+
+            let request = SignatureRequest {
+                id,
+                public_key: public_key.clone(),
+                request_id: request_id.map(ToOwned::to_owned),
+                message_type: message.message_type(),
+                message: message.as_ref().to_vec(),
+                created_at: Utc::now(),
+            };
+
+            state
+                .pending
+                .insert(id, PendingSignature { request, sender });
+
+            id
+        };
+
+        // Removes signature request when it times out or gets cancelled (e.g., when other members of multisig signer
+        // already produced required number of signatures)
+        let _guard = PendingGuard {
+            collector: self,
+            id,
+        };
+
+        match tokio::time::timeout(timeout, receiver).await {
+            Ok(Ok(signature)) => Ok(signature),
+            Ok(Err(_)) => bail!("signature request with id {} was cancelled", id),
+            Err(_) => bail!(
+                "timed out waiting for signature of operator {} (signature request id: {})",
+                public_key.encode(),
+                id
+            ),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, CollectorState> {
+        self.state
+            .lock()
+            .expect("signature collector state lock poisoned")
+    }
+}
+
+struct PendingGuard<'a> {
+    collector: &'a SignatureCollector,
+    id: u64,
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        self.collector.state().pending.remove(&self.id);
+    }
+}
+
+/// Member of multisig signer whose signatures are submitted by an operator to [SignatureCollector]
+pub struct OperatorSigner {
+    public_key: PublicKey,
+    account_prefix: String,
+    collector: SignatureCollector,
+    timeout: Duration,
+}
+
+impl OperatorSigner {
+    /// Creates a new operator signer (signature requests fail if operator does not submit signature within `timeout`)
+    pub fn new(
+        public_key: PublicKey,
+        account_prefix: String,
+        collector: SignatureCollector,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            public_key,
+            account_prefix,
+            collector,
+            timeout,
+        }
+    }
+}
+
+impl ToPublicKey for OperatorSigner {
+    fn to_public_key(&self) -> Result<PublicKey> {
+        Ok(self.public_key.clone())
+    }
+
+    fn get_account_prefix(&self) -> &str {
+        &self.account_prefix
+    }
+
+    fn to_account_address(&self) -> Result<String> {
+        self.public_key.account_address(&self.account_prefix)
+    }
+}
+
+#[async_trait]
+impl Signer for OperatorSigner {
+    async fn sign(&self, request_id: Option<&str>, message: Message<'_>) -> Result<Vec<u8>> {
+        self.collector
+            .collect(&self.public_key, request_id, message, self.timeout)
+            .await
+    }
+}
@@ -0,0 +1,80 @@
+//! Types used for signing mints offline (i.e., in generate-only mode)
+//!
+//! Both, `SignBytes` of solo machine proof and `SignDoc` of the transaction sent to IBC enabled chain, are signed
+//! offline. `SignDoc` contains the proof, so, it is built only after `SignBytes` are signed.
+use serde::{Deserialize, Serialize};
+
+use crate::ibc::{
+    apps::transfer::{amount::Amount, denom::Denom},
+    core::ics24_host::identifier::ChainId,
+};
+
+/// `SignBytes` of a solo machine proof which must be signed offline (along with the context needed to verify them)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignBytesPayload {
+    /// Sequence of solo machine
+    pub sequence: u64,
+    /// Consensus timestamp of solo machine (unix timestamp in seconds)
+    pub timestamp: u64,
+    /// Diversifier of solo machine
+    pub diversifier: String,
+    /// Type of data in sign bytes (e.g., `packet_commitment`)
+    pub data_type: String,
+    /// Protobuf encoded `SignBytes` which must be signed (same as [`Message::SignBytes`](super::Message::SignBytes))
+    #[serde(with = "hex::serde")]
+    pub sign_bytes: Vec<u8>,
+    /// Hex encoded signature over `sign_bytes` (filled by offline signer)
+    #[serde(default)]
+    pub signature: Option<String>,
+}
+
+/// `SignDoc` of the transaction sent to IBC enabled chain which must be signed offline (along with the context needed
+/// to verify it)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignDocPayload {
+    /// Account number of signer on IBC enabled chain
+    pub account_number: u64,
+    /// Account sequence of signer on IBC enabled chain
+    pub sequence: u64,
+    /// Fee of transaction (e.g., `5000stake`)
+    pub fee: String,
+    /// Gas limit of transaction
+    pub gas_limit: u64,
+    /// Protobuf encoded `TxBody` of transaction
+    #[serde(with = "hex::serde")]
+    pub body_bytes: Vec<u8>,
+    /// Protobuf encoded `AuthInfo` of transaction
+    #[serde(with = "hex::serde")]
+    pub auth_info_bytes: Vec<u8>,
+    /// Protobuf encoded `SignDoc` which must be signed (same as [`Message::SignDoc`](super::Message::SignDoc))
+    #[serde(with = "hex::serde")]
+    pub sign_doc: Vec<u8>,
+    /// Hex encoded signature over `sign_doc` (filled by offline signer)
+    #[serde(default)]
+    pub signature: Option<String>,
+}
+
+/// Mint generated in generate-only mode (which can be submitted once its sign bytes and sign doc are signed offline)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnsignedMint {
+    /// Chain ID of IBC enabled chain
+    pub chain_id: ChainId,
+    /// Request ID for tracking purposes
+    pub request_id: Option<String>,
+    /// Address of sender on solo machine
+    pub sender: String,
+    /// Address of receiver on IBC enabled chain
+    pub receiver: String,
+    /// Amount of tokens
+    pub amount: Amount,
+    /// Denom of tokens (on solo machine)
+    pub denom: Denom,
+    /// Protobuf encoded packet sent to IBC enabled chain
+    #[serde(with = "hex::serde")]
+    pub packet: Vec<u8>,
+    /// Sign bytes of packet commitment proof
+    pub sign_bytes: SignBytesPayload,
+    /// Sign doc of transaction sent to IBC enabled chain (added once sign bytes are signed)
+    #[serde(default)]
+    pub sign_doc: Option<SignDocPayload>,
+}
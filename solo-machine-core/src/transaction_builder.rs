@@ -1,37 +1,28 @@
-use std::convert::TryInto;
-
-#[cfg(feature = "solomachine-v2")]
-use crate::proto::ibc::lightclients::solomachine::v2::{
-    ChannelStateData, ClientState as SoloMachineClientState, ClientStateData, ConnectionStateData,
-    ConsensusState as SoloMachineConsensusState, ConsensusStateData, DataType,
-    Header as SoloMachineHeader, HeaderData, PacketAcknowledgementData, PacketCommitmentData,
-    SignBytes, TimestampedSignatureData,
-};
-use anyhow::{anyhow, bail, ensure, Context, Result};
-use chrono::{DateTime, Utc};
-#[cfg(not(feature = "solomachine-v2"))]
-use cosmos_sdk_proto::ibc::lightclients::solomachine::v1::{
-    ChannelStateData, ClientState as SoloMachineClientState, ClientStateData, ConnectionStateData,
-    ConsensusState as SoloMachineConsensusState, ConsensusStateData, DataType,
-    Header as SoloMachineHeader, HeaderData, PacketAcknowledgementData, PacketCommitmentData,
-    SignBytes, TimestampedSignatureData,
-};
+use std::{cmp::Ordering, collections::BTreeSet, convert::TryInto, sync::Arc};
+
+use anyhow::{anyhow, ensure, Context, Result};
+use chrono::{DateTime, TimeZone, Utc};
 use cosmos_sdk_proto::{
     cosmos::{
         auth::v1beta1::{query_client::QueryClient as AuthQueryClient, QueryAccountRequest},
         base::v1beta1::Coin,
+        crypto::multisig::v1beta1::{CompactBitArray, MultiSignature as RawMultiSignature},
         staking::v1beta1::{query_client::QueryClient as StakingQueryClient, QueryParamsRequest},
         tx::{
             signing::v1beta1::{
                 signature_descriptor::{
-                    data::{Single as SingleSignatureData, Sum as SignatureDataInner},
+                    data::{
+                        Multi as MultiSignatureData, Single as SingleSignatureData,
+                        Sum as SignatureDataInner,
+                    },
                     Data as SignatureData,
                 },
                 SignMode,
             },
             v1beta1::{
-                mode_info::{Single, Sum},
-                AuthInfo, Fee, ModeInfo, SignDoc, SignerInfo, TxBody, TxRaw,
+                mode_info::{Multi, Single, Sum},
+                service_client::ServiceClient as TxServiceClient,
+                AuthInfo, Fee, ModeInfo, SignDoc, SignerInfo, SimulateRequest, Tx, TxBody, TxRaw,
             },
         },
     },
@@ -40,8 +31,8 @@ use cosmos_sdk_proto::{
         core::{
             channel::v1::{
                 Channel, Counterparty as ChannelCounterparty, MsgAcknowledgement,
-                MsgChannelOpenAck, MsgChannelOpenInit, MsgRecvPacket, Order as ChannelOrder,
-                Packet, State as ChannelState,
+                MsgChannelCloseConfirm, MsgChannelOpenAck, MsgChannelOpenInit, MsgRecvPacket,
+                MsgTimeout, Order as ChannelOrder, Packet, State as ChannelState,
             },
             client::v1::{Height, MsgCreateClient, MsgUpdateClient},
             commitment::v1::MerklePrefix,
@@ -57,7 +48,8 @@ use cosmos_sdk_proto::{
     },
 };
 use prost_types::{Any, Duration};
-use serde::{Deserialize, Serialize};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{de, Deserialize, Deserializer, Serialize};
 use serde_json::json;
 use sqlx::{Executor, Transaction};
 use tendermint::block::Header;
@@ -65,36 +57,57 @@ use tendermint_light_client::supervisor::Instance;
 use tendermint_rpc::Client;
 
 use crate::{
-    cosmos::{account::Account, crypto::PublicKey},
+    cosmos::{
+        account::Account,
+        bit_array::BitArray,
+        crypto::{MultisigPublicKey, PublicKey},
+    },
     ibc::{
-        client::ics07_tendermint::consensus_state::IConsensusState,
+        apps::transfer::{amount::Amount, denom::Denom},
+        client::{
+            ics06_solo_machine::{
+                ClientState as SoloMachineClientState, ConsensusState as SoloMachineConsensusState,
+                Header as SoloMachineHeader, SignData,
+            },
+            ics07_tendermint::consensus_state::IConsensusState,
+        },
         core::{
             ics02_client::height::IHeight,
             ics04_channel::packet::IPacket,
             ics23_vector_commitments::proof_specs,
             ics24_host::{
-                identifier::{ChainId, ChannelId, ClientId, ConnectionId, Identifier},
+                identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId},
                 path::{
                     ChannelPath, ClientStatePath, ConnectionPath, ConsensusStatePath,
-                    PacketAcknowledgementPath, PacketCommitmentPath,
+                    PacketAcknowledgementPath, PacketCommitmentPath, PacketReceiptPath,
                 },
             },
         },
     },
-    model::{chain, ibc as ibc_handler, Chain},
+    model::{
+        chain::{
+            connection,
+            solo_machine_client::{self, SoloMachineClient},
+        },
+        ibc as ibc_handler, Chain, ConnectionDetails, GasMode,
+    },
     proto::{proto_encode, AnyConvert},
-    signer::Message,
+    signer::{
+        guard,
+        offline::{SignBytesPayload, SignDocPayload},
+        Message, MultiSignature, UnavailableMembers,
+    },
     Db, Signer, ToPublicKey,
 };
 
-const DEFAULT_TIMEOUT_HEIGHT_OFFSET: u64 = 10;
-
 /// Builds a transaction to create a solo machine client on IBC enabled chain
 pub async fn msg_create_solo_machine_client(
     signer: impl Signer,
+    fee_payer: Option<&Arc<dyn Signer>>,
     chain: &Chain,
     memo: String,
 ) -> Result<TxRaw> {
+    let version = chain.config.solo_machine_version;
     let any_public_key = signer.to_public_key()?.to_any()?;
 
     let consensus_state = SoloMachineConsensusState {
@@ -102,18 +115,14 @@ pub async fn msg_create_solo_machine_client(
         diversifier: chain.config.diversifier.clone(),
         timestamp: to_u64_timestamp(chain.consensus_timestamp)?,
     };
-    let any_consensus_state = consensus_state.to_any()?;
+    let any_consensus_state = version.encode_consensus_state(&consensus_state)?;
 
     let client_state = SoloMachineClientState {
-        sequence: chain.sequence.into(),
-        #[cfg(not(feature = "solomachine-v2"))]
-        frozen_sequence: 0,
-        #[cfg(feature = "solomachine-v2")]
+        sequence: solo_machine_client::INITIAL_SEQUENCE,
         is_frozen: false,
         consensus_state: Some(consensus_state),
-        allow_update_after_proposal: true,
     };
-    let any_client_state = client_state.to_any()?;
+    let any_client_state = version.encode_client_state(&client_state)?;
 
     let message = MsgCreateClient {
         client_state: Some(any_client_state),
@@ -121,65 +130,75 @@ pub async fn msg_create_solo_machine_client(
         signer: signer.to_account_address()?,
     };
 
-    build(signer, chain, &[message], memo, None).await
+    build(signer, fee_payer, chain, &[message], memo, None).await
 }
 
-/// Builds a transaction to update solo machine client on IBC enabled chain
-pub async fn msg_update_solo_machine_client<'e>(
-    executor: impl Executor<'e, Database = Db>,
+/// Builds a transaction to update given solo machine clients on IBC enabled chain with a new public key and/or
+/// diversifier (current ones are retained if they're not provided). Header of each client is signed using current
+/// diversifier and sequence of that client.
+#[allow(clippy::too_many_arguments)]
+pub async fn msg_update_solo_machine_client(
+    transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
-    chain: &mut Chain,
+    fee_payer: Option<&Arc<dyn Signer>>,
+    chain: &Chain,
+    solo_machine_client_ids: &[ClientId],
     new_public_key: Option<&PublicKey>,
+    new_diversifier: Option<&str>,
     memo: String,
 ) -> Result<TxRaw> {
-    if chain.connection_details.is_none() {
-        bail!(
-            "connection details not found for chain with id {}",
-            chain.id
-        );
-    }
-
-    let sequence = chain.sequence.into();
+    ensure!(
+        !solo_machine_client_ids.is_empty(),
+        "no solo machine client to update on chain with id {}",
+        chain.id
+    );
 
     let any_public_key = match new_public_key {
         Some(new_public_key) => new_public_key.to_any()?,
         None => signer.to_public_key()?.to_any()?,
     };
 
-    let signature = get_header_proof(
-        &signer,
-        chain,
-        Some(any_public_key.clone()),
-        chain.config.diversifier.clone(),
-    )
-    .await?;
+    let new_diversifier = new_diversifier
+        .unwrap_or(&chain.config.diversifier)
+        .to_owned();
 
-    *chain = chain::increment_sequence(executor, &chain.id).await?;
+    let mut messages = Vec::with_capacity(solo_machine_client_ids.len());
 
-    let header = SoloMachineHeader {
-        sequence,
-        timestamp: to_u64_timestamp(chain.consensus_timestamp)?,
-        signature,
-        new_public_key: Some(any_public_key),
-        new_diversifier: chain.config.diversifier.clone(),
-    };
+    for solo_machine_client_id in solo_machine_client_ids {
+        let client =
+            get_solo_machine_client(&mut *transaction, chain, solo_machine_client_id).await?;
 
-    let any_header = header.to_any()?;
-
-    let connection_details = chain.connection_details.as_ref().ok_or_else(|| {
-        anyhow!(
-            "connection details not found for chain with id {}",
-            chain.id
+        let signature = get_header_proof(
+            &mut *transaction,
+            &signer,
+            chain,
+            &client,
+            Some(any_public_key.clone()),
+            new_diversifier.clone(),
         )
-    })?;
+        .await?;
 
-    let message = MsgUpdateClient {
-        client_id: connection_details.solo_machine_client_id.to_string(),
-        header: Some(any_header),
-        signer: signer.to_account_address()?,
-    };
+        solo_machine_client::increment_sequence(&mut *transaction, &chain.id, &client.client_id)
+            .await?;
 
-    build(signer, chain, &[message], memo, None).await
+        let header = SoloMachineHeader {
+            sequence: client.sequence,
+            timestamp: to_u64_timestamp(client.consensus_timestamp)?,
+            signature,
+            new_public_key: Some(any_public_key.clone()),
+            new_diversifier: new_diversifier.clone(),
+        };
+
+        let any_header = chain.config.solo_machine_version.encode_header(&header)?;
+
+        messages.push(MsgUpdateClient {
+            client_id: client.client_id.to_string(),
+            header: Some(any_header),
+            signer: signer.to_account_address()?,
+        });
+    }
+
+    build(signer, fee_payer, chain, &messages, memo, None).await
 }
 
 /// Builds a transaction to create a tendermint client on IBC enabled solo machine
@@ -215,8 +234,41 @@ pub async fn msg_create_tendermint_client(
     Ok((client_state, consensus_state))
 }
 
+/// Builds new state of given tendermint client on IBC enabled solo machine using the latest header verified by light
+/// client (fails if the latest verified height is not greater than current height of client)
+pub fn msg_update_tendermint_client(
+    chain: &Chain,
+    instance: &mut Instance,
+    client_state: &TendermintClientState,
+) -> Result<(TendermintClientState, TendermintConsensusState)> {
+    let current_height = client_state
+        .latest_height
+        .as_ref()
+        .ok_or_else(|| anyhow!("latest height cannot be absent in client state"))?;
+
+    let latest_header = get_latest_header(instance)?;
+    let latest_height = get_block_height(chain, &latest_header);
+
+    ensure!(
+        latest_height.cmp(current_height) == Ordering::Greater,
+        "latest verified height {} is not greater than current height {} of tendermint client",
+        latest_height.to_string(),
+        current_height.to_string(),
+    );
+
+    let client_state = TendermintClientState {
+        latest_height: Some(latest_height),
+        ..client_state.clone()
+    };
+
+    let consensus_state = TendermintConsensusState::from_block_header(latest_header);
+
+    Ok((client_state, consensus_state))
+}
+
 pub async fn msg_connection_open_init(
     signer: impl Signer,
+    fee_payer: Option<&Arc<dyn Signer>>,
     chain: &Chain,
     solo_machine_client_id: &ClientId,
     tendermint_client_id: &ClientId,
@@ -239,13 +291,16 @@ pub async fn msg_connection_open_init(
         signer: signer.to_account_address()?,
     };
 
-    build(signer, chain, &[message], memo, None).await
+    build(signer, fee_payer, chain, &[message], memo, None).await
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn msg_connection_open_ack(
     transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
-    chain: &mut Chain,
+    fee_payer: Option<&Arc<dyn Signer>>,
+    chain: &Chain,
+    solo_machine_client_id: &ClientId,
     solo_machine_connection_id: &ConnectionId,
     tendermint_client_id: &ClientId,
     tendermint_connection_id: &ConnectionId,
@@ -256,19 +311,44 @@ pub async fn msg_connection_open_ack(
             .await?
             .ok_or_else(|| anyhow!("client for client id {} not found", tendermint_client_id))?;
 
-    let proof_height = Height::new(0, chain.sequence.into());
+    let mut client =
+        get_solo_machine_client(&mut *transaction, chain, solo_machine_client_id).await?;
+    let proof_height = Height::new(0, client.sequence);
 
-    let proof_try =
-        get_connection_proof(&mut *transaction, &signer, chain, tendermint_connection_id).await?;
-    *chain = chain::increment_sequence(&mut *transaction, &chain.id).await?;
+    let proof_try = get_connection_proof(
+        &mut *transaction,
+        &signer,
+        chain,
+        &client,
+        tendermint_connection_id,
+    )
+    .await?;
+    client =
+        solo_machine_client::increment_sequence(&mut *transaction, &chain.id, &client.client_id)
+            .await?;
 
-    let proof_client =
-        get_client_proof(&mut *transaction, &signer, chain, tendermint_client_id).await?;
-    *chain = chain::increment_sequence(&mut *transaction, &chain.id).await?;
+    let proof_client = get_client_proof(
+        &mut *transaction,
+        &signer,
+        chain,
+        &client,
+        tendermint_client_id,
+    )
+    .await?;
+    client =
+        solo_machine_client::increment_sequence(&mut *transaction, &chain.id, &client.client_id)
+            .await?;
 
-    let proof_consensus =
-        get_consensus_proof(&mut *transaction, &signer, chain, tendermint_client_id).await?;
-    *chain = chain::increment_sequence(&mut *transaction, &chain.id).await?;
+    let proof_consensus = get_consensus_proof(
+        &mut *transaction,
+        &signer,
+        chain,
+        &client,
+        tendermint_client_id,
+    )
+    .await?;
+    solo_machine_client::increment_sequence(&mut *transaction, &chain.id, &client.client_id)
+        .await?;
 
     let message = MsgConnectionOpenAck {
         connection_id: solo_machine_connection_id.to_string(),
@@ -286,151 +366,426 @@ pub async fn msg_connection_open_ack(
         signer: signer.to_account_address()?,
     };
 
-    build(signer, chain, &[message], memo, None).await
+    build(signer, fee_payer, chain, &[message], memo, None).await
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn msg_channel_open_init(
     signer: impl Signer,
+    fee_payer: Option<&Arc<dyn Signer>>,
     chain: &Chain,
+    port_id: &PortId,
+    ordering: ChannelOrder,
+    version: &str,
     solo_machine_connection_id: &ConnectionId,
     memo: String,
 ) -> Result<TxRaw> {
     let message = MsgChannelOpenInit {
-        port_id: chain.config.port_id.to_string(),
+        port_id: port_id.to_string(),
         channel: Some(Channel {
             state: ChannelState::Init.into(),
-            ordering: ChannelOrder::Unordered.into(),
+            ordering: ordering.into(),
             counterparty: Some(ChannelCounterparty {
-                port_id: chain.config.port_id.to_string(),
+                port_id: port_id.to_string(),
                 channel_id: "".to_string(),
             }),
             connection_hops: vec![solo_machine_connection_id.to_string()],
-            version: "ics20-1".to_string(),
+            version: version.to_string(),
         }),
         signer: signer.to_account_address()?,
     };
 
-    build(signer, chain, &[message], memo, None).await
+    build(signer, fee_payer, chain, &[message], memo, None).await
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn msg_channel_open_ack(
     transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
-    chain: &mut Chain,
+    fee_payer: Option<&Arc<dyn Signer>>,
+    chain: &Chain,
+    solo_machine_client_id: &ClientId,
+    port_id: &PortId,
+    version: &str,
     solo_machine_channel_id: &ChannelId,
     tendermint_channel_id: &ChannelId,
     memo: String,
 ) -> Result<TxRaw> {
-    let proof_height = Height::new(0, chain.sequence.into());
+    let client = get_solo_machine_client(&mut *transaction, chain, solo_machine_client_id).await?;
+    let proof_height = Height::new(0, client.sequence);
 
-    let proof_try =
-        get_channel_proof(&mut *transaction, &signer, chain, tendermint_channel_id).await?;
-    *chain = chain::increment_sequence(&mut *transaction, &chain.id).await?;
+    let proof_try = get_channel_proof(
+        &mut *transaction,
+        &signer,
+        chain,
+        &client,
+        port_id,
+        tendermint_channel_id,
+    )
+    .await?;
+    solo_machine_client::increment_sequence(&mut *transaction, &chain.id, &client.client_id)
+        .await?;
 
     let message = MsgChannelOpenAck {
-        port_id: chain.config.port_id.to_string(),
+        port_id: port_id.to_string(),
         channel_id: solo_machine_channel_id.to_string(),
         counterparty_channel_id: tendermint_channel_id.to_string(),
-        counterparty_version: "ics20-1".to_string(),
+        counterparty_version: version.to_string(),
         proof_height: Some(proof_height),
         proof_try,
         signer: signer.to_account_address()?,
     };
 
-    build(signer, chain, &[message], memo, None).await
+    build(signer, fee_payer, chain, &[message], memo, None).await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn msg_channel_close_confirm(
+    transaction: &mut Transaction<'_, Db>,
+    signer: impl Signer,
+    fee_payer: Option<&Arc<dyn Signer>>,
+    chain: &Chain,
+    solo_machine_client_id: &ClientId,
+    port_id: &PortId,
+    solo_machine_channel_id: &ChannelId,
+    tendermint_channel_id: &ChannelId,
+    memo: String,
+) -> Result<TxRaw> {
+    let client = get_solo_machine_client(&mut *transaction, chain, solo_machine_client_id).await?;
+    let proof_height = Height::new(0, client.sequence);
+
+    let proof_init = get_channel_proof(
+        &mut *transaction,
+        &signer,
+        chain,
+        &client,
+        port_id,
+        tendermint_channel_id,
+    )
+    .await?;
+    solo_machine_client::increment_sequence(&mut *transaction, &chain.id, &client.client_id)
+        .await?;
+
+    let message = MsgChannelCloseConfirm {
+        port_id: port_id.to_string(),
+        channel_id: solo_machine_channel_id.to_string(),
+        proof_init,
+        proof_height: Some(proof_height),
+        signer: signer.to_account_address()?,
+    };
+
+    build(signer, fee_payer, chain, &[message], memo, None).await
 }
 
 #[allow(clippy::too_many_arguments)]
 pub async fn msg_token_send<C>(
     transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
+    fee_payer: Option<&Arc<dyn Signer>>,
     rpc_client: &C,
-    chain: &mut Chain,
-    amount: u64,
-    denom: &Identifier,
+    chain: &Chain,
+    connection_details: &ConnectionDetails,
+    amount: &Amount,
+    denom: &Denom,
     receiver: String,
+    packet_memo: Option<String>,
     memo: String,
     request_id: Option<&str>,
 ) -> Result<TxRaw>
 where
     C: Client + Send + Sync,
 {
-    let connection_details = chain.connection_details.as_ref().ok_or_else(|| {
-        anyhow!(
-            "connection details not found for chain with id {}",
-            chain.id
-        )
-    })?;
-
     let sender = signer.to_account_address()?;
 
+    let packet = token_send_packet(
+        &mut *transaction,
+        rpc_client,
+        chain,
+        connection_details,
+        amount,
+        denom,
+        sender,
+        receiver,
+        packet_memo,
+    )
+    .await?;
+
+    let client = get_solo_machine_client(
+        &mut *transaction,
+        chain,
+        &connection_details.solo_machine_client_id,
+    )
+    .await?;
+
+    let proof_commitment = get_packet_commitment_proof(
+        &mut *transaction,
+        &signer,
+        chain,
+        &client,
+        &packet,
+        request_id,
+    )
+    .await?;
+
+    msg_recv_packet(
+        transaction,
+        signer,
+        fee_payer,
+        chain,
+        connection_details,
+        packet,
+        proof_commitment,
+        memo,
+        request_id,
+    )
+    .await
+}
+
+/// Builds packet for sending tokens from solo machine to IBC enabled chain
+#[allow(clippy::too_many_arguments)]
+pub async fn token_send_packet<'e, C>(
+    executor: impl Executor<'e, Database = Db>,
+    rpc_client: &C,
+    chain: &Chain,
+    connection_details: &ConnectionDetails,
+    amount: &Amount,
+    denom: &Denom,
+    sender: String,
+    receiver: String,
+    packet_memo: Option<String>,
+) -> Result<Packet>
+where
+    C: Client + Send + Sync,
+{
     let packet_data = TokenTransferPacketData {
         denom: denom.to_string(),
-        amount,
-        sender: sender.clone(),
+        amount: amount.clone(),
+        sender,
         receiver,
+        memo: packet_memo,
     };
 
-    let packet = Packet {
-        sequence: chain.packet_sequence.into(),
-        source_port: chain.config.port_id.to_string(),
+    Ok(Packet {
+        sequence: connection::get_packet_sequence(
+            executor,
+            &chain.id,
+            &connection_details.solo_machine_channel_id,
+        )
+        .await?,
+        source_port: connection_details.port_id.to_string(),
         source_channel: connection_details.tendermint_channel_id.to_string(),
-        destination_port: chain.config.port_id.to_string(),
+        destination_port: connection_details.port_id.to_string(),
         destination_channel: connection_details.solo_machine_channel_id.to_string(),
         data: serde_json::to_vec(&packet_data)?,
         timeout_height: Some(
             get_latest_height(chain, rpc_client)
                 .await?
-                .checked_add(DEFAULT_TIMEOUT_HEIGHT_OFFSET)
+                .checked_add(chain.config.packet_timeout_height_offset)
                 .ok_or_else(|| anyhow!("height addition overflow"))?,
         ),
-        timeout_timestamp: 0,
+        timeout_timestamp: get_timeout_timestamp(Utc::now(), chain)?
+            .map(|timestamp| {
+                timestamp
+                    .timestamp_nanos()
+                    .try_into()
+                    .context("unable to convert unix timestamp to u64")
+            })
+            .transpose()?
+            .unwrap_or_default(),
+    })
+}
+
+/// Builds transaction for receiving given packet (sent from solo machine) on IBC enabled chain
+#[allow(clippy::too_many_arguments)]
+pub async fn msg_recv_packet(
+    transaction: &mut Transaction<'_, Db>,
+    signer: impl Signer,
+    fee_payer: Option<&Arc<dyn Signer>>,
+    chain: &Chain,
+    connection_details: &ConnectionDetails,
+    packet: Packet,
+    proof_commitment: Vec<u8>,
+    memo: String,
+    request_id: Option<&str>,
+) -> Result<TxRaw> {
+    let client = get_solo_machine_client(
+        &mut *transaction,
+        chain,
+        &connection_details.solo_machine_client_id,
+    )
+    .await?;
+
+    solo_machine_client::increment_sequence(&mut *transaction, &chain.id, &client.client_id)
+        .await?;
+    connection::increment_packet_sequence(
+        &mut *transaction,
+        &chain.id,
+        &connection_details.solo_machine_channel_id,
+    )
+    .await?;
+
+    let message = recv_packet_message(&client, packet, proof_commitment, &signer)?;
+
+    build(signer, fee_payer, chain, &[message], memo, request_id).await
+}
+
+/// Builds sign doc of transaction for receiving given packet (sent from solo machine) on IBC enabled chain which must be
+/// signed offline (in generate-only mode) by given signer (which also pays the fees)
+pub async fn msg_recv_packet_sign_doc(
+    signer: impl ToPublicKey,
+    chain: &Chain,
+    client: &SoloMachineClient,
+    packet: Packet,
+    proof_commitment: Vec<u8>,
+    memo: String,
+) -> Result<SignDocPayload> {
+    let message = recv_packet_message(client, packet, proof_commitment, &signer)?;
+
+    let tx_body = build_tx_body(&[message], memo).context("unable to build transaction body")?;
+    let body_bytes = proto_encode(&tx_body)?;
+
+    let (account_number, account_sequence) = get_account_details(&signer, chain).await?;
+
+    let signer_infos = vec![
+        build_signer_info(&signer, account_sequence, &BTreeSet::new())
+            .context("unable to build signer info")?,
+    ];
+    let fee = get_fee(chain, &tx_body, signer_infos.clone(), String::new()).await?;
+
+    let auth_info = AuthInfo {
+        signer_infos,
+        fee: Some(fee.clone()),
     };
+    let auth_info_bytes = proto_encode(&auth_info)?;
 
-    let proof_commitment = get_packet_commitment_proof(&signer, chain, &packet, request_id).await?;
+    let sign_doc = SignDoc {
+        body_bytes: body_bytes.clone(),
+        auth_info_bytes: auth_info_bytes.clone(),
+        chain_id: chain.id.to_string(),
+        account_number,
+    };
 
-    let proof_height = Height::new(0, chain.sequence.into());
+    Ok(SignDocPayload {
+        account_number,
+        sequence: account_sequence,
+        fee: fee
+            .amount
+            .iter()
+            .map(|coin| format!("{}{}", coin.amount, coin.denom))
+            .collect::<Vec<_>>()
+            .join(","),
+        gas_limit: fee.gas_limit,
+        body_bytes,
+        auth_info_bytes,
+        sign_doc: proto_encode(&sign_doc)?,
+        signature: None,
+    })
+}
 
-    *chain = chain::increment_sequence(&mut *transaction, &chain.id).await?;
-    *chain = chain::increment_packet_sequence(&mut *transaction, &chain.id).await?;
+/// Builds transaction for receiving given packet (sent from solo machine) on IBC enabled chain from a sign doc (built
+/// using [`msg_recv_packet_sign_doc`]) which is signed offline. Fails if the sign doc does not receive given packet
+/// with given proof (for current sequence of solo machine client).
+pub async fn msg_recv_packet_signed_offline(
+    transaction: &mut Transaction<'_, Db>,
+    chain: &Chain,
+    connection_details: &ConnectionDetails,
+    packet: Packet,
+    proof_commitment: Vec<u8>,
+    sign_doc: &SignDocPayload,
+    signature: Vec<u8>,
+) -> Result<TxRaw> {
+    let client = get_solo_machine_client(
+        &mut *transaction,
+        chain,
+        &connection_details.solo_machine_client_id,
+    )
+    .await?;
 
-    let message = MsgRecvPacket {
-        packet: Some(packet),
-        proof_commitment,
-        proof_height: Some(proof_height),
-        signer: sender,
+    let tx_body = <TxBody as prost::Message>::decode(sign_doc.body_bytes.as_slice())
+        .context("unable to decode body bytes")?;
+
+    ensure!(
+        tx_body.messages.len() == 1,
+        "transaction in sign doc must contain exactly one message"
+    );
+
+    let message = MsgRecvPacket::from_any(&tx_body.messages[0])?;
+
+    ensure!(
+        message.packet.as_ref() == Some(&packet)
+            && message.proof_commitment == proof_commitment
+            && message.proof_height == Some(Height::new(0, client.sequence)),
+        "transaction in sign doc does not receive packet with sequence {} using the signed packet commitment proof",
+        packet.sequence
+    );
+
+    let expected_sign_doc = SignDoc {
+        body_bytes: sign_doc.body_bytes.clone(),
+        auth_info_bytes: sign_doc.auth_info_bytes.clone(),
+        chain_id: chain.id.to_string(),
+        account_number: sign_doc.account_number,
     };
 
-    build(signer, chain, &[message], memo, request_id).await
+    ensure!(
+        proto_encode(&expected_sign_doc)? == sign_doc.sign_doc,
+        "sign doc does not match body bytes and auth info bytes of transaction"
+    );
+
+    solo_machine_client::increment_sequence(&mut *transaction, &chain.id, &client.client_id)
+        .await?;
+    connection::increment_packet_sequence(
+        &mut *transaction,
+        &chain.id,
+        &connection_details.solo_machine_channel_id,
+    )
+    .await?;
+
+    Ok(TxRaw {
+        body_bytes: sign_doc.body_bytes.clone(),
+        auth_info_bytes: sign_doc.auth_info_bytes.clone(),
+        signatures: vec![signature],
+    })
+}
+
+/// Returns message for receiving given packet (sent from solo machine) on IBC enabled chain using a packet commitment
+/// proof for current sequence of given solo machine client
+fn recv_packet_message(
+    client: &SoloMachineClient,
+    packet: Packet,
+    proof_commitment: Vec<u8>,
+    signer: impl ToPublicKey,
+) -> Result<MsgRecvPacket> {
+    Ok(MsgRecvPacket {
+        packet: Some(packet),
+        proof_commitment,
+        proof_height: Some(Height::new(0, client.sequence)),
+        signer: signer.to_account_address()?,
+    })
 }
 
-pub async fn msg_token_receive(
+#[allow(clippy::too_many_arguments)]
+pub async fn msg_token_receive<'e>(
+    executor: impl Executor<'e, Database = Db>,
     signer: impl Signer,
+    fee_payer: Option<&Arc<dyn Signer>>,
     chain: &Chain,
-    amount: u64,
-    denom: &Identifier,
+    connection_details: &ConnectionDetails,
+    amount: &Amount,
+    denom: &Denom,
     receiver: String,
     memo: String,
     request_id: Option<&str>,
 ) -> Result<TxRaw> {
-    let connection_details = chain.connection_details.as_ref().ok_or_else(|| {
-        anyhow!(
-            "connection details not found for chain with id {}",
-            chain.id
-        )
-    })?;
+    let client =
+        get_solo_machine_client(executor, chain, &connection_details.solo_machine_client_id)
+            .await?;
 
-    let denom = chain.get_ibc_denom(denom).ok_or_else(|| {
-        anyhow!(
-            "connection is not established with chain with id {}",
-            chain.id
-        )
-    })?;
+    let denom = connection_details.get_ibc_denom(denom);
 
     let sender = signer.to_account_address()?;
 
     let message = MsgTransfer {
-        source_port: chain.config.port_id.to_string(),
+        source_port: connection_details.port_id.to_string(),
         source_channel: connection_details.solo_machine_channel_id.to_string(),
         token: Some(Coin {
             amount: amount.to_string(),
@@ -438,34 +793,62 @@ pub async fn msg_token_receive(
         }),
         sender,
         receiver,
-        timeout_height: Some(Height::new(0, u64::from(chain.sequence) + 1)),
-        timeout_timestamp: 0,
+        timeout_height: Some(Height::new(
+            0,
+            client
+                .sequence
+                .checked_add(chain.config.packet_timeout_height_offset)
+                .ok_or_else(|| anyhow!("height addition overflow"))?,
+        )),
+        timeout_timestamp: get_timeout_timestamp(client.consensus_timestamp, chain)?
+            .map(to_u64_timestamp)
+            .transpose()?
+            .unwrap_or_default(),
     };
 
-    build(signer, chain, &[message], memo, request_id).await
+    build(signer, fee_payer, chain, &[message], memo, request_id).await
 }
 
-pub async fn msg_token_receive_ack<'e>(
-    executor: impl Executor<'e, Database = Db>,
+/// Builds a message to acknowledge given packet. Sends an error acknowledgement if `error` is provided.
+#[allow(clippy::too_many_arguments)]
+pub async fn msg_token_receive_ack(
+    transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
-    chain: &mut Chain,
+    fee_payer: Option<&Arc<dyn Signer>>,
+    chain: &Chain,
+    connection_details: &ConnectionDetails,
     packet: Packet,
+    error: Option<&str>,
     memo: String,
     request_id: Option<&str>,
 ) -> Result<TxRaw> {
-    let proof_height = Height::new(0, chain.sequence.into());
-    let acknowledgement = serde_json::to_vec(&json!({ "result": [1] }))?;
+    let client = get_solo_machine_client(
+        &mut *transaction,
+        chain,
+        &connection_details.solo_machine_client_id,
+    )
+    .await?;
+    let proof_height = Height::new(0, client.sequence);
+    let acknowledgement = match error {
+        None => serde_json::to_vec(&json!({ "result": [1] }))?,
+        Some(error) => serde_json::to_vec(&json!({ "error": error }))?,
+    };
 
     let proof_acked = get_packet_acknowledgement_proof(
+        &mut *transaction,
         &signer,
         chain,
+        &client,
+        &packet.destination_port.parse()?,
+        &packet.destination_channel.parse()?,
         acknowledgement.clone(),
         packet.sequence,
         request_id,
     )
     .await?;
 
-    *chain = chain::increment_sequence(executor, &chain.id).await?;
+    solo_machine_client::increment_sequence(&mut *transaction, &chain.id, &client.client_id)
+        .await?;
 
     let message = MsgAcknowledgement {
         packet: Some(packet),
@@ -475,11 +858,57 @@ pub async fn msg_token_receive_ack<'e>(
         signer: signer.to_account_address()?,
     };
 
-    build(signer, chain, &[message], memo, request_id).await
+    build(signer, fee_payer, chain, &[message], memo, request_id).await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn msg_token_receive_timeout(
+    transaction: &mut Transaction<'_, Db>,
+    signer: impl Signer,
+    fee_payer: Option<&Arc<dyn Signer>>,
+    chain: &Chain,
+    connection_details: &ConnectionDetails,
+    packet: Packet,
+    memo: String,
+    request_id: Option<&str>,
+) -> Result<TxRaw> {
+    let client = get_solo_machine_client(
+        &mut *transaction,
+        chain,
+        &connection_details.solo_machine_client_id,
+    )
+    .await?;
+    let proof_height = Height::new(0, client.sequence);
+
+    let proof_unreceived = get_packet_receipt_absence_proof(
+        &mut *transaction,
+        &signer,
+        chain,
+        &client,
+        &packet.destination_port.parse()?,
+        &packet.destination_channel.parse()?,
+        packet.sequence,
+        request_id,
+    )
+    .await?;
+
+    solo_machine_client::increment_sequence(&mut *transaction, &chain.id, &client.client_id)
+        .await?;
+
+    let message = MsgTimeout {
+        next_sequence_recv: packet.sequence,
+        packet: Some(packet),
+        proof_unreceived,
+        proof_height: Some(proof_height),
+        signer: signer.to_account_address()?,
+    };
+
+    build(signer, fee_payer, chain, &[message], memo, request_id).await
 }
 
 async fn build<T>(
     signer: impl Signer,
+    fee_payer: Option<&Arc<dyn Signer>>,
     chain: &Chain,
     messages: &[T],
     memo: String,
@@ -493,26 +922,101 @@ where
 
     let (account_number, account_sequence) = get_account_details(&signer, chain).await?;
 
-    let auth_info =
-        build_auth_info(&signer, chain, account_sequence).context("unable to build auth info")?;
-    let auth_info_bytes = proto_encode(&auth_info)?;
-
-    let signature = build_signature(
-        signer,
-        tx_body_bytes.clone(),
-        auth_info_bytes.clone(),
-        chain.id.to_string(),
-        account_number,
-        request_id,
-    )
-    .await
-    .context("unable to sign transaction")?;
+    // Fee payer (if any) is the second signer of transaction
+    let fee_payer = match fee_payer {
+        None => None,
+        Some(fee_payer) => {
+            let (account_number, account_sequence) = get_account_details(fee_payer, chain).await?;
+            Some((fee_payer, account_number, account_sequence))
+        }
+    };
 
-    Ok(TxRaw {
-        body_bytes: tx_body_bytes,
-        auth_info_bytes,
-        signatures: vec![signature],
-    })
+    let payer = fee_payer
+        .map(|(fee_payer, _, _)| fee_payer.to_account_address())
+        .transpose()?
+        .unwrap_or_default();
+
+    // Members of multisig signers (of signer and fee payer respectively) who failed to sign `SignDoc`. Bit array of
+    // members signing `SignDoc` is a part of `SignDoc` itself, so, whenever a selected member fails to sign, a new set of
+    // members is selected (excluding the failed ones) and `SignDoc` is signed again.
+    let mut unavailable = BTreeSet::new();
+    let mut fee_payer_unavailable = BTreeSet::new();
+    let mut fee = None;
+
+    loop {
+        let mut signer_infos = vec![build_signer_info(&signer, account_sequence, &unavailable)
+            .context("unable to build signer info")?];
+
+        if let Some((fee_payer, _, account_sequence)) = fee_payer {
+            signer_infos.push(
+                build_signer_info(fee_payer, account_sequence, &fee_payer_unavailable)
+                    .context("unable to build signer info of fee payer")?,
+            );
+        }
+
+        // Number of signatures does not change when signing `SignDoc` again, so, fee is computed only once
+        if fee.is_none() {
+            fee = Some(get_fee(chain, &tx_body, signer_infos.clone(), payer.clone()).await?);
+        }
+
+        let auth_info = AuthInfo {
+            signer_infos,
+            fee: fee.clone(),
+        };
+        let auth_info_bytes = proto_encode(&auth_info)?;
+
+        let signature = build_signature(
+            &signer,
+            tx_body_bytes.clone(),
+            auth_info_bytes.clone(),
+            chain.id.to_string(),
+            account_number,
+            &unavailable,
+            request_id,
+        )
+        .await;
+
+        let mut signatures = match signature {
+            Ok(signature) => vec![signature],
+            Err(err) => match err.downcast_ref::<UnavailableMembers>() {
+                Some(members) => {
+                    unavailable.extend(members.members.iter().copied());
+                    continue;
+                }
+                None => return Err(err.context("unable to sign transaction")),
+            },
+        };
+
+        if let Some((fee_payer, account_number, _)) = fee_payer {
+            let signature = build_signature(
+                fee_payer,
+                tx_body_bytes.clone(),
+                auth_info_bytes.clone(),
+                chain.id.to_string(),
+                account_number,
+                &fee_payer_unavailable,
+                request_id,
+            )
+            .await;
+
+            match signature {
+                Ok(signature) => signatures.push(signature),
+                Err(err) => match err.downcast_ref::<UnavailableMembers>() {
+                    Some(members) => {
+                        fee_payer_unavailable.extend(members.members.iter().copied());
+                        continue;
+                    }
+                    None => return Err(err.context("unable to sign transaction by fee payer")),
+                },
+            }
+        }
+
+        return Ok(TxRaw {
+            body_bytes: tx_body_bytes,
+            auth_info_bytes,
+            signatures,
+        });
+    }
 }
 
 fn build_tx_body<T>(messages: &[T], memo: String) -> Result<TxBody>
@@ -533,33 +1037,182 @@ where
     })
 }
 
-fn build_auth_info(
+fn build_signer_info(
     signer: impl ToPublicKey,
-    chain: &Chain,
     account_sequence: u64,
-) -> Result<AuthInfo> {
-    let signer_info = SignerInfo {
-        public_key: Some(signer.to_public_key()?.to_any()?),
-        mode_info: Some(ModeInfo {
-            sum: Some(Sum::Single(Single { mode: 1 })),
-        }),
-        sequence: account_sequence,
+    unavailable: &BTreeSet<usize>,
+) -> Result<SignerInfo> {
+    let public_key = signer.to_public_key()?;
+
+    let mode_info = match public_key {
+        PublicKey::Multisig(ref public_key) => multisig_mode_info(public_key, unavailable)?,
+        _ => direct_mode_info(),
     };
 
-    let fee = Fee {
+    Ok(SignerInfo {
+        public_key: Some(public_key.to_any()?),
+        mode_info: Some(mode_info),
+        sequence: account_sequence,
+    })
+}
+
+fn direct_mode_info() -> ModeInfo {
+    ModeInfo {
+        sum: Some(Sum::Single(Single {
+            mode: SignMode::Direct.into(),
+        })),
+    }
+}
+
+/// Returns mode info of a multisig signer where each member in `sign_doc_signers()` signs `SignDoc` directly
+///
+/// Note: `SIGN_MODE_LEGACY_AMINO_JSON` cannot be used here because IBC messages do not support amino JSON sign bytes.
+fn multisig_mode_info(
+    public_key: &MultisigPublicKey,
+    unavailable: &BTreeSet<usize>,
+) -> Result<ModeInfo> {
+    let signers = sign_doc_signers(public_key, unavailable)?;
+    let num_signers = signers.num_true_bits_before(signers.len());
+
+    Ok(ModeInfo {
+        sum: Some(Sum::Multi(Multi {
+            bitarray: Some(signers),
+            mode_infos: vec![direct_mode_info(); num_signers],
+        })),
+    })
+}
+
+/// Returns the members of a multisig signer who sign `SignDoc` of transactions, i.e., first `threshold` members which
+/// have not failed to sign it yet (bit array of signers is a part of `SignDoc`, so, it has to be fixed before collecting
+/// signatures)
+fn sign_doc_signers(
+    public_key: &MultisigPublicKey,
+    unavailable: &BTreeSet<usize>,
+) -> Result<CompactBitArray> {
+    let threshold: usize = public_key.threshold.try_into()?;
+    let mut signers = CompactBitArray::new(public_key.public_keys.len());
+
+    let available = (0..public_key.public_keys.len())
+        .filter(|index| !unavailable.contains(index))
+        .take(threshold)
+        .collect::<Vec<_>>();
+
+    ensure!(
+        available.len() == threshold,
+        "unable to collect enough signatures from members of multisig signer (available: {}, required: {}, failed members: {:?})",
+        available.len(),
+        threshold,
+        unavailable
+    );
+
+    for index in available {
+        signers.set(index, true);
+    }
+
+    Ok(signers)
+}
+
+/// Returns fee of transaction based on gas mode of chain
+async fn get_fee(
+    chain: &Chain,
+    tx_body: &TxBody,
+    signer_infos: Vec<SignerInfo>,
+    payer: String,
+) -> Result<Fee> {
+    let static_fee = build_fee(
+        chain,
+        chain.config.fee.amount,
+        chain.config.fee.gas_limit,
+        payer.clone(),
+    );
+
+    match chain.config.gas_mode {
+        GasMode::Static => Ok(static_fee),
+        GasMode::Simulate {
+            gas_adjustment,
+            gas_price,
+        } => {
+            let auth_info = AuthInfo {
+                signer_infos,
+                fee: Some(static_fee.clone()),
+            };
+
+            // Falls back to static fee if simulation fails (e.g., when gRPC endpoint does not support simulation). If
+            // the transaction itself is invalid, broadcasting it returns the actual error.
+            let gas_used = match simulate(chain, tx_body.clone(), auth_info).await {
+                Ok(gas_used) => gas_used,
+                Err(err) => {
+                    log::warn!(
+                        "unable to simulate transaction on chain {}, falling back to static fee: {:?}",
+                        chain.id,
+                        err
+                    );
+                    return Ok(static_fee);
+                }
+            };
+
+            let gas_limit = (Decimal::from(gas_used) * gas_adjustment)
+                .ceil()
+                .to_u64()
+                .ok_or_else(|| anyhow!("gas limit overflow"))?;
+            let amount = (Decimal::from(gas_limit) * gas_price).ceil();
+
+            Ok(build_fee(chain, amount, gas_limit, payer))
+        }
+    }
+}
+
+fn build_fee(chain: &Chain, amount: Decimal, gas_limit: u64, payer: String) -> Fee {
+    Fee {
         amount: vec![Coin {
             denom: chain.config.fee.denom.to_string(),
-            amount: chain.config.fee.amount.to_string(),
+            amount: amount.to_string(),
         }],
-        gas_limit: chain.config.fee.gas_limit,
-        payer: "".to_owned(),
-        granter: "".to_owned(),
-    };
+        gas_limit,
+        payer,
+        granter: chain.config.fee.granter.clone().unwrap_or_default(),
+    }
+}
 
-    Ok(AuthInfo {
-        signer_infos: vec![signer_info],
-        fee: Some(fee),
-    })
+/// Simulates given transaction on chain and returns the gas used
+async fn simulate(chain: &Chain, body: TxBody, auth_info: AuthInfo) -> Result<u64> {
+    let mut service_client = TxServiceClient::connect(chain.config.grpc_addr.clone())
+        .await
+        .context(format!(
+            "unable to connect to grpc tx service client at {}",
+            chain.config.grpc_addr
+        ))?;
+
+    // Signatures are not verified when simulating transactions, so, empty signatures are used to avoid signing the
+    // transaction twice
+    let signatures = auth_info
+        .signer_infos
+        .iter()
+        .map(|signer_info| match signer_info.mode_info {
+            Some(ModeInfo {
+                sum: Some(Sum::Multi(ref multi)),
+            }) => proto_encode(&RawMultiSignature {
+                signatures: vec![Vec::new(); multi.mode_infos.len()],
+            }),
+            _ => Ok(Vec::new()),
+        })
+        .collect::<Result<_>>()?;
+
+    let response = service_client
+        .simulate(SimulateRequest {
+            tx: Some(Tx {
+                body: Some(body),
+                auth_info: Some(auth_info),
+                signatures,
+            }),
+        })
+        .await?
+        .into_inner();
+
+    response
+        .gas_info
+        .map(|gas_info| gas_info.gas_used)
+        .ok_or_else(|| anyhow!("missing gas info in simulation response"))
 }
 
 async fn build_signature(
@@ -568,6 +1221,7 @@ async fn build_signature(
     auth_info_bytes: Vec<u8>,
     chain_id: String,
     account_number: u64,
+    unavailable: &BTreeSet<usize>,
     request_id: Option<&str>,
 ) -> Result<Vec<u8>> {
     let sign_doc = SignDoc {
@@ -579,9 +1233,27 @@ async fn build_signature(
 
     let sign_doc_bytes = proto_encode(&sign_doc)?;
 
-    signer
-        .sign(request_id, Message::SignDoc(&sign_doc_bytes))
-        .await
+    match signer.to_public_key()? {
+        PublicKey::Multisig(ref public_key) => {
+            let signers = sign_doc_signers(public_key, unavailable)?;
+            let multi_signature = signer
+                .multi_sign(
+                    request_id,
+                    Message::SignDoc(&sign_doc_bytes),
+                    Some(&signers),
+                )
+                .await?;
+
+            proto_encode(&RawMultiSignature {
+                signatures: multi_signature.signatures,
+            })
+        }
+        _ => {
+            signer
+                .sign(request_id, Message::SignDoc(&sign_doc_bytes))
+                .await
+        }
+    }
 }
 
 async fn get_account_details(signer: impl ToPublicKey, chain: &Chain) -> Result<(u64, u64)> {
@@ -675,164 +1347,155 @@ fn get_block_height(chain: &Chain, header: &Header) -> Height {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn get_packet_acknowledgement_proof(
+    transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
     chain: &Chain,
+    client: &SoloMachineClient,
+    port_id: &PortId,
+    channel_id: &ChannelId,
     acknowledgement: Vec<u8>,
     packet_sequence: u64,
     request_id: Option<&str>,
 ) -> Result<Vec<u8>> {
-    let mut acknowledgement_path = PacketAcknowledgementPath::new(
-        &chain.config.port_id,
-        &chain
-            .connection_details
-            .as_ref()
-            .ok_or_else(|| {
-                anyhow!(
-                    "connection details for chain with id {} not found",
-                    chain.id
-                )
-            })?
-            .tendermint_channel_id,
-        packet_sequence,
-    );
+    let mut acknowledgement_path =
+        PacketAcknowledgementPath::new(port_id, channel_id, packet_sequence);
     acknowledgement_path.apply_prefix(&"ibc".parse().unwrap());
 
-    let acknowledgement_data = PacketAcknowledgementData {
+    let sign_data = SignData::PacketAcknowledgement {
         path: acknowledgement_path.into_bytes(),
         acknowledgement,
     };
 
-    let acknowledgement_data_bytes = proto_encode(&acknowledgement_data)?;
+    timestamped_sign(transaction, signer, chain, client, sign_data, request_id).await
+}
 
-    let sign_bytes = SignBytes {
-        sequence: chain.sequence.into(),
-        timestamp: to_u64_timestamp(chain.consensus_timestamp)?,
-        diversifier: chain.config.diversifier.to_owned(),
-        data_type: DataType::PacketAcknowledgement.into(),
-        data: acknowledgement_data_bytes,
+#[allow(clippy::too_many_arguments)]
+async fn get_packet_receipt_absence_proof(
+    transaction: &mut Transaction<'_, Db>,
+    signer: impl Signer,
+    chain: &Chain,
+    client: &SoloMachineClient,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    packet_sequence: u64,
+    request_id: Option<&str>,
+) -> Result<Vec<u8>> {
+    let mut receipt_path = PacketReceiptPath::new(port_id, channel_id, packet_sequence);
+    receipt_path.apply_prefix(&"ibc".parse().unwrap());
+
+    let sign_data = SignData::PacketReceiptAbsence {
+        path: receipt_path.into_bytes(),
     };
 
-    timestamped_sign(signer, chain, sign_bytes, request_id).await
+    timestamped_sign(transaction, signer, chain, client, sign_data, request_id).await
 }
 
 async fn get_packet_commitment_proof(
+    transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
     chain: &Chain,
+    client: &SoloMachineClient,
     packet: &Packet,
     request_id: Option<&str>,
 ) -> Result<Vec<u8>> {
+    let sign_data = get_packet_commitment_sign_data(packet)?;
+    timestamped_sign(transaction, signer, chain, client, sign_data, request_id).await
+}
+
+/// Returns sign bytes of packet commitment proof (for current sequence of given solo machine client) which must be
+/// signed offline (in generate-only mode)
+pub fn packet_commitment_sign_bytes(
+    chain: &Chain,
+    client: &SoloMachineClient,
+    packet: &Packet,
+) -> Result<SignBytesPayload> {
+    let sign_data = get_packet_commitment_sign_data(packet)?;
+
+    Ok(SignBytesPayload {
+        sequence: client.sequence,
+        timestamp: to_u64_timestamp(client.consensus_timestamp)?,
+        diversifier: chain.config.diversifier.clone(),
+        data_type: "packet_commitment".to_string(),
+        sign_bytes: sign_bytes(chain, client, sign_data)?,
+        signature: None,
+    })
+}
+
+fn get_packet_commitment_sign_data(packet: &Packet) -> Result<SignData> {
     let commitment_bytes = packet.commitment_bytes()?;
+    let port_id: PortId = packet.source_port.parse()?;
+    let channel_id: ChannelId = packet.source_channel.parse()?;
 
-    let mut commitment_path = PacketCommitmentPath::new(
-        &chain.config.port_id,
-        &chain
-            .connection_details
-            .as_ref()
-            .ok_or_else(|| {
-                anyhow!(
-                    "connection details for chain with id {} not found",
-                    chain.id
-                )
-            })?
-            .tendermint_channel_id,
-        chain.packet_sequence.into(),
-    );
+    let mut commitment_path = PacketCommitmentPath::new(&port_id, &channel_id, packet.sequence);
     commitment_path.apply_prefix(&"ibc".parse().unwrap());
 
-    let packet_commitment_data = PacketCommitmentData {
+    Ok(SignData::PacketCommitment {
         path: commitment_path.into_bytes(),
         commitment: commitment_bytes,
-    };
-
-    let packet_commitment_data_bytes = proto_encode(&packet_commitment_data)?;
-
-    let sign_bytes = SignBytes {
-        sequence: chain.sequence.into(),
-        timestamp: to_u64_timestamp(chain.consensus_timestamp)?,
-        diversifier: chain.config.diversifier.to_owned(),
-        data_type: DataType::PacketCommitment.into(),
-        data: packet_commitment_data_bytes,
-    };
-
-    timestamped_sign(signer, chain, sign_bytes, request_id).await
+    })
 }
 
-async fn get_channel_proof<'e>(
-    executor: impl Executor<'e, Database = Db>,
+async fn get_channel_proof(
+    transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
     chain: &Chain,
+    client: &SoloMachineClient,
+    port_id: &PortId,
     channel_id: &ChannelId,
 ) -> Result<Vec<u8>> {
-    let channel = ibc_handler::get_channel(executor, &chain.config.port_id, channel_id)
+    let channel = ibc_handler::get_channel(&mut *transaction, port_id, channel_id)
         .await?
         .ok_or_else(|| {
             anyhow!(
                 "channel with port id {} and channel id {} not found",
-                chain.config.port_id,
+                port_id,
                 channel_id
             )
         })?;
 
-    let mut channel_path = ChannelPath::new(&chain.config.port_id, channel_id);
+    let mut channel_path = ChannelPath::new(port_id, channel_id);
     channel_path.apply_prefix(&"ibc".parse().unwrap());
 
-    let channel_state_data = ChannelStateData {
+    let sign_data = SignData::ChannelState {
         path: channel_path.into_bytes(),
-        channel: Some(channel),
-    };
-
-    let channel_state_data_bytes = proto_encode(&channel_state_data)?;
-
-    let sign_bytes = SignBytes {
-        sequence: chain.sequence.into(),
-        timestamp: to_u64_timestamp(chain.consensus_timestamp)?,
-        diversifier: chain.config.diversifier.to_owned(),
-        data_type: DataType::ChannelState.into(),
-        data: channel_state_data_bytes,
+        channel,
     };
 
-    timestamped_sign(signer, chain, sign_bytes, None).await
+    timestamped_sign(transaction, signer, chain, client, sign_data, None).await
 }
 
-async fn get_connection_proof<'e>(
-    executor: impl Executor<'e, Database = Db>,
+async fn get_connection_proof(
+    transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
     chain: &Chain,
+    client: &SoloMachineClient,
     connection_id: &ConnectionId,
 ) -> Result<Vec<u8>> {
-    let connection = ibc_handler::get_connection(executor, connection_id)
+    let connection = ibc_handler::get_connection(&mut *transaction, connection_id)
         .await?
         .ok_or_else(|| anyhow!("connection with id {} not found", connection_id))?;
 
     let mut connection_path = ConnectionPath::new(connection_id);
     connection_path.apply_prefix(&"ibc".parse().unwrap());
 
-    let connection_state_data = ConnectionStateData {
+    let sign_data = SignData::ConnectionState {
         path: connection_path.into_bytes(),
-        connection: Some(connection),
-    };
-
-    let connection_state_data_bytes = proto_encode(&connection_state_data)?;
-
-    let sign_bytes = SignBytes {
-        sequence: chain.sequence.into(),
-        timestamp: to_u64_timestamp(chain.consensus_timestamp)?,
-        diversifier: chain.config.diversifier.to_owned(),
-        data_type: DataType::ConnectionState.into(),
-        data: connection_state_data_bytes,
+        connection,
     };
 
-    timestamped_sign(signer, chain, sign_bytes, None).await
+    timestamped_sign(transaction, signer, chain, client, sign_data, None).await
 }
 
-async fn get_client_proof<'e>(
-    executor: impl Executor<'e, Database = Db>,
+async fn get_client_proof(
+    transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
     chain: &Chain,
+    client: &SoloMachineClient,
     client_id: &ClientId,
 ) -> Result<Vec<u8>> {
-    let client_state = ibc_handler::get_tendermint_client_state(executor, client_id)
+    let client_state = ibc_handler::get_tendermint_client_state(&mut *transaction, client_id)
         .await?
         .ok_or_else(|| anyhow!("client with id {} not found", client_id))?
         .to_any()?;
@@ -840,28 +1503,19 @@ async fn get_client_proof<'e>(
     let mut client_state_path = ClientStatePath::new(client_id);
     client_state_path.apply_prefix(&"ibc".parse().unwrap());
 
-    let client_state_data = ClientStateData {
+    let sign_data = SignData::ClientState {
         path: client_state_path.into_bytes(),
-        client_state: Some(client_state),
+        client_state,
     };
 
-    let client_state_data_bytes = proto_encode(&client_state_data)?;
-
-    let sign_bytes = SignBytes {
-        sequence: chain.sequence.into(),
-        timestamp: to_u64_timestamp(chain.consensus_timestamp)?,
-        diversifier: chain.config.diversifier.to_owned(),
-        data_type: DataType::ClientState.into(),
-        data: client_state_data_bytes,
-    };
-
-    timestamped_sign(signer, chain, sign_bytes, None).await
+    timestamped_sign(transaction, signer, chain, client, sign_data, None).await
 }
 
 async fn get_consensus_proof(
     transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
     chain: &Chain,
+    client: &SoloMachineClient,
     client_id: &ClientId,
 ) -> Result<Vec<u8>> {
     let client_state = ibc_handler::get_tendermint_client_state(&mut *transaction, client_id)
@@ -887,74 +1541,158 @@ async fn get_consensus_proof(
     let mut consensus_state_path = ConsensusStatePath::new(client_id, &height);
     consensus_state_path.apply_prefix(&"ibc".parse().unwrap());
 
-    let consensus_state_data = ConsensusStateData {
+    let sign_data = SignData::ConsensusState {
         path: consensus_state_path.into_bytes(),
-        consensus_state: Some(consensus_state),
+        consensus_state,
     };
 
-    let consensus_state_data_bytes = proto_encode(&consensus_state_data)?;
-
-    let sign_bytes = SignBytes {
-        sequence: chain.sequence.into(),
-        timestamp: to_u64_timestamp(chain.consensus_timestamp)?,
-        diversifier: chain.config.diversifier.to_owned(),
-        data_type: DataType::ConsensusState.into(),
-        data: consensus_state_data_bytes,
-    };
-
-    timestamped_sign(signer, chain, sign_bytes, None).await
+    timestamped_sign(transaction, signer, chain, client, sign_data, None).await
 }
 
 async fn get_header_proof(
+    transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
     chain: &Chain,
+    client: &SoloMachineClient,
     new_public_key: Option<Any>,
     new_diversifier: String,
 ) -> Result<Vec<u8>> {
-    let header_data = HeaderData {
-        new_pub_key: new_public_key,
+    let sign_data = SignData::Header {
+        new_public_key,
         new_diversifier,
     };
 
-    let header_data_bytes = proto_encode(&header_data)?;
-
-    let sign_bytes = SignBytes {
-        sequence: chain.sequence.into(),
-        timestamp: to_u64_timestamp(chain.consensus_timestamp)?,
-        diversifier: chain.config.diversifier.to_owned(),
-        data_type: DataType::Header.into(),
-        data: header_data_bytes,
-    };
-
-    sign(signer, None, sign_bytes).await
+    sign(
+        transaction,
+        signer,
+        chain,
+        client,
+        None,
+        sign_bytes(chain, client, sign_data)?,
+    )
+    .await
 }
 
 async fn timestamped_sign(
+    transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
     chain: &Chain,
-    sign_bytes: SignBytes,
+    client: &SoloMachineClient,
+    sign_data: SignData,
     request_id: Option<&str>,
 ) -> Result<Vec<u8>> {
-    let signature_data = sign(signer, request_id, sign_bytes).await?;
+    let signature_data = sign(
+        transaction,
+        signer,
+        chain,
+        client,
+        request_id,
+        sign_bytes(chain, client, sign_data)?,
+    )
+    .await?;
+    timestamped_signature_data(chain, client, signature_data)
+}
 
-    let timestamped_signature_data = TimestampedSignatureData {
-        signature_data,
-        timestamp: to_u64_timestamp(chain.consensus_timestamp)?,
-    };
+/// Fetches given solo machine client of chain from database. Consensus timestamp of returned client is advanced to
+/// current time (if it is behind), i.e., it is the timestamp with which solo machine signs next for the client (use
+/// [`solo_machine_client::get_solo_machine_client`] for the stored consensus timestamp).
+pub async fn get_solo_machine_client<'e>(
+    executor: impl Executor<'e, Database = Db>,
+    chain: &Chain,
+    client_id: &ClientId,
+) -> Result<SoloMachineClient> {
+    let mut client = solo_machine_client::get_solo_machine_client(executor, &chain.id, client_id)
+        .await?
+        .ok_or_else(|| {
+            anyhow!(
+                "solo machine client with id {} not found for chain with id {}",
+                client_id,
+                chain.id
+            )
+        })?;
+
+    // Solo machine timestamps are in seconds
+    let now = Utc.timestamp(Utc::now().timestamp(), 0);
 
-    proto_encode(&timestamped_signature_data)
+    if client.consensus_timestamp < now {
+        client.consensus_timestamp = now;
+    }
+
+    Ok(client)
+}
+
+/// Returns protobuf encoded `SignBytes` of given data for current sequence and consensus timestamp of given solo
+/// machine client (as per solo machine client protocol version of chain)
+fn sign_bytes(chain: &Chain, client: &SoloMachineClient, sign_data: SignData) -> Result<Vec<u8>> {
+    chain.config.solo_machine_version.encode_sign_bytes(
+        client.sequence,
+        to_u64_timestamp(client.consensus_timestamp)?,
+        chain.config.diversifier.clone(),
+        sign_data,
+    )
+}
+
+/// Returns timestamped signature data (i.e., proof) from a signature over sign bytes which is produced offline (in
+/// generate-only mode)
+pub fn timestamped_signature(
+    chain: &Chain,
+    client: &SoloMachineClient,
+    signature: Vec<u8>,
+) -> Result<Vec<u8>> {
+    timestamped_signature_data(chain, client, signature_data(signature)?)
+}
+
+fn timestamped_signature_data(
+    chain: &Chain,
+    client: &SoloMachineClient,
+    signature_data: Vec<u8>,
+) -> Result<Vec<u8>> {
+    chain
+        .config
+        .solo_machine_version
+        .encode_timestamped_signature_data(
+            signature_data,
+            to_u64_timestamp(client.consensus_timestamp)?,
+        )
 }
 
+/// Signs given `SignBytes` after reserving them in sign-ledger of given solo machine client within given database
+/// transaction (so, the signature is never released for `SignBytes` which conflict with the ones already signed for
+/// the same sequence). Consensus timestamp of the client is advanced to the timestamp of `SignBytes` (IBC enabled
+/// chain rejects signatures older than consensus timestamp of the client).
 async fn sign(
+    transaction: &mut Transaction<'_, Db>,
     signer: impl Signer,
+    chain: &Chain,
+    client: &SoloMachineClient,
     request_id: Option<&str>,
-    sign_bytes: SignBytes,
+    sign_bytes: Vec<u8>,
 ) -> Result<Vec<u8>> {
-    let sign_bytes = proto_encode(&sign_bytes)?;
+    guard::reserve_sign_bytes(&mut *transaction, chain, &client.client_id, &sign_bytes).await?;
+    solo_machine_client::set_consensus_timestamp(
+        &mut *transaction,
+        &chain.id,
+        &client.client_id,
+        client.consensus_timestamp,
+    )
+    .await?;
+
+    if let PublicKey::Multisig(_) = signer.to_public_key()? {
+        let multi_signature = signer
+            .multi_sign(request_id, Message::SignBytes(&sign_bytes), None)
+            .await?;
+
+        return multi_signature_data(multi_signature);
+    }
+
     let signature = signer
         .sign(request_id, Message::SignBytes(&sign_bytes))
         .await?;
 
+    signature_data(signature)
+}
+
+fn signature_data(signature: Vec<u8>) -> Result<Vec<u8>> {
     let signature_data = SignatureData {
         sum: Some(SignatureDataInner::Single(SingleSignatureData {
             signature,
@@ -965,6 +1703,28 @@ async fn sign(
     proto_encode(&signature_data)
 }
 
+fn multi_signature_data(multi_signature: MultiSignature) -> Result<Vec<u8>> {
+    let signatures = multi_signature
+        .signatures
+        .into_iter()
+        .map(|signature| SignatureData {
+            sum: Some(SignatureDataInner::Single(SingleSignatureData {
+                signature,
+                mode: SignMode::Unspecified.into(),
+            })),
+        })
+        .collect();
+
+    let signature_data = SignatureData {
+        sum: Some(SignatureDataInner::Multi(MultiSignatureData {
+            bitarray: Some(multi_signature.bit_array),
+            signatures,
+        })),
+    };
+
+    proto_encode(&signature_data)
+}
+
 fn to_u64_timestamp(timestamp: DateTime<Utc>) -> Result<u64> {
     timestamp
         .timestamp()
@@ -972,10 +1732,101 @@ fn to_u64_timestamp(timestamp: DateTime<Utc>) -> Result<u64> {
         .context("unable to convert unix timestamp to u64")
 }
 
+/// Returns timeout timestamp for a packet after adding configured offset to given timestamp (returns `None` if
+/// timestamp based timeouts are disabled)
+fn get_timeout_timestamp(timestamp: DateTime<Utc>, chain: &Chain) -> Result<Option<DateTime<Utc>>> {
+    if chain.config.packet_timeout_timestamp_offset.is_zero() {
+        return Ok(None);
+    }
+
+    let offset = chrono::Duration::from_std(chain.config.packet_timeout_timestamp_offset)
+        .context("invalid packet timeout timestamp offset")?;
+
+    timestamp
+        .checked_add_signed(offset)
+        .map(Some)
+        .ok_or_else(|| anyhow!("timestamp addition overflow"))
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TokenTransferPacketData {
     pub denom: String,
-    pub amount: u64,
+    #[serde(deserialize_with = "deserialize_amount")]
+    pub amount: Amount,
     pub sender: String,
     pub receiver: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+/// Deserializes token amount in packet data (IBC enabled chains encode it as a string but older versions of solo
+/// machine encoded it as a number)
+fn deserialize_amount<'de, D>(deserializer: D) -> Result<Amount, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawAmount {
+        Number(u64),
+        String(String),
+    }
+
+    match RawAmount::deserialize(deserializer)? {
+        RawAmount::Number(amount) => Ok(amount.into()),
+        RawAmount::String(amount) => amount.parse().map_err(de::Error::custom),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet_data_with_amount(amount: &str) -> String {
+        format!(
+            r#"{{"denom":"uatom","amount":{},"sender":"sender","receiver":"receiver"}}"#,
+            amount
+        )
+    }
+
+    #[test]
+    fn test_packet_data_amount_string_or_number() {
+        let packet_data: TokenTransferPacketData =
+            serde_json::from_str(&packet_data_with_amount("\"100\"")).unwrap();
+        assert_eq!(packet_data.amount, Amount::from(100));
+
+        let packet_data: TokenTransferPacketData =
+            serde_json::from_str(&packet_data_with_amount("100")).unwrap();
+        assert_eq!(packet_data.amount, Amount::from(100));
+
+        let packet_data: TokenTransferPacketData =
+            serde_json::from_str(&packet_data_with_amount("18446744073709551615")).unwrap();
+        assert_eq!(packet_data.amount, Amount::from(u64::MAX));
+
+        let packet_data: TokenTransferPacketData =
+            serde_json::from_str(&packet_data_with_amount("\"18446744073709551616\"")).unwrap();
+        assert_eq!(packet_data.amount.to_string(), "18446744073709551616");
+    }
+
+    #[test]
+    fn test_packet_data_invalid_amount() {
+        for amount in [
+            "18446744073709551616",
+            "-1",
+            "1.5",
+            "\"-1\"",
+            "\"+1\"",
+            "\"\"",
+            "null",
+        ]
+        .iter()
+        {
+            assert!(
+                serde_json::from_str::<TokenTransferPacketData>(&packet_data_with_amount(amount))
+                    .is_err(),
+                "{}",
+                amount
+            );
+        }
+    }
 }
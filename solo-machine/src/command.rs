@@ -7,16 +7,29 @@ use std::{
     io::{stdout, Write},
     net::SocketAddr,
     path::PathBuf,
+    sync::Arc,
+    time::Duration,
 };
 
-use anyhow::{ensure, Context, Result};
+use anyhow::{anyhow, ensure, Context, Result};
 use cli_table::{Cell, Row, RowStruct, Style};
-use solo_machine_core::{connect_db, event::HandlerRegistrar as _, init_db, run_migrations};
+use k256::ecdsa::VerifyingKey;
+use solo_machine_core::{
+    connect_db,
+    cosmos::crypto::PublicKey,
+    event::HandlerRegistrar as _,
+    init_db,
+    policy::{AcceptAll, AcceptancePolicy},
+    run_migrations,
+    signer::multisig::{MultisigSigner, OperatorSigner, SignatureCollector},
+    Signer,
+};
 use structopt::{clap::Shell, StructOpt};
 use termcolor::{ColorChoice, ColorSpec, StandardStream, WriteColor};
 
 use crate::{
     event::{cli_event_handler::CliEventHandler, env_logger::EnvLogger, HandlerRegistrar},
+    policy::PolicyRegistrar,
     server::start_grpc,
     signer::SignerRegistrar,
 };
@@ -44,8 +57,24 @@ pub struct Command {
     #[structopt(long, env = "SOLO_DB_URI", hide_env_values = true)]
     db_uri: Option<String>,
     /// Register a signer (path to signer's `*.so` file)
-    #[structopt(long, env = "SOLO_SIGNER", hide_env_values = true)]
+    #[structopt(
+        long,
+        env = "SOLO_SIGNER",
+        hide_env_values = true,
+        conflicts_with = "multisig-threshold"
+    )]
     signer: Option<PathBuf>,
+    #[structopt(flatten)]
+    multisig: MultisigArgs,
+    /// Register an acceptance policy for packets sent from IBC enabled chain to solo machine (path to policy's `*.so`
+    /// file). If this is not provided, all the packets are accepted.
+    #[structopt(long, env = "SOLO_POLICY", hide_env_values = true)]
+    policy: Option<PathBuf>,
+    /// Register a separate signer which pays fees of transactions sent to IBC enabled chain (path to signer's `*.so`
+    /// file). Signer's arguments are read from environment variables prefixed with `SOLO_FEE_PAYER_` (e.g.,
+    /// `SOLO_FEE_PAYER_MNEMONIC`). If this is not provided, fees are paid by `signer`.
+    #[structopt(long, env = "SOLO_FEE_PAYER_SIGNER", hide_env_values = true)]
+    fee_payer_signer: Option<PathBuf>,
     /// Register an event handler. Multiple event handlers can be registered and they're executed in order they're
     /// provided in CLI. Also, if an event handler returns an error when handling a message, all the future event
     /// handlers will not get executed.
@@ -55,6 +84,41 @@ pub struct Command {
     subcommand: SubCommand,
 }
 
+/// Arguments for using a k-of-n multisig signer instead of `signer`
+#[derive(Debug, StructOpt)]
+pub struct MultisigArgs {
+    /// Minimum number of signatures required from members of multisig signer. If this is provided, a multisig signer
+    /// (with `multisig-signer`s followed by `multisig-operator`s as its members) is used instead of `signer`. Members
+    /// sign transactions using `SIGN_MODE_DIRECT` (instead of `SIGN_MODE_LEGACY_AMINO_JSON`, which is not supported by
+    /// IBC messages).
+    #[structopt(long, env = "SOLO_MULTISIG_THRESHOLD", hide_env_values = true)]
+    multisig_threshold: Option<u32>,
+    /// Register a member of multisig signer (path to signer's `*.so` file). Arguments of n-th member (starting from 1)
+    /// are read from environment variables prefixed with `SOLO_MULTISIG_<n>_` (e.g., `SOLO_MULTISIG_1_MNEMONIC`).
+    #[structopt(long, requires = "multisig-threshold")]
+    multisig_signer: Vec<PathBuf>,
+    /// Hex encoded secp256k1 public key of an operator who is a member of multisig signer. Operators submit their
+    /// signatures to gRPC server, so, this is only supported when starting gRPC server.
+    #[structopt(long, requires = "multisig-threshold")]
+    multisig_operator: Vec<String>,
+    /// Bech32 account prefix of multisig signer
+    #[structopt(
+        long,
+        env = "SOLO_MULTISIG_ACCOUNT_PREFIX",
+        hide_env_values = true,
+        default_value = "cosmos"
+    )]
+    multisig_account_prefix: String,
+    /// Time to wait for an operator to submit its signature
+    #[structopt(
+        long,
+        env = "SOLO_MULTISIG_OPERATOR_TIMEOUT",
+        default_value = "5 min",
+        parse(try_from_str = humantime::parse_duration)
+    )]
+    multisig_operator_timeout: Duration,
+}
+
 #[derive(Debug, StructOpt)]
 #[allow(clippy::large_enum_variant)]
 pub enum SubCommand {
@@ -74,6 +138,30 @@ pub enum SubCommand {
         /// gRPC server address
         #[structopt(short, long, env = "SOLO_GRPC_ADDR", default_value = "0.0.0.0:9000")]
         addr: SocketAddr,
+        /// Interval at which IBC enabled chains are polled for packets sent to solo machine
+        #[structopt(
+            long,
+            env = "SOLO_PACKET_POLL_INTERVAL",
+            default_value = "10 sec",
+            parse(try_from_str = humantime::parse_duration)
+        )]
+        packet_poll_interval: Duration,
+        /// Interval at which pending packet acknowledgements (which failed earlier) are retried
+        #[structopt(
+            long,
+            env = "SOLO_ACK_RETRY_INTERVAL",
+            default_value = "30 sec",
+            parse(try_from_str = humantime::parse_duration)
+        )]
+        ack_retry_interval: Duration,
+        /// Memo to include in transactions sent by packet listener and ack retry worker
+        #[structopt(
+            long,
+            default_value = "solo-machine-memo",
+            env = "SOLO_MEMO",
+            hide_env_values = true
+        )]
+        memo: String,
     },
 }
 
@@ -100,8 +188,8 @@ impl Command {
         match self.subcommand {
             SubCommand::Chain(chain) => {
                 ensure!(
-                    self.signer.is_some(),
-                    "`signer` is required for chain commands"
+                    self.signer.is_some() || self.multisig.multisig_threshold.is_some(),
+                    "`signer` or `multisig-threshold` is required for chain commands"
                 );
                 ensure!(self.db_uri.is_some(), "`db-uri` is required");
 
@@ -111,7 +199,7 @@ impl Command {
                 handler_registrar.register(Box::new(CliEventHandler::new(color_choice)));
                 let (sender, handle) = handler_registrar.spawn();
 
-                let signer = SignerRegistrar::try_from(self.signer.unwrap())?.unwrap()?;
+                let signer = load_signer(self.signer, self.multisig, None)?;
 
                 chain
                     .subcommand
@@ -128,8 +216,8 @@ impl Command {
             }
             SubCommand::Ibc(ibc) => {
                 ensure!(
-                    self.signer.is_some(),
-                    "`signer` is required for ibc commands"
+                    self.signer.is_some() || self.multisig.multisig_threshold.is_some(),
+                    "`signer` or `multisig-threshold` is required for ibc commands"
                 );
                 ensure!(self.db_uri.is_some(), "`db-uri` is required");
 
@@ -139,10 +227,12 @@ impl Command {
                 handler_registrar.register(Box::new(CliEventHandler::new(color_choice)));
                 let (sender, handle) = handler_registrar.spawn();
 
-                let signer = SignerRegistrar::try_from(self.signer.unwrap())?.unwrap()?;
+                let signer = load_signer(self.signer, self.multisig, None)?;
+                let policy = load_policy(self.policy)?;
+                let fee_payer = load_fee_payer(self.fee_payer_signer)?;
 
                 ibc.subcommand
-                    .execute(db_pool, signer, sender, color_choice)
+                    .execute(db_pool, signer, sender, policy, fee_payer, color_choice)
                     .await?;
 
                 handle
@@ -166,10 +256,15 @@ impl Command {
                     "Initialized solo machine!",
                 )
             }
-            SubCommand::Start { addr } => {
+            SubCommand::Start {
+                addr,
+                packet_poll_interval,
+                ack_retry_interval,
+                memo,
+            } => {
                 ensure!(
-                    self.signer.is_some(),
-                    "`signer` is required for gRPC server"
+                    self.signer.is_some() || self.multisig.multisig_threshold.is_some(),
+                    "`signer` or `multisig-threshold` is required for gRPC server"
                 );
                 ensure!(self.db_uri.is_some(), "`db-uri` is required");
 
@@ -178,9 +273,24 @@ impl Command {
                 handler_registrar.register(Box::new(EnvLogger::new()));
                 let (sender, handle) = handler_registrar.spawn();
 
-                let signer = SignerRegistrar::try_from(self.signer.unwrap())?.unwrap()?;
+                let collector = SignatureCollector::new();
+                let signer = load_signer(self.signer, self.multisig, Some(&collector))?;
+                let policy = load_policy(self.policy)?;
+                let fee_payer = load_fee_payer(self.fee_payer_signer)?;
 
-                start_grpc(db_pool, signer, sender, addr).await?;
+                start_grpc(
+                    db_pool,
+                    signer,
+                    sender,
+                    policy,
+                    fee_payer,
+                    collector,
+                    addr,
+                    packet_poll_interval,
+                    ack_retry_interval,
+                    memo,
+                )
+                .await?;
 
                 handle
                     .await
@@ -190,6 +300,68 @@ impl Command {
     }
 }
 
+/// Loads signer from given file (or a multisig signer if multisig threshold is provided). Multisig operators are only
+/// supported if a signature collector is provided.
+fn load_signer(
+    file: Option<PathBuf>,
+    multisig: MultisigArgs,
+    collector: Option<&SignatureCollector>,
+) -> Result<Arc<dyn Signer>> {
+    let threshold = match multisig.multisig_threshold {
+        None => {
+            return SignerRegistrar::try_from(file.ok_or_else(|| anyhow!("`signer` is required"))?)?
+                .unwrap()
+        }
+        Some(threshold) => threshold,
+    };
+
+    let mut members = Vec::new();
+
+    for (index, file) in multisig.multisig_signer.into_iter().enumerate() {
+        members.push(SignerRegistrar::multisig_member(file, index + 1)?.unwrap()?);
+    }
+
+    if !multisig.multisig_operator.is_empty() {
+        let collector = collector
+            .ok_or_else(|| anyhow!("`multisig-operator` is only supported by gRPC server"))?;
+
+        for operator in multisig.multisig_operator {
+            let public_key_bytes = hex::decode(&operator).context("unable to decode hex bytes")?;
+            let verifying_key = VerifyingKey::from_sec1_bytes(&public_key_bytes)
+                .context("invalid secp256k1 bytes")?;
+
+            let member: Arc<dyn Signer> = Arc::new(OperatorSigner::new(
+                PublicKey::Secp256k1(verifying_key),
+                multisig.multisig_account_prefix.clone(),
+                collector.clone(),
+                multisig.multisig_operator_timeout,
+            ));
+
+            members.push(member);
+        }
+    }
+
+    Ok(Arc::new(MultisigSigner::new(
+        threshold,
+        members,
+        multisig.multisig_account_prefix,
+    )?))
+}
+
+/// Loads acceptance policy from given file (accepts all the packets if no file is provided)
+fn load_policy(file: Option<PathBuf>) -> Result<Arc<dyn AcceptancePolicy>> {
+    match file {
+        None => Ok(Arc::new(AcceptAll)),
+        Some(file) => PolicyRegistrar::try_from(file)?.unwrap(),
+    }
+}
+
+/// Loads fee payer signer from given file (fees are paid by signer of transaction if no file is provided)
+fn load_fee_payer(file: Option<PathBuf>) -> Result<Option<Arc<dyn Signer>>> {
+    file.map(|file| SignerRegistrar::fee_payer(file)?.unwrap())
+        .transpose()
+}
+
 fn add_row(table: &mut Vec<RowStruct>, title: &str, value: impl Display) {
     table.push(vec![title.cell().bold(true), value.cell()].row());
 }
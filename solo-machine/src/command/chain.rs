@@ -6,8 +6,12 @@ use humantime::format_duration;
 use num_rational::Ratio;
 use rust_decimal::Decimal;
 use solo_machine_core::{
-    ibc::core::ics24_host::identifier::{ChainId, Identifier, PortId},
-    model::{ChainConfig, ChainKey, Fee},
+    ibc::{
+        apps::transfer::denom::Denom,
+        client::ics06_solo_machine::SoloMachineVersion,
+        core::ics24_host::identifier::{ChainId, ChannelId, Identifier, PortId},
+    },
+    model::{BroadcastMode, ChainConfig, ChainKey, ConnectionDetails, Fee, GasMode},
     service::ChainService,
     DbPool, Event, ToPublicKey,
 };
@@ -19,6 +23,7 @@ use tokio::sync::mpsc::UnboundedSender;
 use crate::command::add_row;
 
 #[derive(Debug, StructOpt)]
+#[allow(clippy::large_enum_variant)]
 pub enum ChainCommand {
     /// Adds metadata for new IBC enabled chain
     Add {
@@ -62,6 +67,30 @@ pub enum ChainCommand {
             hide_env_values = true
         )]
         gas_limit: u64,
+        /// Simulate transactions to estimate their gas limit and fee (static fee and gas limit are used if simulation
+        /// fails)
+        #[structopt(long)]
+        simulate_gas: bool,
+        /// Multiplier applied to simulated gas to compute gas limit (only used with `--simulate-gas`)
+        #[structopt(
+            long,
+            default_value = "1.5",
+            env = "SOLO_GAS_ADJUSTMENT",
+            hide_env_values = true
+        )]
+        gas_adjustment: Decimal,
+        /// Price of gas in fee denom used to compute fee (only used with `--simulate-gas`)
+        #[structopt(
+            long,
+            default_value = "0.01",
+            env = "SOLO_GAS_PRICE",
+            hide_env_values = true
+        )]
+        gas_price: Decimal,
+        /// Address of fee granter which pays fees of transactions using feegrant module (the granter must have
+        /// granted a fee allowance to fee payer)
+        #[structopt(long, env = "SOLO_FEE_GRANTER", hide_env_values = true)]
+        fee_granter: Option<String>,
         /// Trust level (e.g. 1/3)
         #[structopt(
             long,
@@ -119,6 +148,54 @@ pub enum ChainCommand {
         /// Block hash at trusted height of the chain
         #[structopt(long, env = "SOLO_TRUSTED_HASH", hide_env_values = true, parse(try_from_str = parse_trusted_hash))]
         trusted_hash: [u8; 32],
+        /// Number of blocks after which an IBC packet times out
+        #[structopt(
+            long,
+            default_value = "10",
+            env = "SOLO_PACKET_TIMEOUT_HEIGHT_OFFSET",
+            hide_env_values = true
+        )]
+        packet_timeout_height_offset: u64,
+        /// Duration after which an IBC packet times out (zero disables timestamp based timeouts)
+        #[structopt(
+            long,
+            default_value = "0 sec",
+            env = "SOLO_PACKET_TIMEOUT_TIMESTAMP_OFFSET",
+            hide_env_values = true,
+            parse(try_from_str = humantime::parse_duration)
+        )]
+        packet_timeout_timestamp_offset: Duration,
+        /// Strategy for broadcasting transactions (`commit` waits for inclusion of transaction in a block using
+        /// `broadcast_tx_commit`, `sync` and `async` broadcast transaction using `broadcast_tx_sync` and
+        /// `broadcast_tx_async` and then poll it by hash until it is included in a block)
+        #[structopt(
+            long,
+            default_value = "commit",
+            possible_values = &["commit", "sync", "async"],
+            env = "SOLO_BROADCAST_MODE",
+            hide_env_values = true
+        )]
+        broadcast_mode: String,
+        /// Maximum duration to wait for inclusion of a transaction in a block (only used with `sync` and `async`
+        /// broadcast modes)
+        #[structopt(
+            long,
+            default_value = "60 sec",
+            env = "SOLO_BROADCAST_TIMEOUT",
+            hide_env_values = true,
+            parse(try_from_str = humantime::parse_duration)
+        )]
+        broadcast_timeout: Duration,
+        /// Version of solo machine client protocol spoken with IBC enabled chain (`v1` for cosmos-sdk v0.40 to v0.42,
+        /// `v2` for ibc-go v1 to v6 and `v3` for ibc-go v7 onwards)
+        #[structopt(
+            long,
+            default_value = "v2",
+            possible_values = &["v1", "v2", "v3"],
+            env = "SOLO_MACHINE_VERSION",
+            hide_env_values = true
+        )]
+        solo_machine_version: SoloMachineVersion,
     },
     /// Fetches current state and metadata for an IBC enabled chain
     Get { chain_id: ChainId },
@@ -130,15 +207,25 @@ pub enum ChainCommand {
         #[structopt(long, default_value)]
         offset: u32,
     },
+    /// Fetches all the established connections (and channels) with given chain
+    GetConnections { chain_id: ChainId },
     /// Returns the final denom of a token on solo machine after sending it on given chain
     GetIbcDenom {
         chain_id: ChainId,
-        denom: Identifier,
+        denom: Denom,
+        /// Optional channel ID of solo machine client on IBC enabled chain (if this is not provided, default channel
+        /// of chain will be used)
+        #[structopt(long)]
+        channel_id: Option<ChannelId>,
     },
     /// Fetches balance of given denom on IBC enabled chain
     Balance {
         chain_id: ChainId,
-        denom: Identifier,
+        denom: Denom,
+        /// Optional channel ID of solo machine client on IBC enabled chain (if this is not provided, default channel
+        /// of chain will be used)
+        #[structopt(long)]
+        channel_id: Option<ChannelId>,
     },
 }
 
@@ -159,6 +246,10 @@ impl ChainCommand {
                 fee_amount,
                 fee_denom,
                 gas_limit,
+                simulate_gas,
+                gas_adjustment,
+                gas_price,
+                fee_granter,
                 trust_level,
                 trusting_period,
                 max_clock_drift,
@@ -167,6 +258,11 @@ impl ChainCommand {
                 port_id,
                 trusted_height,
                 trusted_hash,
+                packet_timeout_height_offset,
+                packet_timeout_timestamp_offset,
+                broadcast_mode,
+                broadcast_timeout,
+                solo_machine_version,
             } => {
                 let config = ChainConfig {
                     grpc_addr,
@@ -175,6 +271,7 @@ impl ChainCommand {
                         amount: fee_amount,
                         denom: fee_denom,
                         gas_limit,
+                        granter: fee_granter,
                     },
                     trust_level,
                     trusting_period,
@@ -184,6 +281,18 @@ impl ChainCommand {
                     port_id,
                     trusted_height,
                     trusted_hash,
+                    packet_timeout_height_offset,
+                    packet_timeout_timestamp_offset,
+                    gas_mode: if simulate_gas {
+                        GasMode::Simulate {
+                            gas_adjustment,
+                            gas_price,
+                        }
+                    } else {
+                        GasMode::Static
+                    },
+                    broadcast_mode: BroadcastMode::new(&broadcast_mode, broadcast_timeout)?,
+                    solo_machine_version,
                 };
 
                 chain_service
@@ -204,16 +313,25 @@ impl ChainCommand {
                         stdout.reset().context("unable to reset stdout")
                     }
                     Some(ref chain) => {
+                        let sequence = chain_service.get_sequence(chain).await?;
+                        let packet_sequence = chain_service.get_packet_sequence(chain).await?;
+
                         let mut table = Vec::new();
 
                         add_row(&mut table, "ID", &chain.id);
-                        add_row(&mut table, "Node ID", &chain.node_id);
+                        add_row(&mut table, "Node ID", chain.node_id);
                         add_row(&mut table, "gRPC address", &chain.config.grpc_addr);
                         add_row(&mut table, "RPC address", &chain.config.rpc_addr);
-                        add_row(&mut table, "Fee amount", &chain.config.fee.amount);
+                        add_row(&mut table, "Fee amount", chain.config.fee.amount);
                         add_row(&mut table, "Fee denom", &chain.config.fee.denom);
-                        add_row(&mut table, "Gas limit", &chain.config.fee.gas_limit);
-                        add_row(&mut table, "Trust level", &chain.config.trust_level);
+                        add_row(&mut table, "Gas limit", chain.config.fee.gas_limit);
+                        add_row(&mut table, "Gas mode", &chain.config.gas_mode);
+                        add_row(
+                            &mut table,
+                            "Fee granter",
+                            chain.config.fee.granter.as_deref().unwrap_or("-"),
+                        );
+                        add_row(&mut table, "Trust level", chain.config.trust_level);
                         add_row(
                             &mut table,
                             "Trusting period",
@@ -231,19 +349,29 @@ impl ChainCommand {
                         );
                         add_row(&mut table, "Diversifier", &chain.config.diversifier);
                         add_row(&mut table, "Port ID", &chain.config.port_id);
-                        add_row(&mut table, "Trusted height", &chain.config.trusted_height);
+                        add_row(&mut table, "Trusted height", chain.config.trusted_height);
                         add_row(
                             &mut table,
                             "Trusted hash",
-                            hex::encode_upper(&chain.config.trusted_hash),
+                            hex::encode_upper(chain.config.trusted_hash),
+                        );
+                        add_row(
+                            &mut table,
+                            "Packet timeout height offset",
+                            chain.config.packet_timeout_height_offset,
+                        );
+                        add_row(
+                            &mut table,
+                            "Packet timeout timestamp offset",
+                            format_duration(chain.config.packet_timeout_timestamp_offset),
                         );
+                        add_row(&mut table, "Broadcast mode", chain.config.broadcast_mode);
                         add_row(
                             &mut table,
-                            "Consensus timestamp",
-                            &chain.consensus_timestamp,
+                            "Solo machine version",
+                            chain.config.solo_machine_version,
                         );
-                        add_row(&mut table, "Sequence", &chain.sequence);
-                        add_row(&mut table, "Packet sequence", &chain.packet_sequence);
+                        add_row(&mut table, "Consensus timestamp", chain.consensus_timestamp);
 
                         match chain.connection_details {
                             None => table.push(
@@ -262,11 +390,15 @@ impl ChainCommand {
                                     .row(),
                                 );
 
+                                add_row(&mut table, "Port ID", &connection_details.port_id);
                                 add_row(
                                     &mut table,
                                     "Solo machine client ID",
-                                    &connection_details.solo_machine_channel_id,
+                                    &connection_details.solo_machine_client_id,
                                 );
+                                if let Some(sequence) = sequence {
+                                    add_row(&mut table, "Sequence", sequence);
+                                }
                                 add_row(
                                     &mut table,
                                     "Tendermint client ID",
@@ -292,11 +424,14 @@ impl ChainCommand {
                                     "Tendermint channel ID",
                                     &connection_details.tendermint_channel_id,
                                 );
+                                if let Some(packet_sequence) = packet_sequence {
+                                    add_row(&mut table, "Packet sequence", packet_sequence);
+                                }
                             }
                         }
 
-                        add_row(&mut table, "Created at", &chain.created_at);
-                        add_row(&mut table, "Updated at", &chain.updated_at);
+                        add_row(&mut table, "Created at", chain.created_at);
+                        add_row(&mut table, "Updated at", chain.updated_at);
 
                         print_stdout(table.table().color_choice(color_choice))
                             .context("unable to print table to stdout")
@@ -327,11 +462,35 @@ impl ChainCommand {
 
                 print_stdout(table).context("unable to print table to stdout")
             }
+            Self::GetConnections { ref chain_id } => {
+                let connections = chain_service.get_connections(chain_id).await?;
+
+                let table = connections
+                    .into_iter()
+                    .map(connection_into_row)
+                    .collect::<Vec<RowStruct>>()
+                    .table()
+                    .title(vec![
+                        "Port ID".cell().bold(true),
+                        "Solo machine client ID".cell().bold(true),
+                        "Tendermint client ID".cell().bold(true),
+                        "Solo machine connection ID".cell().bold(true),
+                        "Tendermint connection ID".cell().bold(true),
+                        "Solo machine channel ID".cell().bold(true),
+                        "Tendermint channel ID".cell().bold(true),
+                    ])
+                    .color_choice(color_choice);
+
+                print_stdout(table).context("unable to print table to stdout")
+            }
             Self::GetIbcDenom {
                 ref chain_id,
                 ref denom,
+                ref channel_id,
             } => {
-                let ibc_denom = chain_service.get_ibc_denom(chain_id, denom).await?;
+                let ibc_denom = chain_service
+                    .get_ibc_denom(chain_id, channel_id.as_ref(), denom)
+                    .await?;
 
                 let table = vec![vec![
                     "IBC denom".cell().bold(true),
@@ -346,8 +505,14 @@ impl ChainCommand {
 
                 print_stdout(table).context("unable to print table to stdout")
             }
-            Self::Balance { chain_id, denom } => {
-                let balance = chain_service.balance(signer, &chain_id, &denom).await?;
+            Self::Balance {
+                chain_id,
+                denom,
+                channel_id,
+            } => {
+                let balance = chain_service
+                    .balance(signer, &chain_id, channel_id.as_ref(), &denom)
+                    .await?;
 
                 let table = vec![vec![
                     "Balance".cell().bold(true),
@@ -383,3 +548,16 @@ fn into_row(key: ChainKey) -> RowStruct {
     ]
     .row()
 }
+
+fn connection_into_row(connection_details: ConnectionDetails) -> RowStruct {
+    vec![
+        connection_details.port_id.cell(),
+        connection_details.solo_machine_client_id.cell(),
+        connection_details.tendermint_client_id.cell(),
+        connection_details.solo_machine_connection_id.cell(),
+        connection_details.tendermint_connection_id.cell(),
+        connection_details.solo_machine_channel_id.cell(),
+        connection_details.tendermint_channel_id.cell(),
+    ]
+    .row()
+}
@@ -1,3 +1,8 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
 use anyhow::{Context, Result};
 use cli_table::{
     format::Justify, print_stdout, Cell, Color, ColorChoice, Row, RowStruct, Style, Table,
@@ -5,15 +10,25 @@ use cli_table::{
 use k256::ecdsa::VerifyingKey;
 use solo_machine_core::{
     cosmos::crypto::{PublicKey, PublicKeyAlgo},
-    ibc::core::ics24_host::identifier::{ChainId, Identifier},
-    model::{Operation, OperationType},
+    ibc::{
+        apps::transfer::{amount::Amount, denom::Denom, forward::ForwardMetadata},
+        core::{
+            ics04_channel::channel::ChannelOrdering,
+            ics24_host::identifier::{ChainId, ChannelId, ConnectionId, PortId},
+        },
+    },
+    model::{Operation, OperationType, PendingTransactionStatus},
+    policy::AcceptancePolicy,
     service::IbcService,
+    signer::offline::UnsignedMint,
     DbPool, Event, Signer,
 };
 use structopt::StructOpt;
-use tokio::sync::mpsc::UnboundedSender;
+use tokio::{fs, sync::mpsc::UnboundedSender};
 
 const PUBLIC_KEY_ALGO_VARIANTS: [&str; 2] = ["secp256k1", "eth-secp256k1"];
+const CHANNEL_ORDERING_VARIANTS: [&str; 2] = ["unordered", "ordered"];
+const CLIENT_UPDATE_PROPOSAL_TYPE_URL: &str = "/ibc.core.client.v1.ClientUpdateProposal";
 
 #[derive(Debug, StructOpt)]
 pub enum IbcCommand {
@@ -32,17 +47,88 @@ pub enum IbcCommand {
         /// Force create a new connection even if one already exists
         #[structopt(long)]
         force: bool,
+        /// Resume an interrupted connection handshake from its last confirmed step
+        #[structopt(long, conflicts_with = "force")]
+        resume: bool,
+    },
+    /// Opens a new IBC channel on existing connection with an IBC enabled chain
+    OpenChannel {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Port ID of new channel (on both solo machine and IBC enabled chain)
+        #[structopt(long, default_value = "transfer")]
+        port_id: PortId,
+        /// Ordering of packets in new channel
+        #[structopt(long, possible_values = &CHANNEL_ORDERING_VARIANTS, default_value = "unordered")]
+        ordering: ChannelOrdering,
+        /// Version of new channel
+        #[structopt(long, default_value = "ics20-1")]
+        version: String,
+        /// Optional memo to include in transactions
+        #[structopt(
+            long,
+            default_value = "solo-machine-memo",
+            env = "SOLO_MEMO",
+            hide_env_values = true
+        )]
+        memo: String,
+    },
+    /// Accepts an IBC connection initialized by counterparty on IBC enabled chain
+    AcceptConnection {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Connection ID of solo machine client on IBC enabled chain (in INIT state)
+        connection_id: ConnectionId,
+        /// Optional memo to include in transactions
+        #[structopt(
+            long,
+            default_value = "solo-machine-memo",
+            env = "SOLO_MEMO",
+            hide_env_values = true
+        )]
+        memo: String,
+    },
+    /// Accepts an IBC channel initialized by counterparty on IBC enabled chain
+    AcceptChannel {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Channel ID of solo machine client on IBC enabled chain (in INIT state)
+        channel_id: ChannelId,
+        /// Port ID of channel (on both solo machine and IBC enabled chain)
+        #[structopt(long, default_value = "transfer")]
+        port_id: PortId,
+        /// Optional memo to include in transactions
+        #[structopt(
+            long,
+            default_value = "solo-machine-memo",
+            env = "SOLO_MEMO",
+            hide_env_values = true
+        )]
+        memo: String,
     },
     /// Mint some tokens on IBC enabled chain
     Mint {
         /// Chain ID of IBC enabled chain
         chain_id: ChainId,
         /// Amount to send to IBC enabled chain
-        amount: u64,
+        amount: Amount,
         /// Denom of tokens to send to IBC enabled chain
-        denom: Identifier,
+        denom: Denom,
         /// Optional receiver address (if this is not provided, tokens will be sent to signer's address)
         receiver: Option<String>,
+        /// Optional memo to include in packet data (different from the memo of transactions)
+        #[structopt(long, conflicts_with = "forward-receiver")]
+        packet_memo: Option<String>,
+        /// Optional address of receiver on another chain to which IBC enabled chain forwards the tokens (using
+        /// packet-forward middleware)
+        #[structopt(long, requires = "forward-channel")]
+        forward_receiver: Option<String>,
+        /// Port ID of channel (on IBC enabled chain) over which tokens are forwarded
+        #[structopt(long, default_value = "transfer")]
+        forward_port: PortId,
+        /// Channel ID of channel (on IBC enabled chain) over which tokens are forwarded
+        #[structopt(long, requires = "forward-receiver")]
+        forward_channel: Option<ChannelId>,
         /// Optional memo to include in transactions
         #[structopt(
             long,
@@ -54,15 +140,52 @@ pub enum IbcCommand {
         /// Optional request ID (for tracking purposes)
         #[structopt(long)]
         request_id: Option<String>,
+        /// Optional channel ID of solo machine client on IBC enabled chain (if this is not provided, default channel
+        /// of chain will be used)
+        #[structopt(long)]
+        channel_id: Option<ChannelId>,
+        /// Only generates unsigned mint (as JSON) whose sign bytes (of packet commitment proof) can be signed offline.
+        /// Sign doc of transaction is then added using `ibc prepare-tx` and the mint is submitted using `ibc submit`
+        /// once the sign doc is also signed offline
+        #[structopt(long)]
+        generate_only: bool,
+        /// Optional file to which unsigned mint is written (if this is not provided, it is printed to stdout)
+        #[structopt(long, requires = "generate-only")]
+        output: Option<PathBuf>,
+    },
+    /// Adds sign doc of transaction sent to IBC enabled chain to a mint generated using `ibc mint --generate-only`
+    /// (after filling signature over its sign bytes). Sign doc must be signed offline by the key of `SOLO_SIGNER` (which
+    /// also pays the fees)
+    PrepareTx {
+        /// Path of JSON file containing unsigned mint along with hex encoded signature over its sign bytes
+        file: PathBuf,
+        /// Optional memo to include in transactions
+        #[structopt(
+            long,
+            default_value = "solo-machine-memo",
+            env = "SOLO_MEMO",
+            hide_env_values = true
+        )]
+        memo: String,
+        /// Optional file to which unsigned mint is written (if this is not provided, it is printed to stdout)
+        #[structopt(long)]
+        output: Option<PathBuf>,
+    },
+    /// Submits a mint prepared using `ibc mint --generate-only` and `ibc prepare-tx` (after filling signatures over its
+    /// sign bytes and sign doc). Transaction is assembled from the signed sign doc, nothing is signed online
+    Submit {
+        /// Path of JSON file containing unsigned mint along with hex encoded signatures over its sign bytes and sign
+        /// doc
+        file: PathBuf,
     },
     /// Burn some tokens on IBC enabled chain
     Burn {
         /// Chain ID of IBC enabled chain
         chain_id: ChainId,
         /// Amount to receive from IBC enabled chain
-        amount: u64,
+        amount: Amount,
         /// Denom of tokens to receive from IBC enabled chain
-        denom: Identifier,
+        denom: Denom,
         /// Optional memo to include in transactions
         #[structopt(
             long,
@@ -74,6 +197,10 @@ pub enum IbcCommand {
         /// Optional request ID (for tracking purposes)
         #[structopt(long)]
         request_id: Option<String>,
+        /// Optional channel ID of solo machine client on IBC enabled chain (if this is not provided, default channel
+        /// of chain will be used)
+        #[structopt(long)]
+        channel_id: Option<ChannelId>,
     },
     /// Updates signer's public key on IBC enabled chain for future messages from solo machine
     UpdateSigner {
@@ -94,6 +221,129 @@ pub enum IbcCommand {
         )]
         memo: String,
     },
+    /// Updates diversifier of solo machine on IBC enabled chain for future messages from solo machine (optionally along
+    /// with signer's public key)
+    UpdateDiversifier {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// New diversifier
+        #[structopt(long)]
+        new_diversifier: String,
+        /// Optional hex encoded public key (signer's public key is updated in the same header if this is provided)
+        #[structopt(long)]
+        new_public_key: Option<String>,
+        /// Type of public key
+        #[structopt(long, possible_values = &PUBLIC_KEY_ALGO_VARIANTS, default_value = "secp256k1", env = "SOLO_PUBLIC_KEY_ALGO", hide_env_values = true)]
+        public_key_algo: PublicKeyAlgo,
+        /// Optional memo to include in transactions
+        #[structopt(
+            long,
+            default_value = "solo-machine-memo",
+            env = "SOLO_MEMO",
+            hide_env_values = true
+        )]
+        memo: String,
+    },
+    /// Closes IBC channel with an IBC enabled chain
+    CloseChannel {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Optional memo to include in transactions
+        #[structopt(
+            long,
+            default_value = "solo-machine-memo",
+            env = "SOLO_MEMO",
+            hide_env_values = true
+        )]
+        memo: String,
+        /// Optional channel ID of solo machine client on IBC enabled chain (if this is not provided, default channel
+        /// of chain will be used)
+        #[structopt(long)]
+        channel_id: Option<ChannelId>,
+    },
+    /// Updates tendermint client on solo machine to latest verified header of IBC enabled chain
+    UpdateClient {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Optional channel ID of solo machine client on IBC enabled chain (if this is not provided, default channel
+        /// of chain will be used)
+        #[structopt(long)]
+        channel_id: Option<ChannelId>,
+    },
+    /// Checks status of solo machine client on IBC enabled chain (e.g., whether it is frozen)
+    ClientStatus {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Optional channel ID of solo machine client on IBC enabled chain (if this is not provided, default channel
+        /// of chain will be used)
+        #[structopt(long)]
+        channel_id: Option<ChannelId>,
+    },
+    /// Creates a substitute solo machine client (with signer's public key) for recovering frozen solo machine client
+    /// and prints the `ClientUpdateProposal` to be submitted to governance of IBC enabled chain
+    CreateSubstituteClient {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Optional channel ID of solo machine client on IBC enabled chain (if this is not provided, default channel
+        /// of chain will be used)
+        #[structopt(long)]
+        channel_id: Option<ChannelId>,
+        /// Title of client update proposal
+        #[structopt(long, default_value = "Recover solo machine client")]
+        title: String,
+        /// Description of client update proposal
+        #[structopt(
+            long,
+            default_value = "Replaces the state of frozen solo machine client with the state of substitute client"
+        )]
+        description: String,
+        /// Optional memo to include in transactions
+        #[structopt(
+            long,
+            default_value = "solo-machine-memo",
+            env = "SOLO_MEMO",
+            hide_env_values = true
+        )]
+        memo: String,
+        /// Optional file to write client update proposal to (printed to stdout if not provided)
+        #[structopt(long)]
+        output: Option<PathBuf>,
+    },
+    /// Re-points solo machine to recovered solo machine client after client update proposal passed on IBC enabled
+    /// chain (signer must be the one used for creating substitute client)
+    RecoverClient {
+        /// Chain ID of IBC enabled chain
+        chain_id: ChainId,
+        /// Optional channel ID of solo machine client on IBC enabled chain (if this is not provided, default channel
+        /// of chain will be used)
+        #[structopt(long)]
+        channel_id: Option<ChannelId>,
+    },
+    /// Retries acknowledging pending packets sent from IBC enabled chain to solo machine (which failed earlier)
+    RetryAcks {
+        /// Optional chain ID of IBC enabled chain (if this is not provided, pending packets of all the chains are
+        /// retried)
+        #[structopt(long)]
+        chain_id: Option<ChainId>,
+        /// Retries all the pending packets immediately (including the ones which failed after all the retry attempts)
+        #[structopt(long)]
+        force: bool,
+        /// Optional memo to include in transactions
+        #[structopt(
+            long,
+            default_value = "solo-machine-memo",
+            env = "SOLO_MEMO",
+            hide_env_values = true
+        )]
+        memo: String,
+    },
+    /// Reconciles a transaction whose outcome was unknown when it was sent to IBC enabled chain (i.e., it was not
+    /// included in a block before timeout). Its operation is finalized if the transaction is included in a block (or
+    /// voided if the transaction failed).
+    Reconcile {
+        /// Hash of transaction on IBC enabled chain (in hex)
+        transaction_hash: String,
+    },
     /// Check history of operations on solo machine
     History {
         #[structopt(long, default_value = "10")]
@@ -109,35 +359,134 @@ impl IbcCommand {
         db_pool: DbPool,
         signer: impl Signer,
         sender: UnboundedSender<Event>,
+        policy: Arc<dyn AcceptancePolicy>,
+        fee_payer: Option<Arc<dyn Signer>>,
         color_choice: ColorChoice,
     ) -> Result<()> {
-        let ibc_service = IbcService::new_with_notifier(db_pool, sender);
+        let mut ibc_service = IbcService::new_with_notifier(db_pool, sender).with_policy(policy);
+
+        if let Some(fee_payer) = fee_payer {
+            ibc_service = ibc_service.with_fee_payer(fee_payer);
+        }
 
         match self {
             Self::Connect {
                 chain_id,
                 memo,
                 force,
-            } => ibc_service.connect(signer, chain_id, memo, force).await,
+                resume,
+            } => {
+                ibc_service
+                    .connect(signer, chain_id, memo, force, resume)
+                    .await
+            }
+            Self::OpenChannel {
+                chain_id,
+                port_id,
+                ordering,
+                version,
+                memo,
+            } => ibc_service
+                .open_channel(signer, chain_id, port_id, ordering, version, memo)
+                .await
+                .map(|_| ()),
+            Self::AcceptConnection {
+                chain_id,
+                connection_id,
+                memo,
+            } => ibc_service
+                .accept_connection(signer, chain_id, connection_id, memo)
+                .await
+                .map(|_| ()),
+            Self::AcceptChannel {
+                chain_id,
+                channel_id,
+                port_id,
+                memo,
+            } => ibc_service
+                .accept_channel(signer, chain_id, port_id, channel_id, memo)
+                .await
+                .map(|_| ()),
             Self::Mint {
                 chain_id,
                 amount,
                 denom,
                 receiver,
+                packet_memo,
+                forward_receiver,
+                forward_port,
+                forward_channel,
                 memo,
                 request_id,
-            } => ibc_service
-                .mint(signer, chain_id, request_id, amount, denom, receiver, memo)
-                .await
-                .map(|_| ()),
+                channel_id,
+                generate_only,
+                output,
+            } => {
+                let packet_memo = match (forward_receiver, forward_channel) {
+                    (Some(forward_receiver), Some(forward_channel)) => Some(
+                        ForwardMetadata::new(forward_receiver, forward_port, forward_channel)
+                            .to_memo()?,
+                    ),
+                    _ => packet_memo,
+                };
+
+                if generate_only {
+                    let unsigned_mint = ibc_service
+                        .prepare_mint(
+                            signer,
+                            chain_id,
+                            channel_id,
+                            request_id,
+                            amount,
+                            denom,
+                            receiver,
+                            packet_memo,
+                        )
+                        .await?;
+
+                    return write_unsigned_mint(&unsigned_mint, output).await;
+                }
+
+                ibc_service
+                    .mint(
+                        signer,
+                        chain_id,
+                        channel_id,
+                        request_id,
+                        amount,
+                        denom,
+                        receiver,
+                        packet_memo,
+                        memo,
+                    )
+                    .await
+                    .map(|_| ())
+            }
+            Self::PrepareTx { file, memo, output } => {
+                let unsigned_mint = read_unsigned_mint(&file).await?;
+
+                let unsigned_mint = ibc_service
+                    .prepare_mint_transaction(signer, unsigned_mint, memo)
+                    .await?;
+
+                write_unsigned_mint(&unsigned_mint, output).await
+            }
+            Self::Submit { file } => {
+                let unsigned_mint = read_unsigned_mint(&file).await?;
+
+                ibc_service.submit_mint(unsigned_mint).await.map(|_| ())
+            }
             Self::Burn {
                 chain_id,
                 amount,
                 denom,
                 memo,
                 request_id,
+                channel_id,
             } => ibc_service
-                .burn(signer, chain_id, request_id, amount, denom, memo)
+                .burn(
+                    signer, chain_id, channel_id, request_id, amount, denom, memo,
+                )
                 .await
                 .map(|_| ()),
             Self::UpdateSigner {
@@ -146,22 +495,153 @@ impl IbcCommand {
                 public_key_algo,
                 memo,
             } => {
-                let new_public_key_bytes =
-                    hex::decode(&new_public_key).context("unable to decode hex bytes")?;
-
-                let new_verifying_key = VerifyingKey::from_sec1_bytes(&new_public_key_bytes)
-                    .context("invalid secp256k1 bytes")?;
-
-                let new_public_key = match public_key_algo {
-                    PublicKeyAlgo::Secp256k1 => PublicKey::Secp256k1(new_verifying_key),
-                    #[cfg(feature = "ethermint")]
-                    PublicKeyAlgo::EthSecp256k1 => PublicKey::EthSecp256k1(new_verifying_key),
-                };
+                let new_public_key = parse_public_key(&new_public_key, public_key_algo)?;
 
                 ibc_service
                     .update_signer(signer, chain_id, new_public_key, memo)
                     .await
             }
+            Self::UpdateDiversifier {
+                chain_id,
+                new_diversifier,
+                new_public_key,
+                public_key_algo,
+                memo,
+            } => {
+                let new_public_key = new_public_key
+                    .map(|new_public_key| parse_public_key(&new_public_key, public_key_algo))
+                    .transpose()?;
+
+                ibc_service
+                    .update_diversifier(signer, chain_id, new_diversifier, new_public_key, memo)
+                    .await
+            }
+            Self::CloseChannel {
+                chain_id,
+                memo,
+                channel_id,
+            } => {
+                ibc_service
+                    .close_channel(signer, chain_id, channel_id, memo)
+                    .await
+            }
+            Self::UpdateClient {
+                chain_id,
+                channel_id,
+            } => ibc_service
+                .update_tendermint_client(chain_id, channel_id)
+                .await
+                .map(|_| ()),
+            Self::ClientStatus {
+                chain_id,
+                channel_id,
+            } => {
+                let status = ibc_service.client_status(chain_id, channel_id).await?;
+
+                let table = vec![
+                    vec!["Client ID".cell().bold(true), status.client_id.cell()],
+                    vec!["Sequence".cell().bold(true), status.sequence.cell()],
+                    vec![
+                        "Frozen".cell().bold(true),
+                        status
+                            .frozen
+                            .cell()
+                            .foreground_color(status.frozen.then_some(Color::Red)),
+                    ],
+                    vec![
+                        "Public Key".cell().bold(true),
+                        status
+                            .public_key
+                            .map(|public_key| public_key.encode())
+                            .unwrap_or_else(|| "-".to_string())
+                            .cell(),
+                    ],
+                ]
+                .table()
+                .color_choice(color_choice);
+
+                print_stdout(table).context("unable to print table to stdout")
+            }
+            Self::CreateSubstituteClient {
+                chain_id,
+                channel_id,
+                title,
+                description,
+                memo,
+                output,
+            } => {
+                let proposal = ibc_service
+                    .create_substitute_client(
+                        signer,
+                        chain_id,
+                        channel_id,
+                        title,
+                        description,
+                        memo,
+                    )
+                    .await?;
+
+                let json = serde_json::to_string_pretty(&serde_json::json!({
+                    "@type": CLIENT_UPDATE_PROPOSAL_TYPE_URL,
+                    "title": proposal.title,
+                    "description": proposal.description,
+                    "subject_client_id": proposal.subject_client_id,
+                    "substitute_client_id": proposal.substitute_client_id,
+                }))
+                .context("unable to serialize client update proposal")?;
+
+                match output {
+                    None => {
+                        println!("{}", json);
+                        Ok(())
+                    }
+                    Some(output) => fs::write(&output, json)
+                        .await
+                        .context(format!("unable to write to {}", output.display())),
+                }
+            }
+            Self::RecoverClient {
+                chain_id,
+                channel_id,
+            } => ibc_service
+                .recover_client(signer, chain_id, channel_id)
+                .await
+                .map(|_| ()),
+            Self::RetryAcks {
+                chain_id,
+                force,
+                memo,
+            } => ibc_service.retry_acks(signer, chain_id, force, memo).await,
+            Self::Reconcile { transaction_hash } => {
+                let pending = ibc_service.reconcile(&transaction_hash).await?;
+
+                let status_color = match pending.status {
+                    PendingTransactionStatus::Pending => Color::Yellow,
+                    PendingTransactionStatus::Finalized => Color::Green,
+                    PendingTransactionStatus::Voided => Color::Red,
+                };
+
+                let table = vec![
+                    vec![
+                        "Transaction Hash".cell().bold(true),
+                        pending.transaction_hash.cell(),
+                    ],
+                    vec!["Chain ID".cell().bold(true), pending.chain_id.cell()],
+                    vec![
+                        "Request ID".cell().bold(true),
+                        pending.request_id.unwrap_or_else(|| "-".to_string()).cell(),
+                    ],
+                    vec!["Operation".cell().bold(true), pending.operation.cell()],
+                    vec![
+                        "Status".cell().bold(true),
+                        pending.status.cell().foreground_color(Some(status_color)),
+                    ],
+                ]
+                .table()
+                .color_choice(color_choice);
+
+                print_stdout(table).context("unable to print table to stdout")
+            }
             Self::History { limit, offset } => {
                 let history = ibc_service.history(signer, limit, offset).await?;
 
@@ -213,6 +693,45 @@ fn into_row(operation: Operation) -> RowStruct {
 fn get_color_for_operation_type(operation_type: &OperationType) -> Color {
     match operation_type {
         OperationType::Mint { .. } => Color::Green,
+        OperationType::MintFailed { .. } => Color::Magenta,
         OperationType::Burn { .. } => Color::Red,
+        OperationType::Refund { .. } => Color::Yellow,
+        OperationType::Redeem { .. } => Color::Cyan,
+    }
+}
+
+fn parse_public_key(public_key: &str, public_key_algo: PublicKeyAlgo) -> Result<PublicKey> {
+    let public_key_bytes = hex::decode(public_key).context("unable to decode hex bytes")?;
+
+    let verifying_key =
+        VerifyingKey::from_sec1_bytes(&public_key_bytes).context("invalid secp256k1 bytes")?;
+
+    match public_key_algo {
+        PublicKeyAlgo::Secp256k1 => Ok(PublicKey::Secp256k1(verifying_key)),
+        #[cfg(feature = "ethermint")]
+        PublicKeyAlgo::EthSecp256k1 => Ok(PublicKey::EthSecp256k1(verifying_key)),
+    }
+}
+
+async fn read_unsigned_mint(file: &Path) -> Result<UnsignedMint> {
+    let json = fs::read(file)
+        .await
+        .context(format!("unable to read {}", file.display()))?;
+
+    serde_json::from_slice(&json).context("unable to parse unsigned mint")
+}
+
+async fn write_unsigned_mint(unsigned_mint: &UnsignedMint, output: Option<PathBuf>) -> Result<()> {
+    let json =
+        serde_json::to_string_pretty(unsigned_mint).context("unable to serialize unsigned mint")?;
+
+    match output {
+        None => {
+            println!("{}", json);
+            Ok(())
+        }
+        Some(output) => fs::write(&output, json)
+            .await
+            .context(format!("unable to write to {}", output.display())),
     }
 }
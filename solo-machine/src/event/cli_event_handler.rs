@@ -77,6 +77,39 @@ impl EventHandler for CliEventHandler {
                 print_stdout(table.table().color_choice(self.color_choice))
                     .context("unable to print table to stdout")?;
             }
+            Event::MintFailed {
+                chain_id,
+                request_id,
+                to_address,
+                amount,
+                denom,
+                error,
+                transaction_hash,
+            } => {
+                print_stream(
+                    &mut stdout,
+                    ColorSpec::new().set_bold(true).set_fg(Some(Color::Red)),
+                    "Mint failed!",
+                )?;
+                writeln!(stdout)?;
+
+                let mut table = Vec::new();
+
+                add_row(&mut table, "Chain ID", chain_id);
+                add_row(
+                    &mut table,
+                    "Request ID",
+                    request_id.as_deref().unwrap_or("-"),
+                );
+                add_row(&mut table, "To", to_address);
+                add_row(&mut table, "Amount", amount);
+                add_row(&mut table, "Denom", denom);
+                add_row(&mut table, "Error", error);
+                add_row(&mut table, "Transaction Hash", transaction_hash);
+
+                print_stdout(table.table().color_choice(self.color_choice))
+                    .context("unable to print table to stdout")?;
+            }
             Event::TokensBurnt {
                 chain_id,
                 request_id,
@@ -108,6 +141,95 @@ impl EventHandler for CliEventHandler {
                 print_stdout(table.table().color_choice(self.color_choice))
                     .context("unable to print table to stdout")?;
             }
+            Event::TokensRefunded {
+                chain_id,
+                request_id,
+                to_address,
+                amount,
+                denom,
+                transaction_hash,
+            } => {
+                print_stream(
+                    &mut stdout,
+                    ColorSpec::new().set_bold(true),
+                    "Tokens refunded!",
+                )?;
+                writeln!(stdout)?;
+
+                let mut table = Vec::new();
+
+                add_row(&mut table, "Chain ID", chain_id);
+                add_row(
+                    &mut table,
+                    "Request ID",
+                    request_id.as_deref().unwrap_or("-"),
+                );
+                add_row(&mut table, "To", to_address);
+                add_row(&mut table, "Amount", amount);
+                add_row(&mut table, "Denom", denom);
+                add_row(&mut table, "Transaction Hash", transaction_hash);
+
+                print_stdout(table.table().color_choice(self.color_choice))
+                    .context("unable to print table to stdout")?;
+            }
+            Event::TokensRedeemed {
+                chain_id,
+                from_address,
+                to_address,
+                amount,
+                denom,
+                transaction_hash,
+            } => {
+                print_stream(
+                    &mut stdout,
+                    ColorSpec::new().set_bold(true),
+                    "Tokens redeemed!",
+                )?;
+                writeln!(stdout)?;
+
+                let mut table = Vec::new();
+
+                add_row(&mut table, "Chain ID", chain_id);
+                add_row(&mut table, "From", from_address);
+                add_row(&mut table, "To", to_address);
+                add_row(&mut table, "Amount", amount);
+                add_row(&mut table, "Denom", denom);
+                add_row(&mut table, "Transaction Hash", transaction_hash);
+
+                print_stdout(table.table().color_choice(self.color_choice))
+                    .context("unable to print table to stdout")?;
+            }
+            Event::PacketRejected {
+                chain_id,
+                channel_id,
+                sequence,
+                from_address,
+                amount,
+                denom,
+                reason,
+                transaction_hash,
+            } => {
+                print_stream(
+                    &mut stdout,
+                    ColorSpec::new().set_bold(true).set_fg(Some(Color::Yellow)),
+                    "Packet rejected!",
+                )?;
+                writeln!(stdout)?;
+
+                let mut table = Vec::new();
+
+                add_row(&mut table, "Chain ID", chain_id);
+                add_row(&mut table, "Channel ID", channel_id);
+                add_row(&mut table, "Sequence", sequence);
+                add_row(&mut table, "From", from_address);
+                add_row(&mut table, "Amount", amount);
+                add_row(&mut table, "Denom", denom);
+                add_row(&mut table, "Reason", reason);
+                add_row(&mut table, "Transaction Hash", transaction_hash);
+
+                print_stdout(table.table().color_choice(self.color_choice))
+                    .context("unable to print table to stdout")?;
+            }
             Event::SignerUpdated { chain_id, .. } => {
                 print_stream(
                     &mut stdout,
@@ -123,6 +245,161 @@ impl EventHandler for CliEventHandler {
                 print_stdout(table.table().color_choice(self.color_choice))
                     .context("unable to print table to stdout")?;
             }
+            Event::DiversifierUpdated {
+                chain_id,
+                old_diversifier,
+                new_diversifier,
+            } => {
+                print_stream(
+                    &mut stdout,
+                    ColorSpec::new().set_bold(true),
+                    "Diversifier updated!",
+                )?;
+                writeln!(stdout)?;
+
+                let mut table = Vec::new();
+
+                add_row(&mut table, "Chain ID", chain_id);
+                add_row(&mut table, "Old diversifier", old_diversifier);
+                add_row(&mut table, "New diversifier", new_diversifier);
+
+                print_stdout(table.table().color_choice(self.color_choice))
+                    .context("unable to print table to stdout")?;
+            }
+            Event::TendermintClientUpdated {
+                chain_id,
+                client_id,
+                latest_height,
+            } => {
+                print_stream(
+                    &mut stdout,
+                    ColorSpec::new().set_bold(true),
+                    "Tendermint client updated!",
+                )?;
+                writeln!(stdout)?;
+
+                let mut table = Vec::new();
+
+                add_row(&mut table, "Chain ID", chain_id);
+                add_row(&mut table, "Client ID", client_id);
+                add_row(&mut table, "Latest Height", latest_height);
+
+                print_stdout(table.table().color_choice(self.color_choice))
+                    .context("unable to print table to stdout")?;
+            }
+            Event::PacketAcknowledged {
+                chain_id,
+                channel_id,
+                sequence,
+                transaction_hash,
+            } => {
+                print_stream(
+                    &mut stdout,
+                    ColorSpec::new().set_bold(true),
+                    "Packet acknowledged!",
+                )?;
+                writeln!(stdout)?;
+
+                let mut table = Vec::new();
+
+                add_row(&mut table, "Chain ID", chain_id);
+                add_row(&mut table, "Channel ID", channel_id);
+                add_row(&mut table, "Sequence", sequence);
+                add_row(&mut table, "Transaction Hash", transaction_hash);
+
+                print_stdout(table.table().color_choice(self.color_choice))
+                    .context("unable to print table to stdout")?;
+            }
+            Event::PacketAckFailed {
+                chain_id,
+                channel_id,
+                sequence,
+                attempts,
+                error,
+            } => {
+                print_stream(
+                    &mut stdout,
+                    ColorSpec::new().set_bold(true).set_fg(Some(Color::Red)),
+                    "Packet acknowledgement failed!",
+                )?;
+                writeln!(stdout)?;
+
+                let mut table = Vec::new();
+
+                add_row(&mut table, "Chain ID", chain_id);
+                add_row(&mut table, "Channel ID", channel_id);
+                add_row(&mut table, "Sequence", sequence);
+                add_row(&mut table, "Attempts", attempts);
+                add_row(&mut table, "Error", error);
+
+                print_stdout(table.table().color_choice(self.color_choice))
+                    .context("unable to print table to stdout")?;
+            }
+            Event::ClientFrozen {
+                chain_id,
+                client_id,
+                sequence,
+            } => {
+                print_stream(
+                    &mut stdout,
+                    ColorSpec::new().set_bold(true).set_fg(Some(Color::Red)),
+                    "Solo machine client frozen!",
+                )?;
+                writeln!(stdout)?;
+
+                let mut table = Vec::new();
+
+                add_row(&mut table, "Chain ID", chain_id);
+                add_row(&mut table, "Client ID", client_id);
+                add_row(&mut table, "Sequence", sequence);
+
+                print_stdout(table.table().color_choice(self.color_choice))
+                    .context("unable to print table to stdout")?;
+            }
+            Event::SubstituteClientCreated {
+                chain_id,
+                subject_client_id,
+                substitute_client_id,
+            } => {
+                print_stream(
+                    &mut stdout,
+                    ColorSpec::new().set_bold(true),
+                    "Substitute client created!",
+                )?;
+                writeln!(stdout)?;
+
+                let mut table = Vec::new();
+
+                add_row(&mut table, "Chain ID", chain_id);
+                add_row(&mut table, "Subject Client ID", subject_client_id);
+                add_row(&mut table, "Substitute Client ID", substitute_client_id);
+
+                print_stdout(table.table().color_choice(self.color_choice))
+                    .context("unable to print table to stdout")?;
+            }
+            Event::ClientRecovered {
+                chain_id,
+                client_id,
+                sequence,
+                public_key,
+            } => {
+                print_stream(
+                    &mut stdout,
+                    ColorSpec::new().set_bold(true).set_fg(Some(Color::Green)),
+                    "Solo machine client recovered!",
+                )?;
+                writeln!(stdout)?;
+
+                let mut table = Vec::new();
+
+                add_row(&mut table, "Chain ID", chain_id);
+                add_row(&mut table, "Client ID", client_id);
+                add_row(&mut table, "Sequence", sequence);
+                add_row(&mut table, "Public Key", public_key.encode());
+
+                print_stdout(table.table().color_choice(self.color_choice))
+                    .context("unable to print table to stdout")?;
+            }
             Event::CreatedSoloMachineClient { client_id } => {
                 print_stream(
                     &mut stdout,
@@ -237,6 +514,7 @@ impl EventHandler for CliEventHandler {
                 let mut table = Vec::new();
 
                 add_row(&mut table, "Chain ID", chain_id);
+                add_row(&mut table, "Port ID", connection_details.port_id);
                 add_row(
                     &mut table,
                     "Solo machine client ID",
@@ -271,6 +549,89 @@ impl EventHandler for CliEventHandler {
                 print_stdout(table.table().color_choice(self.color_choice))
                     .context("unable to print table to stdout")?;
             }
+            Event::ConnectionAccepted {
+                chain_id,
+                solo_machine_connection_id,
+                tendermint_connection_id,
+            } => {
+                print_stream(
+                    &mut stdout,
+                    ColorSpec::new().set_bold(true),
+                    "Connection accepted!",
+                )?;
+                writeln!(stdout)?;
+
+                let mut table = Vec::new();
+
+                add_row(&mut table, "Chain ID", chain_id);
+                add_row(
+                    &mut table,
+                    "Solo machine connection ID",
+                    solo_machine_connection_id,
+                );
+                add_row(
+                    &mut table,
+                    "Tendermint connection ID",
+                    tendermint_connection_id,
+                );
+
+                print_stdout(table.table().color_choice(self.color_choice))
+                    .context("unable to print table to stdout")?;
+            }
+            Event::ChannelOpened {
+                chain_id,
+                connection_details,
+            } => {
+                print_stream(
+                    &mut stdout,
+                    ColorSpec::new().set_bold(true),
+                    "Channel opened!",
+                )?;
+                writeln!(stdout)?;
+
+                let mut table = Vec::new();
+
+                add_row(&mut table, "Chain ID", chain_id);
+                add_row(&mut table, "Port ID", connection_details.port_id);
+                add_row(
+                    &mut table,
+                    "Solo machine channel ID",
+                    connection_details.solo_machine_channel_id,
+                );
+                add_row(
+                    &mut table,
+                    "Tendermint channel ID",
+                    connection_details.tendermint_channel_id,
+                );
+
+                print_stdout(table.table().color_choice(self.color_choice))
+                    .context("unable to print table to stdout")?;
+            }
+            Event::ChannelClosed {
+                chain_id,
+                solo_machine_channel_id,
+                tendermint_channel_id,
+            } => {
+                print_stream(
+                    &mut stdout,
+                    ColorSpec::new().set_bold(true),
+                    "Channel closed!",
+                )?;
+                writeln!(stdout)?;
+
+                let mut table = Vec::new();
+
+                add_row(&mut table, "Chain ID", chain_id);
+                add_row(
+                    &mut table,
+                    "Solo machine channel ID",
+                    solo_machine_channel_id,
+                );
+                add_row(&mut table, "Tendermint channel ID", tendermint_channel_id);
+
+                print_stdout(table.table().color_choice(self.color_choice))
+                    .context("unable to print table to stdout")?;
+            }
             Event::Warning { message } => {
                 print_stream(
                     &mut stdout,
@@ -32,6 +32,24 @@ impl EventHandler for EnvLogger {
                 denom,
                 transaction_hash,
             ),
+            Event::MintFailed {
+                chain_id,
+                request_id,
+                to_address,
+                amount,
+                denom,
+                error,
+                transaction_hash,
+            } => log::error!(
+                "Failed to mint tokens [Chain ID = {}] [Request ID = {}] [Address = {}] [Amount = {} {}] [Error = {}] [Transaction Hash = {}]",
+                chain_id,
+                request_id.unwrap_or_else(|| "None".to_string()),
+                to_address,
+                amount,
+                denom,
+                error,
+                transaction_hash,
+            ),
             Event::TokensBurnt {
                 chain_id,
                 request_id,
@@ -48,6 +66,58 @@ impl EventHandler for EnvLogger {
                 denom,
                 transaction_hash,
             ),
+            Event::TokensRefunded {
+                chain_id,
+                request_id,
+                to_address,
+                amount,
+                denom,
+                transaction_hash,
+            } => log::info!(
+                "Refunded tokens [Chain ID = {}] [Request ID = {}] [Address = {}] [Amount = {} {}] [Transaction Hash = {}]",
+                chain_id,
+                request_id.unwrap_or_else(|| "None".to_string()),
+                to_address,
+                amount,
+                denom,
+                transaction_hash,
+            ),
+            Event::TokensRedeemed {
+                chain_id,
+                from_address,
+                to_address,
+                amount,
+                denom,
+                transaction_hash,
+            } => log::info!(
+                "Redeemed tokens [Chain ID = {}] [From = {}] [To = {}] [Amount = {} {}] [Transaction Hash = {}]",
+                chain_id,
+                from_address,
+                to_address,
+                amount,
+                denom,
+                transaction_hash,
+            ),
+            Event::PacketRejected {
+                chain_id,
+                channel_id,
+                sequence,
+                from_address,
+                amount,
+                denom,
+                reason,
+                transaction_hash,
+            } => log::warn!(
+                "Rejected packet [Chain ID = {}] [Channel ID = {}] [Sequence = {}] [From = {}] [Amount = {} {}] [Reason = {}] [Transaction Hash = {}]",
+                chain_id,
+                channel_id,
+                sequence,
+                from_address,
+                amount,
+                denom,
+                reason,
+                transaction_hash,
+            ),
             Event::SignerUpdated {
                 chain_id,
                 old_public_key: _,
@@ -56,6 +126,84 @@ impl EventHandler for EnvLogger {
                 "Successfully updated signer's public key [Chain ID: {}]",
                 chain_id
             ),
+            Event::DiversifierUpdated {
+                chain_id,
+                old_diversifier,
+                new_diversifier,
+            } => log::info!(
+                "Successfully updated diversifier [Chain ID: {}] [Old Diversifier: {}] [New Diversifier: {}]",
+                chain_id,
+                old_diversifier,
+                new_diversifier,
+            ),
+            Event::TendermintClientUpdated {
+                chain_id,
+                client_id,
+                latest_height,
+            } => log::info!(
+                "Updated tendermint client on solo machine [Chain ID = {}] [Client ID = {}] [Latest Height = {}]",
+                chain_id,
+                client_id,
+                latest_height,
+            ),
+            Event::PacketAcknowledged {
+                chain_id,
+                channel_id,
+                sequence,
+                transaction_hash,
+            } => log::info!(
+                "Acknowledged packet [Chain ID = {}] [Channel ID = {}] [Sequence = {}] [Transaction Hash = {}]",
+                chain_id,
+                channel_id,
+                sequence,
+                transaction_hash,
+            ),
+            Event::PacketAckFailed {
+                chain_id,
+                channel_id,
+                sequence,
+                attempts,
+                error,
+            } => log::error!(
+                "Failed to acknowledge packet [Chain ID = {}] [Channel ID = {}] [Sequence = {}] [Attempts = {}] [Error = {}]",
+                chain_id,
+                channel_id,
+                sequence,
+                attempts,
+                error,
+            ),
+            Event::ClientFrozen {
+                chain_id,
+                client_id,
+                sequence,
+            } => log::error!(
+                "Solo machine client is frozen [Chain ID = {}] [Client ID = {}] [Sequence = {}]",
+                chain_id,
+                client_id,
+                sequence,
+            ),
+            Event::SubstituteClientCreated {
+                chain_id,
+                subject_client_id,
+                substitute_client_id,
+            } => log::info!(
+                "Created substitute solo machine client [Chain ID = {}] [Subject Client ID = {}] [Substitute Client ID = {}]",
+                chain_id,
+                subject_client_id,
+                substitute_client_id,
+            ),
+            Event::ClientRecovered {
+                chain_id,
+                client_id,
+                sequence,
+                public_key,
+            } => log::info!(
+                "Recovered solo machine client [Chain ID = {}] [Client ID = {}] [Sequence = {}] [Public Key = {}]",
+                chain_id,
+                client_id,
+                sequence,
+                public_key.encode(),
+            ),
 
             Event::CreatedSoloMachineClient { client_id } => {
                 log::info!(
@@ -107,6 +255,34 @@ impl EventHandler for EnvLogger {
                 chain_id,
                 serde_json::to_string(&connection_details)?
             ),
+            Event::ConnectionAccepted {
+                chain_id,
+                solo_machine_connection_id,
+                tendermint_connection_id,
+            } => log::info!(
+                "Connection accepted [Chain ID = {}] [Solo machine connection ID = {}] [Tendermint connection ID = {}]",
+                chain_id,
+                solo_machine_connection_id,
+                tendermint_connection_id,
+            ),
+            Event::ChannelOpened {
+                chain_id,
+                connection_details,
+            } => log::info!(
+                "Channel opened [Chain ID = {}] [Details = {}]",
+                chain_id,
+                serde_json::to_string(&connection_details)?
+            ),
+            Event::ChannelClosed {
+                chain_id,
+                solo_machine_channel_id,
+                tendermint_channel_id,
+            } => log::info!(
+                "Channel closed [Chain ID = {}] [Solo machine channel ID = {}] [Tendermint channel ID = {}]",
+                chain_id,
+                solo_machine_channel_id,
+                tendermint_channel_id,
+            ),
             Event::ChainAdded { chain_id } => {
                 log::info!("Added new chain [Chain ID = {}]", chain_id)
             }
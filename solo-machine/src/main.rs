@@ -4,6 +4,7 @@ use structopt::StructOpt;
 
 mod command;
 mod event;
+mod policy;
 mod server;
 mod signer;
 
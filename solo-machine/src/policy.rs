@@ -0,0 +1,62 @@
+use std::{convert::TryFrom, ffi::OsStr, path::PathBuf, sync::Arc};
+
+use anyhow::{anyhow, Context, Error, Result};
+use libloading::{Library, Symbol};
+use solo_machine_core::policy::{AcceptancePolicy, PolicyRegistrar as IPolicyRegistrar};
+
+#[derive(Default)]
+pub struct PolicyRegistrar {
+    policy: Option<Arc<dyn AcceptancePolicy>>,
+}
+
+impl PolicyRegistrar {
+    pub fn unwrap(self) -> Result<Arc<dyn AcceptancePolicy>> {
+        self.policy
+            .ok_or_else(|| anyhow!("acceptance policy not registered"))
+    }
+
+    // TODO: remove conditional compilation when this issue is fixed:
+    // https://github.com/nagisa/rust_libloading/issues/41
+    fn register_policy(&mut self, file: impl AsRef<OsStr>) -> Result<()> {
+        unsafe {
+            #[cfg(target_os = "linux")]
+            let library: Library = {
+                // Load library with `RTLD_NOW | RTLD_NODELETE` to fix a SIGSEGV
+                libloading::os::unix::Library::open(
+                    Some(file),
+                    libloading::os::unix::RTLD_NOW | 0x1000,
+                )
+                .context("unable to load acceptance policy")?
+                .into()
+            };
+            #[cfg(not(target_os = "linux"))]
+            let library = Library::new(file).context("unable to load acceptance policy")?;
+
+            let register_fn: Symbol<unsafe extern "C" fn(&mut dyn IPolicyRegistrar) -> Result<()>> =
+                library
+                    .get("register_policy".as_bytes())
+                    .context("unable to load `register_policy` function from acceptance policy")?;
+
+            register_fn(self)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl IPolicyRegistrar for PolicyRegistrar {
+    fn register(&mut self, policy: Arc<dyn AcceptancePolicy>) {
+        self.policy = Some(policy);
+    }
+}
+
+impl TryFrom<PathBuf> for PolicyRegistrar {
+    type Error = Error;
+
+    fn try_from(file: PathBuf) -> Result<Self, Self::Error> {
+        let mut registrar = Self::default();
+        registrar.register_policy(file)?;
+
+        Ok(registrar)
+    }
+}
@@ -1,27 +1,84 @@
 mod chain;
 mod ibc;
+mod multisig;
 
-use std::{net::SocketAddr, time::Duration};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 
 use anyhow::{Context, Result};
-use solo_machine_core::{DbPool, Event, Signer};
+use solo_machine_core::{
+    policy::AcceptancePolicy, service::IbcService as CoreIbcService,
+    signer::multisig::SignatureCollector, DbPool, Event, Signer,
+};
 use tokio::sync::mpsc::UnboundedSender;
 use tonic::transport::Server as GrpcServer;
 
 use self::{
     chain::{chain_server::ChainServer, ChainService},
     ibc::{ibc_server::IbcServer, IbcService},
+    multisig::{multisig_server::MultisigServer, MultisigService},
 };
 
-/// Starts gRPC server
+/// Starts gRPC server (along with a background listener for packets sent from IBC enabled chains and a worker for
+/// retrying failed packet acknowledgements). Operators of multisig signer submit their signatures to given
+/// signature collector using gRPC server.
+#[allow(clippy::too_many_arguments)]
 pub async fn start_grpc(
     db_pool: DbPool,
-    signer: impl Signer + Clone + Send + Sync + 'static,
+    signer: impl Signer + Clone + 'static,
     sender: UnboundedSender<Event>,
+    policy: Arc<dyn AcceptancePolicy>,
+    fee_payer: Option<Arc<dyn Signer>>,
+    collector: SignatureCollector,
     addr: SocketAddr,
+    packet_poll_interval: Duration,
+    ack_retry_interval: Duration,
+    memo: String,
 ) -> Result<()> {
     let chain_service = ChainService::new(db_pool.clone(), sender.clone(), signer.clone());
-    let ibc_service = IbcService::new(db_pool, sender, signer);
+    let mut core_ibc_service =
+        CoreIbcService::new_with_notifier(db_pool, sender).with_policy(policy);
+
+    if let Some(fee_payer) = fee_payer {
+        core_ibc_service = core_ibc_service.with_fee_payer(fee_payer);
+    }
+
+    let packet_listener = core_ibc_service.clone();
+    let packet_signer = signer.clone();
+    let packet_memo = memo.clone();
+
+    log::info!(
+        "starting packet listener with poll interval of {}",
+        humantime::format_duration(packet_poll_interval)
+    );
+
+    tokio::spawn(async move {
+        if let Err(err) = packet_listener
+            .listen_packets(packet_signer, packet_memo, packet_poll_interval)
+            .await
+        {
+            log::error!("packet listener stopped: {}", err);
+        }
+    });
+
+    let ack_retry_worker = core_ibc_service.clone();
+    let ack_retry_signer = signer.clone();
+
+    log::info!(
+        "starting ack retry worker with interval of {}",
+        humantime::format_duration(ack_retry_interval)
+    );
+
+    tokio::spawn(async move {
+        if let Err(err) = ack_retry_worker
+            .run_ack_retry_worker(ack_retry_signer, memo, ack_retry_interval)
+            .await
+        {
+            log::error!("ack retry worker stopped: {}", err);
+        }
+    });
+
+    let ibc_service = IbcService::new(core_ibc_service, signer);
+    let multisig_service = MultisigService::new(collector);
 
     log::info!("starting grpc server at {}", addr);
 
@@ -29,6 +86,7 @@ pub async fn start_grpc(
         .timeout(Duration::from_secs(60))
         .add_service(ChainServer::new(chain_service))
         .add_service(IbcServer::new(ibc_service))
+        .add_service(MultisigServer::new(multisig_service))
         .serve(addr)
         .await
         .context(format!("unable to start grpc server at: {}", addr))
@@ -7,7 +7,10 @@ use std::{
 };
 
 use solo_machine_core::{
-    model::{ChainConfig as CoreChainConfig, Fee},
+    model::{
+        BroadcastMode, ChainConfig as CoreChainConfig, ConnectionDetails as CoreConnectionDetails,
+        Fee, GasMode,
+    },
     service::ChainService as CoreChainService,
     DbPool, Event, Signer,
 };
@@ -21,12 +24,19 @@ const DEFAULT_RPC_ADDR: &str = "http://0.0.0.0:26657";
 const DEFAULT_FEE_AMOUNT: &str = "1000";
 const DEFAULT_FEE_DENOM: &str = "stake";
 const DEFAULT_GAS_LIMIT: u64 = 300000;
+const DEFAULT_GAS_ADJUSTMENT: &str = "1.5";
+const DEFAULT_GAS_PRICE: &str = "0.01";
 const DEFAULT_TRUST_LEVEL: &str = "1/3";
 const DEFAULT_TRUSTING_PERIOD: Duration = Duration::from_secs(336 * 60 * 60); // 14 days
 const DEFAULT_MAX_CLOCK_DRIFT: Duration = Duration::from_secs(3); // 3 secs
 const DEFAULT_RPC_TIMEOUT: Duration = Duration::from_secs(60); // 60 secs
 const DEFAULT_DIVERSIFIER: &str = "solo-machine-diversifier";
 const DEFAULT_PORT_ID: &str = "transfer";
+const DEFAULT_PACKET_TIMEOUT_HEIGHT_OFFSET: u64 = 10;
+const DEFAULT_PACKET_TIMEOUT_TIMESTAMP_OFFSET: Duration = Duration::from_secs(0); // disabled
+const DEFAULT_BROADCAST_MODE: &str = "commit";
+const DEFAULT_BROADCAST_TIMEOUT: Duration = Duration::from_secs(60); // 60 secs
+const DEFAULT_SOLO_MACHINE_VERSION: &str = "v2";
 
 pub struct ChainService<S> {
     core_service: CoreChainService,
@@ -71,6 +81,10 @@ where
             fee_amount: Some(DEFAULT_FEE_AMOUNT.to_string()),
             fee_denom: Some(DEFAULT_FEE_DENOM.to_string()),
             gas_limit: Some(DEFAULT_GAS_LIMIT),
+            simulate_gas: Some(false),
+            gas_adjustment: None,
+            gas_price: None,
+            fee_granter: None,
         });
 
         let fee = Fee {
@@ -85,6 +99,28 @@ where
                 .parse()
                 .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?,
             gas_limit: fee_config.gas_limit.unwrap_or(DEFAULT_GAS_LIMIT),
+            granter: fee_config.fee_granter,
+        };
+
+        let gas_mode = if fee_config.simulate_gas.unwrap_or(false) {
+            GasMode::Simulate {
+                gas_adjustment: fee_config
+                    .gas_adjustment
+                    .unwrap_or_else(|| DEFAULT_GAS_ADJUSTMENT.to_string())
+                    .parse()
+                    .map_err(|err: rust_decimal::Error| {
+                        Status::invalid_argument(err.to_string())
+                    })?,
+                gas_price: fee_config
+                    .gas_price
+                    .unwrap_or_else(|| DEFAULT_GAS_PRICE.to_string())
+                    .parse()
+                    .map_err(|err: rust_decimal::Error| {
+                        Status::invalid_argument(err.to_string())
+                    })?,
+            }
+        } else {
+            GasMode::Static
         };
 
         let trust_level = config
@@ -147,6 +183,40 @@ where
         let mut trusted_hash = [0; 32];
         trusted_hash.copy_from_slice(&trusted_hash_bytes);
 
+        let packet_timeout_height_offset = config
+            .packet_timeout_height_offset
+            .unwrap_or(DEFAULT_PACKET_TIMEOUT_HEIGHT_OFFSET);
+
+        let packet_timeout_timestamp_offset = config
+            .packet_timeout_timestamp_offset
+            .map(Duration::try_from)
+            .transpose()
+            .map_err(|_| Status::invalid_argument("negative packet_timeout_timestamp_offset"))?
+            .unwrap_or(DEFAULT_PACKET_TIMEOUT_TIMESTAMP_OFFSET);
+
+        let broadcast_timeout = config
+            .broadcast_timeout
+            .map(Duration::try_from)
+            .transpose()
+            .map_err(|_| Status::invalid_argument("negative broadcast_timeout"))?
+            .unwrap_or(DEFAULT_BROADCAST_TIMEOUT);
+
+        let broadcast_mode = BroadcastMode::new(
+            config
+                .broadcast_mode
+                .as_deref()
+                .unwrap_or(DEFAULT_BROADCAST_MODE),
+            broadcast_timeout,
+        )
+        .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
+        let solo_machine_version = config
+            .solo_machine_version
+            .as_deref()
+            .unwrap_or(DEFAULT_SOLO_MACHINE_VERSION)
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
         let core_config = CoreChainConfig {
             grpc_addr,
             rpc_addr,
@@ -159,6 +229,11 @@ where
             port_id,
             trusted_height,
             trusted_hash,
+            packet_timeout_height_offset,
+            packet_timeout_timestamp_offset,
+            gas_mode,
+            broadcast_mode,
+            solo_machine_version,
         };
 
         let chain_id = self
@@ -202,6 +277,30 @@ where
             })?
             .ok_or_else(|| Status::not_found("chain details not found"))?;
 
+        let sequence = self
+            .core_service
+            .get_sequence(&chain)
+            .await
+            .map_err(|err| {
+                log::error!("{}", err);
+                Status::internal(err.to_string())
+            })?
+            .unwrap_or_default()
+            .try_into()
+            .map_err(|err: TryFromIntError| Status::internal(err.to_string()))?;
+
+        let packet_sequence = self
+            .core_service
+            .get_packet_sequence(&chain)
+            .await
+            .map_err(|err| {
+                log::error!("{}", err);
+                Status::internal(err.to_string())
+            })?
+            .unwrap_or_default()
+            .try_into()
+            .map_err(|err: TryFromIntError| Status::internal(err.to_string()))?;
+
         let response = QueryChainResponse {
             chain_id: chain.id.to_string(),
             node_id: chain.node_id.to_string(),
@@ -212,6 +311,18 @@ where
                     fee_amount: Some(chain.config.fee.amount.to_string()),
                     fee_denom: Some(chain.config.fee.denom.to_string()),
                     gas_limit: Some(chain.config.fee.gas_limit),
+                    simulate_gas: Some(matches!(chain.config.gas_mode, GasMode::Simulate { .. })),
+                    gas_adjustment: match chain.config.gas_mode {
+                        GasMode::Static => None,
+                        GasMode::Simulate { gas_adjustment, .. } => {
+                            Some(gas_adjustment.to_string())
+                        }
+                    },
+                    gas_price: match chain.config.gas_mode {
+                        GasMode::Static => None,
+                        GasMode::Simulate { gas_price, .. } => Some(gas_price.to_string()),
+                    },
+                    fee_granter: chain.config.fee.granter,
                 }),
                 trust_level: Some(chain.config.trust_level.to_string()),
                 trusting_period: Some(chain.config.trusting_period.into()),
@@ -225,24 +336,18 @@ where
                         .map_err(|err: TryFromIntError| Status::internal(err.to_string()))?,
                 ),
                 trusted_hash: Some(hex::encode(chain.config.trusted_hash)),
+                packet_timeout_height_offset: Some(chain.config.packet_timeout_height_offset),
+                packet_timeout_timestamp_offset: Some(
+                    chain.config.packet_timeout_timestamp_offset.into(),
+                ),
+                broadcast_mode: Some(chain.config.broadcast_mode.name().to_string()),
+                broadcast_timeout: chain.config.broadcast_mode.timeout().map(Into::into),
+                solo_machine_version: Some(chain.config.solo_machine_version.to_string()),
             }),
             consensus_timestamp: Some(SystemTime::from(chain.consensus_timestamp).into()),
-            sequence: chain.sequence,
-            packet_sequence: chain.packet_sequence,
-            connection_details: chain.connection_details.map(|connection_details| {
-                ConnectionDetails {
-                    solo_machine_client_id: connection_details.solo_machine_client_id.to_string(),
-                    tendermint_client_id: connection_details.tendermint_client_id.to_string(),
-                    solo_machine_connection_id: connection_details
-                        .solo_machine_connection_id
-                        .to_string(),
-                    tendermint_connection_id: connection_details
-                        .tendermint_connection_id
-                        .to_string(),
-                    solo_machine_channel_id: connection_details.solo_machine_channel_id.to_string(),
-                    tendermint_channel_id: connection_details.tendermint_channel_id.to_string(),
-                }
-            }),
+            sequence,
+            packet_sequence,
+            connection_details: chain.connection_details.map(Into::into),
             created_at: Some(SystemTime::from(chain.created_at).into()),
             updated_at: Some(SystemTime::from(chain.updated_at).into()),
         };
@@ -266,9 +371,15 @@ where
             .parse()
             .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
 
+        let channel_id = request
+            .channel_id
+            .map(|channel_id| channel_id.parse())
+            .transpose()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
         let ibc_denom = self
             .core_service
-            .get_ibc_denom(&chain_id, &denom)
+            .get_ibc_denom(&chain_id, channel_id.as_ref(), &denom)
             .await
             .map_err(|err| {
                 log::error!("{}", err);
@@ -296,9 +407,15 @@ where
             .parse()
             .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
 
+        let channel_id = request
+            .channel_id
+            .map(|channel_id| channel_id.parse())
+            .transpose()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
         let balance = self
             .core_service
-            .balance(&self.signer, &chain_id, &denom)
+            .balance(&self.signer, &chain_id, channel_id.as_ref(), &denom)
             .await
             .map_err(|err| {
                 log::error!("{}", err);
@@ -310,4 +427,46 @@ where
 
         Ok(Response::new(response))
     }
+
+    async fn query_connections(
+        &self,
+        request: Request<QueryConnectionsRequest>,
+    ) -> Result<Response<QueryConnectionsResponse>, Status> {
+        let request = request.into_inner();
+
+        let chain_id = request
+            .chain_id
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
+        let connections = self
+            .core_service
+            .get_connections(&chain_id)
+            .await
+            .map_err(|err| {
+                log::error!("{}", err);
+                Status::internal(err.to_string())
+            })?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        let response = QueryConnectionsResponse { connections };
+
+        Ok(Response::new(response))
+    }
+}
+
+impl From<CoreConnectionDetails> for ConnectionDetails {
+    fn from(connection_details: CoreConnectionDetails) -> Self {
+        Self {
+            port_id: connection_details.port_id.to_string(),
+            solo_machine_client_id: connection_details.solo_machine_client_id.to_string(),
+            tendermint_client_id: connection_details.tendermint_client_id.to_string(),
+            solo_machine_connection_id: connection_details.solo_machine_connection_id.to_string(),
+            tendermint_connection_id: connection_details.tendermint_connection_id.to_string(),
+            solo_machine_channel_id: connection_details.solo_machine_channel_id.to_string(),
+            tendermint_channel_id: connection_details.tendermint_channel_id.to_string(),
+        }
+    }
 }
@@ -1,20 +1,33 @@
 tonic::include_proto!("ibc");
 
-use std::time::SystemTime;
+use std::{convert::TryFrom, time::SystemTime};
 
+use anyhow::Context;
 use k256::ecdsa::VerifyingKey;
 use solo_machine_core::{
     cosmos::crypto::{PublicKey, PublicKeyAlgo},
-    ibc::core::ics24_host::identifier::ChainId,
-    service::IbcService as CoreIbcService,
-    DbPool, Event, Signer,
+    ibc::{
+        apps::transfer::{
+            amount::Amount, denom::Denom, forward::ForwardMetadata as CoreForwardMetadata,
+        },
+        core::{
+            ics04_channel::channel::ChannelOrdering,
+            ics24_host::identifier::{ChainId, ChannelId},
+        },
+    },
+    service::{IbcService as CoreIbcService, UnknownTxOutcome},
+    Signer,
 };
-use tokio::sync::mpsc::UnboundedSender;
 use tonic::{Request, Response, Status};
 
 use self::ibc_server::Ibc;
 
 const DEFAULT_MEMO: &str = "solo-machine-memo";
+const DEFAULT_PORT_ID: &str = "transfer";
+const DEFAULT_CHANNEL_VERSION: &str = "ics20-1";
+const DEFAULT_PROPOSAL_TITLE: &str = "Recover solo machine client";
+const DEFAULT_PROPOSAL_DESCRIPTION: &str =
+    "Replaces the state of frozen solo machine client with the state of substitute client";
 
 pub struct IbcService<S> {
     core_service: CoreIbcService,
@@ -23,9 +36,7 @@ pub struct IbcService<S> {
 
 impl<S> IbcService<S> {
     /// Creates a new instance of gRPC IBC service
-    pub fn new(db_pool: DbPool, notifier: UnboundedSender<Event>, signer: S) -> Self {
-        let core_service = CoreIbcService::new_with_notifier(db_pool, notifier);
-
+    pub fn new(core_service: CoreIbcService, signer: S) -> Self {
         Self {
             core_service,
             signer,
@@ -50,50 +61,228 @@ where
             .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
         let memo = request.memo.unwrap_or_else(|| DEFAULT_MEMO.to_owned());
         let force = request.force;
+        let resume = request.resume;
+
+        if force && resume {
+            return Err(Status::invalid_argument(
+                "force and resume cannot be used together",
+            ));
+        }
 
         self.core_service
-            .connect(&self.signer, chain_id, memo, force)
+            .connect(&self.signer, chain_id, memo, force, resume)
             .await
-            .map_err(|err| {
-                log::error!("{}", err);
-                Status::internal(err.to_string())
-            })?;
+            .map_err(operation_error)?;
 
         Ok(Response::new(ConnectResponse {}))
     }
 
-    async fn mint(&self, request: Request<MintRequest>) -> Result<Response<MintResponse>, Status> {
+    async fn open_channel(
+        &self,
+        request: Request<OpenChannelRequest>,
+    ) -> Result<Response<OpenChannelResponse>, Status> {
         let request = request.into_inner();
 
         let chain_id = request
             .chain_id
             .parse()
             .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
-        let request_id = request.request_id;
         let memo = request.memo.unwrap_or_else(|| DEFAULT_MEMO.to_owned());
-        let amount = request.amount;
-        let denom = request
-            .denom
+        let port_id = request
+            .port_id
+            .as_deref()
+            .unwrap_or(DEFAULT_PORT_ID)
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+        let ordering = request
+            .ordering
+            .map(|s| s.parse())
+            .transpose()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?
+            .unwrap_or(ChannelOrdering::Unordered);
+        let version = request
+            .version
+            .unwrap_or_else(|| DEFAULT_CHANNEL_VERSION.to_owned());
+
+        let connection_details = self
+            .core_service
+            .open_channel(&self.signer, chain_id, port_id, ordering, version, memo)
+            .await
+            .map_err(operation_error)?;
+
+        Ok(Response::new(OpenChannelResponse {
+            solo_machine_channel_id: connection_details.solo_machine_channel_id.to_string(),
+            tendermint_channel_id: connection_details.tendermint_channel_id.to_string(),
+        }))
+    }
+
+    async fn accept_connection(
+        &self,
+        request: Request<AcceptConnectionRequest>,
+    ) -> Result<Response<AcceptConnectionResponse>, Status> {
+        let request = request.into_inner();
+
+        let chain_id = request
+            .chain_id
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+        let memo = request.memo.unwrap_or_else(|| DEFAULT_MEMO.to_owned());
+        let connection_id = request
+            .connection_id
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
+        let tendermint_connection_id = self
+            .core_service
+            .accept_connection(&self.signer, chain_id, connection_id, memo)
+            .await
+            .map_err(operation_error)?;
+
+        Ok(Response::new(AcceptConnectionResponse {
+            tendermint_connection_id: tendermint_connection_id.to_string(),
+        }))
+    }
+
+    async fn accept_channel(
+        &self,
+        request: Request<AcceptChannelRequest>,
+    ) -> Result<Response<AcceptChannelResponse>, Status> {
+        let request = request.into_inner();
+
+        let chain_id = request
+            .chain_id
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+        let memo = request.memo.unwrap_or_else(|| DEFAULT_MEMO.to_owned());
+        let channel_id = request
+            .channel_id
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+        let port_id = request
+            .port_id
+            .as_deref()
+            .unwrap_or(DEFAULT_PORT_ID)
             .parse()
             .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
-        let receiver = request.receiver_address;
+
+        let connection_details = self
+            .core_service
+            .accept_channel(&self.signer, chain_id, port_id, channel_id, memo)
+            .await
+            .map_err(operation_error)?;
+
+        Ok(Response::new(AcceptChannelResponse {
+            tendermint_channel_id: connection_details.tendermint_channel_id.to_string(),
+        }))
+    }
+
+    async fn mint(&self, request: Request<MintRequest>) -> Result<Response<MintResponse>, Status> {
+        let request = request.into_inner();
+        let memo = request
+            .memo
+            .clone()
+            .unwrap_or_else(|| DEFAULT_MEMO.to_owned());
+
+        let MintParams {
+            chain_id,
+            request_id,
+            amount,
+            denom,
+            receiver,
+            channel_id,
+            packet_memo,
+        } = MintParams::try_from(request)?;
 
         let transaction_hash = self
             .core_service
             .mint(
                 &self.signer,
                 chain_id,
+                channel_id,
                 request_id,
                 amount,
                 denom,
                 receiver,
+                packet_memo,
                 memo,
             )
             .await
-            .map_err(|err| {
-                log::error!("{}", err);
-                Status::internal(err.to_string())
-            })?;
+            .map_err(operation_error)?;
+
+        Ok(Response::new(MintResponse { transaction_hash }))
+    }
+
+    async fn prepare_mint(
+        &self,
+        request: Request<MintRequest>,
+    ) -> Result<Response<PrepareMintResponse>, Status> {
+        let MintParams {
+            chain_id,
+            request_id,
+            amount,
+            denom,
+            receiver,
+            channel_id,
+            packet_memo,
+        } = MintParams::try_from(request.into_inner())?;
+
+        let unsigned_mint = self
+            .core_service
+            .prepare_mint(
+                &self.signer,
+                chain_id,
+                channel_id,
+                request_id,
+                amount,
+                denom,
+                receiver,
+                packet_memo,
+            )
+            .await
+            .map_err(operation_error)?;
+
+        let unsigned_mint = serde_json::to_string(&unsigned_mint)
+            .map_err(|err| Status::internal(err.to_string()))?;
+
+        Ok(Response::new(PrepareMintResponse { unsigned_mint }))
+    }
+
+    async fn prepare_mint_transaction(
+        &self,
+        request: Request<PrepareMintTransactionRequest>,
+    ) -> Result<Response<PrepareMintResponse>, Status> {
+        let request = request.into_inner();
+
+        let unsigned_mint = serde_json::from_str(&request.unsigned_mint)
+            .map_err(|err| Status::invalid_argument(err.to_string()))?;
+        let memo = request.memo.unwrap_or_else(|| DEFAULT_MEMO.to_owned());
+
+        let unsigned_mint = self
+            .core_service
+            .prepare_mint_transaction(&self.signer, unsigned_mint, memo)
+            .await
+            .map_err(operation_error)?;
+
+        let unsigned_mint = serde_json::to_string(&unsigned_mint)
+            .map_err(|err| Status::internal(err.to_string()))?;
+
+        Ok(Response::new(PrepareMintResponse { unsigned_mint }))
+    }
+
+    async fn submit_mint(
+        &self,
+        request: Request<SubmitMintRequest>,
+    ) -> Result<Response<MintResponse>, Status> {
+        let request = request.into_inner();
+
+        let unsigned_mint = serde_json::from_str(&request.unsigned_mint)
+            .map_err(|err| Status::invalid_argument(err.to_string()))?;
+
+        let transaction_hash = self
+            .core_service
+            .submit_mint(unsigned_mint)
+            .await
+            .map_err(operation_error)?;
 
         Ok(Response::new(MintResponse { transaction_hash }))
     }
@@ -107,20 +296,34 @@ where
             .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
         let request_id = request.request_id;
         let memo = request.memo.unwrap_or_else(|| DEFAULT_MEMO.to_owned());
-        let amount = request.amount;
+        let amount = request
+            .amount
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
         let denom = request
             .denom
             .parse()
             .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
 
+        let channel_id = request
+            .channel_id
+            .map(|channel_id| channel_id.parse())
+            .transpose()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
         let transaction_hash = self
             .core_service
-            .burn(&self.signer, chain_id, request_id, amount, denom, memo)
+            .burn(
+                &self.signer,
+                chain_id,
+                channel_id,
+                request_id,
+                amount,
+                denom,
+                memo,
+            )
             .await
-            .map_err(|err| {
-                log::error!("{}", err);
-                Status::internal(err.to_string())
-            })?;
+            .map_err(operation_error)?;
 
         Ok(Response::new(BurnResponse { transaction_hash }))
     }
@@ -138,34 +341,203 @@ where
 
         let memo = request.memo.unwrap_or_else(|| DEFAULT_MEMO.to_owned());
 
-        let new_public_key_bytes = hex::decode(&request.new_public_key)
-            .map_err(|err| Status::invalid_argument(err.to_string()))?;
+        let new_public_key =
+            parse_public_key(&request.new_public_key, request.public_key_algo.as_deref())
+                .map_err(|err| Status::invalid_argument(err.to_string()))?;
 
-        let new_verifying_key = VerifyingKey::from_sec1_bytes(&new_public_key_bytes)
-            .map_err(|err| Status::invalid_argument(err.to_string()))?;
+        self.core_service
+            .update_signer(&self.signer, chain_id, new_public_key, memo)
+            .await
+            .map_err(operation_error)?;
 
-        let public_key_algo = request
-            .public_key_algo
-            .map(|s| s.parse())
+        Ok(Response::new(UpdateSignerResponse {}))
+    }
+
+    async fn update_diversifier(
+        &self,
+        request: Request<UpdateDiversifierRequest>,
+    ) -> Result<Response<UpdateDiversifierResponse>, Status> {
+        let request = request.into_inner();
+
+        let chain_id: ChainId = request
+            .chain_id
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
+        let memo = request.memo.unwrap_or_else(|| DEFAULT_MEMO.to_owned());
+
+        let public_key_algo = request.public_key_algo.as_deref();
+        let new_public_key = request
+            .new_public_key
+            .as_deref()
+            .map(|new_public_key| parse_public_key(new_public_key, public_key_algo))
             .transpose()
-            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?
-            .unwrap_or(PublicKeyAlgo::Secp256k1);
+            .map_err(|err| Status::invalid_argument(err.to_string()))?;
 
-        let new_public_key = match public_key_algo {
-            PublicKeyAlgo::Secp256k1 => PublicKey::Secp256k1(new_verifying_key),
-            #[cfg(feature = "ethermint")]
-            PublicKeyAlgo::EthSecp256k1 => PublicKey::EthSecp256k1(new_verifying_key),
-        };
+        self.core_service
+            .update_diversifier(
+                &self.signer,
+                chain_id,
+                request.new_diversifier,
+                new_public_key,
+                memo,
+            )
+            .await
+            .map_err(operation_error)?;
+
+        Ok(Response::new(UpdateDiversifierResponse {}))
+    }
+
+    async fn close_channel(
+        &self,
+        request: Request<CloseChannelRequest>,
+    ) -> Result<Response<CloseChannelResponse>, Status> {
+        let request = request.into_inner();
+
+        let chain_id = request
+            .chain_id
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+        let memo = request.memo.unwrap_or_else(|| DEFAULT_MEMO.to_owned());
+
+        let channel_id = request
+            .channel_id
+            .map(|channel_id| channel_id.parse())
+            .transpose()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
 
         self.core_service
-            .update_signer(&self.signer, chain_id, new_public_key, memo)
+            .close_channel(&self.signer, chain_id, channel_id, memo)
             .await
-            .map_err(|err| {
-                log::error!("{}", err);
-                Status::internal(err.to_string())
-            })?;
+            .map_err(operation_error)?;
 
-        Ok(Response::new(UpdateSignerResponse {}))
+        Ok(Response::new(CloseChannelResponse {}))
+    }
+
+    async fn update_client(
+        &self,
+        request: Request<UpdateClientRequest>,
+    ) -> Result<Response<UpdateClientResponse>, Status> {
+        let request = request.into_inner();
+
+        let chain_id = request
+            .chain_id
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
+        let channel_id = request
+            .channel_id
+            .map(|channel_id| channel_id.parse())
+            .transpose()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
+        let latest_height = self
+            .core_service
+            .update_tendermint_client(chain_id, channel_id)
+            .await
+            .map_err(operation_error)?;
+
+        Ok(Response::new(UpdateClientResponse {
+            revision_number: latest_height.revision_number,
+            revision_height: latest_height.revision_height,
+        }))
+    }
+
+    async fn client_status(
+        &self,
+        request: Request<ClientStatusRequest>,
+    ) -> Result<Response<ClientStatusResponse>, Status> {
+        let request = request.into_inner();
+
+        let chain_id = request
+            .chain_id
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
+        let channel_id = request
+            .channel_id
+            .map(|channel_id| channel_id.parse())
+            .transpose()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
+        let status = self
+            .core_service
+            .client_status(chain_id, channel_id)
+            .await
+            .map_err(operation_error)?;
+
+        Ok(Response::new(ClientStatusResponse {
+            client_id: status.client_id.to_string(),
+            sequence: status.sequence,
+            frozen: status.frozen,
+            public_key: status.public_key.map(|public_key| public_key.encode()),
+        }))
+    }
+
+    async fn create_substitute_client(
+        &self,
+        request: Request<CreateSubstituteClientRequest>,
+    ) -> Result<Response<CreateSubstituteClientResponse>, Status> {
+        let request = request.into_inner();
+
+        let chain_id = request
+            .chain_id
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+        let memo = request.memo.unwrap_or_else(|| DEFAULT_MEMO.to_owned());
+        let title = request
+            .title
+            .unwrap_or_else(|| DEFAULT_PROPOSAL_TITLE.to_owned());
+        let description = request
+            .description
+            .unwrap_or_else(|| DEFAULT_PROPOSAL_DESCRIPTION.to_owned());
+
+        let channel_id = request
+            .channel_id
+            .map(|channel_id| channel_id.parse())
+            .transpose()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
+        let proposal = self
+            .core_service
+            .create_substitute_client(&self.signer, chain_id, channel_id, title, description, memo)
+            .await
+            .map_err(operation_error)?;
+
+        Ok(Response::new(CreateSubstituteClientResponse {
+            title: proposal.title,
+            description: proposal.description,
+            subject_client_id: proposal.subject_client_id,
+            substitute_client_id: proposal.substitute_client_id,
+        }))
+    }
+
+    async fn recover_client(
+        &self,
+        request: Request<RecoverClientRequest>,
+    ) -> Result<Response<RecoverClientResponse>, Status> {
+        let request = request.into_inner();
+
+        let chain_id = request
+            .chain_id
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
+        let channel_id = request
+            .channel_id
+            .map(|channel_id| channel_id.parse())
+            .transpose()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
+        let status = self
+            .core_service
+            .recover_client(&self.signer, chain_id, channel_id)
+            .await
+            .map_err(operation_error)?;
+
+        Ok(Response::new(RecoverClientResponse {
+            sequence: status.sequence,
+        }))
     }
 
     async fn query_history(
@@ -181,10 +553,7 @@ where
             .core_service
             .history(&self.signer, limit, offset)
             .await
-            .map_err(|err| {
-                log::error!("{}", err);
-                Status::internal(err.to_string())
-            })?;
+            .map_err(operation_error)?;
 
         let response = QueryHistoryResponse {
             operations: history
@@ -194,7 +563,7 @@ where
                     request_id: op.request_id,
                     address: op.address,
                     denom: op.denom.to_string(),
-                    amount: op.amount,
+                    amount: op.amount.to_string(),
                     operation_type: op.operation_type.to_string(),
                     transaction_hash: op.transaction_hash,
                     created_at: Some(SystemTime::from(op.created_at).into()),
@@ -204,4 +573,129 @@ where
 
         Ok(Response::new(response))
     }
+
+    async fn reconcile(
+        &self,
+        request: Request<ReconcileRequest>,
+    ) -> Result<Response<ReconcileResponse>, Status> {
+        let request = request.into_inner();
+
+        let pending = self
+            .core_service
+            .reconcile(&request.transaction_hash)
+            .await
+            .map_err(operation_error)?;
+
+        Ok(Response::new(ReconcileResponse {
+            transaction_hash: pending.transaction_hash,
+            chain_id: pending.chain_id.to_string(),
+            request_id: pending.request_id,
+            operation: pending.operation.to_string(),
+            status: pending.status.to_string(),
+        }))
+    }
+}
+
+/// Parameters of a mint parsed from gRPC request
+struct MintParams {
+    chain_id: ChainId,
+    request_id: Option<String>,
+    amount: Amount,
+    denom: Denom,
+    receiver: Option<String>,
+    channel_id: Option<ChannelId>,
+    packet_memo: Option<String>,
+}
+
+impl TryFrom<MintRequest> for MintParams {
+    type Error = Status;
+
+    fn try_from(request: MintRequest) -> Result<Self, Self::Error> {
+        let chain_id = request
+            .chain_id
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+        let amount = request
+            .amount
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+        let denom = request
+            .denom
+            .parse()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
+        let packet_memo = match request.forward {
+            Some(forward) => {
+                if request.packet_memo.is_some() {
+                    return Err(Status::invalid_argument(
+                        "packet memo cannot be provided along with forward metadata",
+                    ));
+                }
+
+                let port = forward
+                    .port
+                    .as_deref()
+                    .unwrap_or(DEFAULT_PORT_ID)
+                    .parse()
+                    .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+                let channel = forward
+                    .channel
+                    .parse()
+                    .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
+                Some(
+                    CoreForwardMetadata::new(forward.receiver, port, channel)
+                        .to_memo()
+                        .map_err(|err| Status::internal(err.to_string()))?,
+                )
+            }
+            None => request.packet_memo,
+        };
+
+        let channel_id = request
+            .channel_id
+            .map(|channel_id| channel_id.parse())
+            .transpose()
+            .map_err(|err: anyhow::Error| Status::invalid_argument(err.to_string()))?;
+
+        Ok(Self {
+            chain_id,
+            request_id: request.request_id,
+            amount,
+            denom,
+            receiver: request.receiver_address,
+            channel_id,
+            packet_memo,
+        })
+    }
+}
+
+/// Converts error of an operation to gRPC status (`DEADLINE_EXCEEDED` if outcome of transaction sent to IBC enabled chain
+/// is unknown, i.e., it may still be included in a block later)
+fn operation_error(err: anyhow::Error) -> Status {
+    log::error!("{}", err);
+
+    if err.downcast_ref::<UnknownTxOutcome>().is_some() {
+        Status::deadline_exceeded(err.to_string())
+    } else {
+        Status::internal(err.to_string())
+    }
+}
+
+fn parse_public_key(public_key: &str, public_key_algo: Option<&str>) -> anyhow::Result<PublicKey> {
+    let public_key_bytes = hex::decode(public_key).context("unable to decode hex bytes")?;
+
+    let verifying_key =
+        VerifyingKey::from_sec1_bytes(&public_key_bytes).context("invalid secp256k1 bytes")?;
+
+    let public_key_algo = public_key_algo
+        .map(|s| s.parse())
+        .transpose()?
+        .unwrap_or(PublicKeyAlgo::Secp256k1);
+
+    match public_key_algo {
+        PublicKeyAlgo::Secp256k1 => Ok(PublicKey::Secp256k1(verifying_key)),
+        #[cfg(feature = "ethermint")]
+        PublicKeyAlgo::EthSecp256k1 => Ok(PublicKey::EthSecp256k1(verifying_key)),
+    }
 }
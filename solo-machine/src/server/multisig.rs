@@ -0,0 +1,58 @@
+tonic::include_proto!("multisig");
+
+use std::time::SystemTime;
+
+use solo_machine_core::signer::multisig::SignatureCollector;
+use tonic::{Request, Response, Status};
+
+use self::multisig_server::Multisig;
+
+pub struct MultisigService {
+    collector: SignatureCollector,
+}
+
+impl MultisigService {
+    /// Creates a new instance of gRPC multisig service
+    pub fn new(collector: SignatureCollector) -> Self {
+        Self { collector }
+    }
+}
+
+#[tonic::async_trait]
+impl Multisig for MultisigService {
+    async fn pending_signatures(
+        &self,
+        request: Request<PendingSignaturesRequest>,
+    ) -> Result<Response<PendingSignaturesResponse>, Status> {
+        let request = request.into_inner();
+
+        let requests = self
+            .collector
+            .pending_requests(request.public_key.as_deref())
+            .into_iter()
+            .map(|request| SignatureRequest {
+                id: request.id,
+                public_key: request.public_key.encode(),
+                request_id: request.request_id,
+                message_type: request.message_type.to_owned(),
+                message: request.message,
+                created_at: Some(SystemTime::from(request.created_at).into()),
+            })
+            .collect();
+
+        Ok(Response::new(PendingSignaturesResponse { requests }))
+    }
+
+    async fn submit_signature(
+        &self,
+        request: Request<SubmitSignatureRequest>,
+    ) -> Result<Response<SubmitSignatureResponse>, Status> {
+        let request = request.into_inner();
+
+        self.collector
+            .submit(request.id, request.signature)
+            .map_err(|err| Status::invalid_argument(err.to_string()))?;
+
+        Ok(Response::new(SubmitSignatureResponse {}))
+    }
+}
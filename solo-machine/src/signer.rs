@@ -4,12 +4,45 @@ use anyhow::{anyhow, Context, Error, Result};
 use libloading::{Library, Symbol};
 use solo_machine_core::{signer::SignerRegistrar as ISignerRegistrar, Signer};
 
-#[derive(Default)]
+const DEFAULT_ENV_PREFIX: &str = "SOLO_";
+const FEE_PAYER_ENV_PREFIX: &str = "SOLO_FEE_PAYER_";
+const MULTISIG_ENV_PREFIX: &str = "SOLO_MULTISIG_";
+
 pub struct SignerRegistrar {
     signer: Option<Arc<dyn Signer>>,
+    env_prefix: String,
 }
 
 impl SignerRegistrar {
+    fn new(env_prefix: String) -> Self {
+        Self {
+            signer: None,
+            env_prefix,
+        }
+    }
+
+    /// Loads fee payer signer from given file (signer reads its arguments from environment variables prefixed with
+    /// `SOLO_FEE_PAYER_`)
+    pub fn fee_payer(file: PathBuf) -> Result<Self> {
+        let mut registrar = Self::new(FEE_PAYER_ENV_PREFIX.to_owned());
+        registrar
+            .register_signer(file)
+            .context("unable to load fee payer signer")?;
+
+        Ok(registrar)
+    }
+
+    /// Loads n-th member (starting from 1) of multisig signer from given file (signer reads its arguments from
+    /// environment variables prefixed with `SOLO_MULTISIG_<n>_`)
+    pub fn multisig_member(file: PathBuf, n: usize) -> Result<Self> {
+        let mut registrar = Self::new(format!("{}{}_", MULTISIG_ENV_PREFIX, n));
+        registrar
+            .register_signer(file)
+            .context(format!("unable to load member {} of multisig signer", n))?;
+
+        Ok(registrar)
+    }
+
     pub fn unwrap(self) -> Result<Arc<dyn Signer>> {
         self.signer.ok_or_else(|| anyhow!("signer not registered"))
     }
@@ -47,13 +80,17 @@ impl ISignerRegistrar for SignerRegistrar {
     fn register(&mut self, signer: Arc<dyn Signer>) {
         self.signer = Some(signer);
     }
+
+    fn env_prefix(&self) -> &str {
+        &self.env_prefix
+    }
 }
 
 impl TryFrom<PathBuf> for SignerRegistrar {
     type Error = Error;
 
     fn try_from(file: PathBuf) -> Result<Self, Self::Error> {
-        let mut registrar = Self::default();
+        let mut registrar = Self::new(DEFAULT_ENV_PREFIX.to_owned());
         registrar.register_signer(file)?;
 
         Ok(registrar)